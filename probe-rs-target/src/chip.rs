@@ -58,6 +58,13 @@ pub struct Chip {
     /// ref: `<https://open-cmsis-pack.github.io/Open-CMSIS-Pack-Spec/main/html/sdf_pg.html#sdf_element_scanchain>`
     #[serde(default)]
     pub scan_chain: Option<Vec<ScanChainElement>>,
+    /// The maximum debug clock in kHz (for both SWD and JTAG) that this chip can
+    /// reliably be debugged with.
+    ///
+    /// This need not be specified for most chips, in which case the speed of
+    /// the debug probe is used.
+    #[serde(default)]
+    pub max_debug_clock_khz: Option<u32>,
 }
 
 impl Chip {
@@ -72,11 +79,13 @@ impl Chip {
                 name: "main".to_string(),
                 core_type,
                 core_access_options: CoreAccessOptions::Arm(ArmCoreAccessOptions::default()),
+                reset_domain: None,
             }],
             memory_map: vec![],
             flash_algorithms: vec![],
             rtt_scan_ranges: None,
             scan_chain: Some(vec![]),
+            max_debug_clock_khz: None,
         }
     }
 }
@@ -94,6 +103,13 @@ pub struct Core {
 
     /// The AP number to access the core
     pub core_access_options: CoreAccessOptions,
+
+    /// The name of the reset domain of the core.
+    ///
+    /// Cores in the same reset domain are reset together. All cores without a reset domain
+    /// share a single, unnamed reset domain.
+    #[serde(default)]
+    pub reset_domain: Option<String>,
 }
 
 /// The data required to access a core
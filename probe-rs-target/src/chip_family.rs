@@ -52,6 +52,28 @@ impl CoreType {
             CoreType::Armv6m | CoreType::Armv7em | CoreType::Armv7m | CoreType::Armv8m
         )
     }
+
+    /// Returns true if the core type supports the Thumb-2 instruction set.
+    ///
+    /// For ARMv6-M and the ARMv8-M baseline, this is the subset of Thumb-2 supported by these cores.
+    pub fn supports_thumb2(&self) -> bool {
+        self.architecture() == Architecture::Arm
+    }
+
+    /// Returns true if cores of this type can have a floating point unit.
+    ///
+    /// The FPU is optional for most of these core types (e.g. a Cortex-M4 without the F suffix),
+    /// so this does not guarantee that a specific core has one.
+    pub fn may_have_fpu(&self) -> bool {
+        matches!(
+            self,
+            CoreType::Armv7em
+                | CoreType::Armv7a
+                | CoreType::Armv8a
+                | CoreType::Armv8m
+                | CoreType::Riscv
+        )
+    }
 }
 
 /// The architecture family of a specific [`CoreType`].
@@ -247,6 +269,24 @@ impl ChipFamily {
                     memory
                 );
             }
+
+            // A flash algorithm has to run on one of its cores, so all of them must exist on the variant.
+            for algorithm in variant
+                .flash_algorithms
+                .iter()
+                .filter_map(|algorithm_name| self.get_algorithm(algorithm_name))
+            {
+                if let Some(core) = algorithm
+                    .cores
+                    .iter()
+                    .find(|core| !core_names.contains(core))
+                {
+                    return Err(format!(
+                        "flash algorithm `{}` of variant `{}` is assigned to a non-existent core `{}`",
+                        algorithm.name, variant.name, core
+                    ));
+                }
+            }
         }
 
         Ok(())
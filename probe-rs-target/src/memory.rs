@@ -37,6 +37,14 @@ pub struct RamRegion {
     /// True if the chip boots from this memory
     #[serde(default)]
     pub is_boot_memory: bool,
+    /// True if this memory is reserved, e.g. for use by a bootloader or ROM code,
+    /// and should not be scanned for an RTT header by default.
+    #[serde(default)]
+    pub is_reserved: bool,
+    /// True if this memory is dedicated to the stack, e.g. a separate stack RAM.
+    /// If any RAM region of a core is marked, the stack of that core is assumed to be in the marked regions only.
+    #[serde(default)]
+    pub is_stack: bool,
     /// List of cores that can access this region
     pub cores: Vec<String>,
 }
@@ -151,6 +159,15 @@ impl MemoryRegion {
             MemoryRegion::Nvm(region) => &region.cores,
         }
     }
+
+    /// Get the address range of this memory region.
+    pub fn address_range(&self) -> Range<u64> {
+        match self {
+            MemoryRegion::Ram(region) => region.range.clone(),
+            MemoryRegion::Generic(region) => region.range.clone(),
+            MemoryRegion::Nvm(region) => region.range.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
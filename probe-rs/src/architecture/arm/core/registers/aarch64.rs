@@ -8,28 +8,28 @@ pub(crate) const PC: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("PC"), RegisterRole::ProgramCounter],
     id: RegisterId(32),
     data_type: RegisterDataType::UnsignedInteger(64),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::ProgramCounter,
 };
 
 pub(crate) const FP: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("X29"), RegisterRole::FramePointer],
     id: RegisterId(29),
     data_type: RegisterDataType::UnsignedInteger(64),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::Preserve,
 };
 
 pub(crate) const SP: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("SP"), RegisterRole::StackPointer],
     id: RegisterId(31),
     data_type: RegisterDataType::UnsignedInteger(64),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::CanonicalFrameAddress,
 };
 
 pub(crate) const RA: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("X30"), RegisterRole::ReturnAddress],
     id: RegisterId(30),
     data_type: RegisterDataType::UnsignedInteger(64),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::ReturnAddress,
 };
 
 pub(crate) static AARCH64_CORE_REGSISTERS: Lazy<CoreRegisters> =
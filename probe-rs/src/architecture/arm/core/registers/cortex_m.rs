@@ -8,28 +8,28 @@ pub(crate) const PC: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("R15"), RegisterRole::ProgramCounter],
     id: RegisterId(15),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::SpecialRule,
+    unwind_rule: UnwindRule::ProgramCounter,
 };
 
 pub(crate) const FP: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("R7"), RegisterRole::FramePointer],
     id: RegisterId(7),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::SpecialRule,
+    unwind_rule: UnwindRule::Preserve,
 };
 
 pub(crate) const SP: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("R13"), RegisterRole::StackPointer],
     id: RegisterId(13),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Preserve,
+    unwind_rule: UnwindRule::CanonicalFrameAddress,
 };
 
 pub(crate) const RA: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("R14"), RegisterRole::ReturnAddress],
     id: RegisterId(14),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::SpecialRule,
+    unwind_rule: UnwindRule::ReturnAddress,
 };
 
 pub(crate) const XPSR: CoreRegister = CoreRegister {
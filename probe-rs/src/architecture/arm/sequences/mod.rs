@@ -762,6 +762,13 @@ pub trait ArmDebugSequence: Send + Sync {
     fn debug_erase_sequence(&self) -> Option<Arc<dyn DebugEraseSequence>> {
         None
     }
+
+    /// The maximum debug clock in kHz that this sequence can reliably be used with, if it is limited.
+    ///
+    /// See [`Target::recommended_debug_clock`](crate::config::Target::recommended_debug_clock).
+    fn max_debug_clock_khz(&self) -> Option<u32> {
+        None
+    }
 }
 
 /// Chip-Erase Handling via the Device's Debug Interface
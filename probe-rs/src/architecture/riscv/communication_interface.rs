@@ -294,6 +294,17 @@ impl RiscvCommunicationInterface {
         Ok(s)
     }
 
+    /// Creates an interface without entering debug mode, for tests which don't access the debug module.
+    #[cfg(test)]
+    pub(crate) fn new_unconnected(probe: Box<dyn JTAGAccess>) -> Self {
+        let dtm = Dtm::new(probe).map_err(|(_, err)| err).unwrap();
+
+        Self {
+            dtm,
+            state: RiscvCommunicationInterfaceState::new(),
+        }
+    }
+
     /// Deassert the target reset.
     pub fn target_reset_deassert(&mut self) -> Result<(), DebugProbeError> {
         self.dtm.target_reset_deassert()
@@ -15,7 +15,11 @@ use anyhow::{anyhow, Result};
 use bitfield::bitfield;
 use communication_interface::{AbstractCommandErrorKind, RiscvCommunicationInterface, RiscvError};
 use registers::RISCV_CORE_REGSISTERS;
-use std::time::{Duration, Instant};
+use sequences::RiscvDebugSequence;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[macro_use]
 pub(crate) mod registers;
@@ -29,6 +33,7 @@ pub mod sequences;
 pub struct Riscv32<'probe> {
     interface: &'probe mut RiscvCommunicationInterface,
     state: &'probe mut RiscVState,
+    sequence: Arc<dyn RiscvDebugSequence>,
     id: usize,
 }
 
@@ -37,45 +42,23 @@ impl<'probe> Riscv32<'probe> {
     pub fn new(
         interface: &'probe mut RiscvCommunicationInterface,
         state: &'probe mut RiscVState,
+        sequence: Arc<dyn RiscvDebugSequence>,
         id: usize,
     ) -> Self {
         Self {
             interface,
             state,
+            sequence,
             id,
         }
     }
 
     fn read_csr(&mut self, address: u16) -> Result<u32, RiscvError> {
-        // We need to use the "Access Register Command",
-        // which has cmdtype 0
-
-        // write needs to be clear
-        // transfer has to be set
-
-        tracing::debug!("Reading CSR {:#x}", address);
-
-        // always try to read register with abstract command, fallback to program buffer,
-        // if not supported
-        match self.interface.abstract_cmd_register_read(address) {
-            Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::NotSupported)) => {
-                tracing::debug!("Could not read core register {:#x} with abstract command, falling back to program buffer", address);
-                self.interface.read_csr_progbuf(address)
-            }
-            other => other,
-        }
+        self.interface.read_csr(address)
     }
 
     fn write_csr(&mut self, address: u16, value: u32) -> Result<(), RiscvError> {
-        tracing::debug!("Writing CSR {:#x}", address);
-
-        match self.interface.abstract_cmd_register_write(address, value) {
-            Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::NotSupported)) => {
-                tracing::debug!("Could not write core register {:#x} with abstract command, falling back to program buffer", address);
-                self.interface.write_csr_progbuf(address, value)
-            }
-            other => other,
-        }
+        self.interface.write_csr(address, value)
     }
 
     // Resume the core.
@@ -371,73 +354,7 @@ impl<'probe> CoreInterface for Riscv32<'probe> {
 
     fn available_breakpoint_units(&mut self) -> Result<u32, crate::Error> {
         // TODO: This should probably only be done once, when initialising
-
-        tracing::debug!("Determining number of HW breakpoints supported");
-
-        let tselect = 0x7a0;
-        let tdata1 = 0x7a1;
-        let tinfo = 0x7a4;
-
-        let mut tselect_index = 0;
-
-        // These steps follow the debug specification 0.13, section 5.1 Enumeration
-        loop {
-            tracing::debug!("Trying tselect={}", tselect_index);
-            if let Err(e) = self.write_csr(tselect, tselect_index) {
-                match e {
-                    RiscvError::AbstractCommand(AbstractCommandErrorKind::Exception) => break,
-                    other_error => return Err(other_error.into()),
-                }
-            }
-
-            let readback = self.read_csr(tselect)?;
-
-            if readback != tselect_index {
-                break;
-            }
-
-            match self.read_csr(tinfo) {
-                Ok(tinfo_val) => {
-                    if tinfo_val & 0xffff == 1 {
-                        // Trigger doesn't exist, break the loop
-                        break;
-                    } else {
-                        tracing::info!(
-                            "Discovered trigger with index {} and type {}",
-                            tselect_index,
-                            tinfo_val & 0xffff
-                        );
-                    }
-                }
-                Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::Exception)) => {
-                    // An exception means we have to read tdata1 to discover the type
-                    let tdata_val = self.read_csr(tdata1)?;
-
-                    // Read the mxl field from the misa register (see RISC-V Privileged Spec, 3.1.1)
-                    let misa_value = Misa(self.read_csr(0x301)?);
-                    let xlen = u32::pow(2, misa_value.mxl() + 4);
-
-                    let trigger_type = tdata_val >> (xlen - 4);
-
-                    if trigger_type == 0 {
-                        break;
-                    }
-
-                    tracing::info!(
-                        "Discovered trigger with index {} and type {}",
-                        tselect_index,
-                        trigger_type,
-                    );
-                }
-                Err(other) => return Err(other.into()),
-            }
-
-            tselect_index += 1;
-        }
-
-        tracing::debug!("Target supports {} breakpoints.", tselect_index);
-
-        Ok(tselect_index)
+        self.sequence.available_trigger_count(self.interface)
     }
 
     /// See docs on the [`CoreInterface::hw_breakpoints`] trait
@@ -764,6 +681,119 @@ impl RiscVState {
     }
 }
 
+/// Access to the control and status registers (CSRs) of a RISC-V hart.
+pub(crate) trait CsrAccess {
+    /// Read the CSR at `address`.
+    fn read_csr(&mut self, address: u16) -> Result<u32, RiscvError>;
+
+    /// Write `value` to the CSR at `address`.
+    fn write_csr(&mut self, address: u16, value: u32) -> Result<(), RiscvError>;
+}
+
+impl CsrAccess for RiscvCommunicationInterface {
+    fn read_csr(&mut self, address: u16) -> Result<u32, RiscvError> {
+        // We need to use the "Access Register Command",
+        // which has cmdtype 0
+
+        // write needs to be clear
+        // transfer has to be set
+
+        tracing::debug!("Reading CSR {:#x}", address);
+
+        // always try to read register with abstract command, fallback to program buffer,
+        // if not supported
+        match self.abstract_cmd_register_read(address) {
+            Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::NotSupported)) => {
+                tracing::debug!("Could not read core register {:#x} with abstract command, falling back to program buffer", address);
+                self.read_csr_progbuf(address)
+            }
+            other => other,
+        }
+    }
+
+    fn write_csr(&mut self, address: u16, value: u32) -> Result<(), RiscvError> {
+        tracing::debug!("Writing CSR {:#x}", address);
+
+        match self.abstract_cmd_register_write(address, value) {
+            Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::NotSupported)) => {
+                tracing::debug!("Could not write core register {:#x} with abstract command, falling back to program buffer", address);
+                self.write_csr_progbuf(address, value)
+            }
+            other => other,
+        }
+    }
+}
+
+/// Determine the number of hardware triggers implemented by the hart.
+pub(crate) fn enumerate_triggers(csrs: &mut dyn CsrAccess) -> Result<u32, RiscvError> {
+    tracing::debug!("Determining number of HW breakpoints supported");
+
+    let tselect = 0x7a0;
+    let tdata1 = 0x7a1;
+    let tinfo = 0x7a4;
+
+    let mut tselect_index = 0;
+
+    // These steps follow the debug specification 0.13, section 5.1 Enumeration
+    loop {
+        tracing::debug!("Trying tselect={}", tselect_index);
+        if let Err(e) = csrs.write_csr(tselect, tselect_index) {
+            match e {
+                RiscvError::AbstractCommand(AbstractCommandErrorKind::Exception) => break,
+                other_error => return Err(other_error),
+            }
+        }
+
+        let readback = csrs.read_csr(tselect)?;
+
+        if readback != tselect_index {
+            break;
+        }
+
+        match csrs.read_csr(tinfo) {
+            Ok(tinfo_val) => {
+                if tinfo_val & 0xffff == 1 {
+                    // Trigger doesn't exist, break the loop
+                    break;
+                } else {
+                    tracing::info!(
+                        "Discovered trigger with index {} and type {}",
+                        tselect_index,
+                        tinfo_val & 0xffff
+                    );
+                }
+            }
+            Err(RiscvError::AbstractCommand(AbstractCommandErrorKind::Exception)) => {
+                // An exception means we have to read tdata1 to discover the type
+                let tdata_val = csrs.read_csr(tdata1)?;
+
+                // Read the mxl field from the misa register (see RISC-V Privileged Spec, 3.1.1)
+                let misa_value = Misa(csrs.read_csr(0x301)?);
+                let xlen = u32::pow(2, misa_value.mxl() + 4);
+
+                let trigger_type = tdata_val >> (xlen - 4);
+
+                if trigger_type == 0 {
+                    break;
+                }
+
+                tracing::info!(
+                    "Discovered trigger with index {} and type {}",
+                    tselect_index,
+                    trigger_type,
+                );
+            }
+            Err(other) => return Err(other),
+        }
+
+        tselect_index += 1;
+    }
+
+    tracing::debug!("Target supports {} breakpoints.", tselect_index);
+
+    Ok(tselect_index)
+}
+
 memory_mapped_bitfield_register! {
     /// `dmcontrol` register, located at
     /// address 0x10
@@ -934,3 +964,88 @@ bitfield! {
     /// Standard RISC-V extensions
     extensions, _: 25, 0;
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::{
+        communication_interface::{
+            AbstractCommandErrorKind, RiscvCommunicationInterface, RiscvError,
+        },
+        enumerate_triggers,
+        sequences::RiscvDebugSequence,
+        CsrAccess, RiscVState, Riscv32,
+    };
+    use crate::{probe::fake_probe::FakeProbe, CoreInterface};
+
+    /// CSR mock for a hart implementing a fixed number of triggers.
+    struct MockCsrs {
+        trigger_count: u32,
+        tselect: u32,
+    }
+
+    impl CsrAccess for MockCsrs {
+        fn read_csr(&mut self, address: u16) -> Result<u32, RiscvError> {
+            match address {
+                0x7a0 => Ok(self.tselect),
+                // tinfo: address / data match trigger
+                0x7a4 => Ok(0x4),
+                other => panic!("Unexpected CSR read from {other:#x}"),
+            }
+        }
+
+        fn write_csr(&mut self, address: u16, value: u32) -> Result<(), RiscvError> {
+            assert_eq!(address, 0x7a0, "Unexpected CSR write to {address:#x}");
+
+            if value >= self.trigger_count {
+                return Err(RiscvError::AbstractCommand(
+                    AbstractCommandErrorKind::Exception,
+                ));
+            }
+
+            self.tselect = value;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn enumerate_triggers_counts_available_triggers() {
+        for trigger_count in [0, 1, 4] {
+            let mut csrs = MockCsrs {
+                trigger_count,
+                tselect: 0,
+            };
+
+            assert_eq!(enumerate_triggers(&mut csrs).unwrap(), trigger_count);
+        }
+    }
+
+    /// A sequence for a chip which reports a fixed number of triggers, without enumerating them.
+    struct FixedTriggerCount(u32);
+
+    impl RiscvDebugSequence for FixedTriggerCount {
+        fn available_trigger_count(
+            &self,
+            _interface: &mut RiscvCommunicationInterface,
+        ) -> Result<u32, crate::Error> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn available_breakpoint_units_uses_sequence() {
+        let mut interface =
+            RiscvCommunicationInterface::new_unconnected(Box::new(FakeProbe::new()));
+        let mut state = RiscVState::new();
+
+        let mut core = Riscv32::new(
+            &mut interface,
+            &mut state,
+            Arc::new(FixedTriggerCount(2)),
+            0,
+        );
+
+        assert_eq!(core.available_breakpoint_units().unwrap(), 2);
+    }
+}
@@ -10,7 +10,7 @@ pub const PC: CoreRegister = CoreRegister {
     /// This is a CSR register
     id: RegisterId(0x7b1),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::ProgramCounter,
 };
 
 pub(crate) const FP: CoreRegister = CoreRegister {
@@ -21,21 +21,21 @@ pub(crate) const FP: CoreRegister = CoreRegister {
     ],
     id: RegisterId(0x1008),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::Preserve,
 };
 
 pub(crate) const SP: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("x2"), RegisterRole::StackPointer],
     id: RegisterId(0x1002),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::CanonicalFrameAddress,
 };
 
 pub(crate) const RA: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("x1"), RegisterRole::ReturnAddress],
     id: RegisterId(0x1001),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::ReturnAddress,
 };
 
 // S0 and S1 need to be referenceable as constants in other parts of the architecture specific code.
@@ -44,7 +44,7 @@ pub const S1: CoreRegister = CoreRegister {
     roles: &[RegisterRole::Core("x9"), RegisterRole::Other("s1")],
     id: RegisterId(0x1009),
     data_type: RegisterDataType::UnsignedInteger(32),
-    unwind_rule: UnwindRule::Clear,
+    unwind_rule: UnwindRule::Preserve,
 };
 
 pub(crate) static RISCV_CORE_REGSISTERS: Lazy<CoreRegisters> =
@@ -63,13 +63,13 @@ static RISCV_REGISTERS_SET: &[CoreRegister] = &[
         roles: &[RegisterRole::Core("x3"), RegisterRole::Other("gp")],
         id: RegisterId(0x1003),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x4"), RegisterRole::Other("tp")],
         id: RegisterId(0x1004),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x5"), RegisterRole::Other("t0")],
@@ -151,61 +151,61 @@ static RISCV_REGISTERS_SET: &[CoreRegister] = &[
         roles: &[RegisterRole::Core("x18"), RegisterRole::Other("s2")],
         id: RegisterId(0x1012),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x19"), RegisterRole::Other("s3")],
         id: RegisterId(0x1013),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x20"), RegisterRole::Other("s4")],
         id: RegisterId(0x1014),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x21"), RegisterRole::Other("s5")],
         id: RegisterId(0x1015),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x22"), RegisterRole::Other("s6")],
         id: RegisterId(0x1016),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x23"), RegisterRole::Other("s7")],
         id: RegisterId(0x1017),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x24"), RegisterRole::Other("s8")],
         id: RegisterId(0x1018),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x25"), RegisterRole::Other("s9")],
         id: RegisterId(0x1019),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x26"), RegisterRole::Other("s10")],
         id: RegisterId(0x101A),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x27"), RegisterRole::Other("s11")],
         id: RegisterId(0x101B),
         data_type: RegisterDataType::UnsignedInteger(32),
-        unwind_rule: UnwindRule::Clear,
+        unwind_rule: UnwindRule::Preserve,
     },
     CoreRegister {
         roles: &[RegisterRole::Core("x28"), RegisterRole::Other("t3")],
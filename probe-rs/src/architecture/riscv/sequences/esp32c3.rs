@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use super::RiscvDebugSequence;
+use super::{modify_register, RiscvDebugSequence};
 use crate::MemoryInterface;
 
 /// The debug sequence implementation for the ESP32C3.
@@ -23,8 +23,7 @@ impl RiscvDebugSequence for ESP32C3 {
         tracing::info!("Disabling esp32c3 watchdogs...");
         // disable super wdt
         interface.write_word_32(0x600080B0, 0x8F1D312Au32)?; // write protection off
-        let current = interface.read_word_32(0x600080AC)?;
-        interface.write_word_32(0x600080AC, current | 1 << 31)?; // set RTC_CNTL_SWD_AUTO_FEED_EN
+        modify_register(interface, 0x600080AC, 1 << 31, 1 << 31)?; // set RTC_CNTL_SWD_AUTO_FEED_EN
         interface.write_word_32(0x600080B0, 0x0)?; // write protection on
 
         // tg0 wdg
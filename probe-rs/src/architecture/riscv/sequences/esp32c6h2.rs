@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use super::RiscvDebugSequence;
+use super::{modify_register, RiscvDebugSequence};
 use crate::MemoryInterface;
 
 /// The debug sequence implementation for the ESP32C6/ESP32H2.
@@ -23,8 +23,7 @@ impl RiscvDebugSequence for ESP32C6H2 {
         tracing::info!("Disabling esp32c6/esp32h2 watchdogs...");
         // disable super wdt
         interface.write_word_32(0x600B1C20, 0x50D83AA1u32)?; // write protection off
-        let current = interface.read_word_32(0x600B_1C1C)?;
-        interface.write_word_32(0x600B_1C1C, current | 1 << 18)?; // set RTC_CNTL_SWD_AUTO_FEED_EN
+        modify_register(interface, 0x600B_1C1C, 1 << 18, 1 << 18)?; // set RTC_CNTL_SWD_AUTO_FEED_EN
         interface.write_word_32(0x600B1C20, 0x0)?; // write protection on
 
         // tg0 wdg
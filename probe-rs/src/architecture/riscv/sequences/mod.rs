@@ -1,6 +1,7 @@
 //! Debug sequences to operate special requirements RISC-V targets.
 
-use super::communication_interface::RiscvCommunicationInterface;
+use super::{communication_interface::RiscvCommunicationInterface, enumerate_triggers};
+use crate::MemoryInterface;
 use std::sync::Arc;
 
 pub mod esp32c3;
@@ -14,6 +15,18 @@ pub trait RiscvDebugSequence: Send + Sync {
     fn on_connect(&self, _interface: &mut RiscvCommunicationInterface) -> Result<(), crate::Error> {
         Ok(())
     }
+
+    /// Returns the number of hardware triggers available for breakpoints.
+    ///
+    /// The default implementation enumerates the triggers as described in section 5.1
+    /// of the RISC-V debug specification. Chips which do not implement the enumeration
+    /// correctly can override this to report a fixed number instead.
+    fn available_trigger_count(
+        &self,
+        interface: &mut RiscvCommunicationInterface,
+    ) -> Result<u32, crate::Error> {
+        Ok(enumerate_triggers(interface)?)
+    }
 }
 
 /// The default sequences that is used for RISC-V chips that do not specify a specific sequence.
@@ -27,3 +40,37 @@ impl DefaultRiscvSequence {
 }
 
 impl RiscvDebugSequence for DefaultRiscvSequence {}
+
+/// Read-modify-write the 32-bit register at `address`.
+///
+/// The bits selected by `mask` are replaced with the bits of `value`, and all other bits are preserved.
+pub(crate) fn modify_register<M: MemoryInterface + ?Sized>(
+    memory: &mut M,
+    address: u64,
+    mask: u32,
+    value: u32,
+) -> Result<(), crate::Error> {
+    let current = memory.read_word_32(address)?;
+    memory.write_word_32(address, (current & !mask) | (value & mask))
+}
+
+#[cfg(test)]
+mod test {
+    use super::modify_register;
+    use crate::{test::MockMemory, MemoryInterface};
+
+    #[test]
+    fn modify_register_preserves_other_bits() {
+        // The `RTC_CNTL_SWD_CONF` register of the ESP32C6, with some other bits set.
+        let mut memory = MockMemory::new();
+        memory.add_word_range(0x600B_1C1C, &[0x8000_0123]);
+
+        // Set `SWD_AUTO_FEED_EN`, as done when connecting.
+        modify_register(&mut memory, 0x600B_1C1C, 1 << 18, 1 << 18).unwrap();
+        assert_eq!(memory.read_word_32(0x600B_1C1C).unwrap(), 0x8004_0123);
+
+        // Clear a field.
+        modify_register(&mut memory, 0x600B_1C1C, 0x0f00, 0x0000).unwrap();
+        assert_eq!(memory.read_word_32(0x600B_1C1C).unwrap(), 0x8004_0023);
+    }
+}
@@ -421,6 +421,10 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                         response_body.result = format!("{register_value}");
                     } else {
                         // If the expression wasn't pointing to a register, then check if is a local or static variable in our stack_frame
+                        let debug_info = target_core
+                            .core_data
+                            .debug_info
+                            .debug_info_for_frame(stack_frame);
                         let mut variable: Option<probe_rs::debug::Variable> = None;
                         let mut variable_cache: Option<&mut probe_rs::debug::VariableCache> = None;
                         // Search through available caches and stop as soon as the variable is found
@@ -440,7 +444,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                                     // These variables don't have cached children by default, so we need to resolve them before we proceed.
                                     // We check for len() == 1, so unwrap() on first_mut() is safe.
                                     #[allow(clippy::unwrap_used)]
-                                    target_core.core_data.debug_info.cache_deferred_variables(
+                                    debug_info.cache_deferred_variables(
                                         search_cache,
                                         &mut target_core.core,
                                         search_cache.get_children(None)?.first_mut().unwrap(),
@@ -1024,6 +1028,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
             source_location: Option<SourceLocation>,
             pc: RegisterValue,
             is_inlined: bool,
+            is_exception_entry: bool,
         }
 
         let frame_set = if levels == 1 && start_frame == 0 {
@@ -1072,6 +1077,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
             source_location: stack_frame.source_location.clone(),
             pc: stack_frame.pc,
             is_inlined: stack_frame.is_inlined,
+            is_exception_entry: stack_frame.is_exception_entry,
         })
         .collect::<Vec<PartialStackFrameData>>();
 
@@ -1118,7 +1124,15 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                     end_column: None,
                     end_line: None,
                     module_id: None,
-                    presentation_hint: Some("normal".to_owned()),
+                    // Exception entries are rendered as a label, to show the boundary of the exception handler.
+                    presentation_hint: Some(
+                        if frame.is_exception_entry {
+                            "label"
+                        } else {
+                            "normal"
+                        }
+                        .to_owned(),
+                    ),
                     can_restart: Some(false),
                     instruction_pointer_reference: Some(format!("{}", frame.pc)),
                 }
@@ -1402,7 +1416,13 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
             let mut variable_cache: Option<&mut probe_rs::debug::VariableCache> = None;
             let mut stack_frame_registers: Option<&DebugRegisters> = None;
             let mut frame_base: Option<u64> = None;
+            let mut debug_info = &target_core.core_data.debug_info;
             for stack_frame in target_core.core_data.stack_frames.iter_mut() {
+                // The variables of the frame have to be expanded with the debug information of its binary.
+                let frame_debug_info = target_core
+                    .core_data
+                    .debug_info
+                    .debug_info_for_frame(stack_frame);
                 if let Some(search_cache) = &mut stack_frame.local_variables {
                     if let Some(search_variable) =
                         search_cache.get_variable_by_key(arguments.variables_reference)
@@ -1411,6 +1431,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                         variable_cache = Some(search_cache);
                         stack_frame_registers = Some(&stack_frame.registers);
                         frame_base = stack_frame.frame_base;
+                        debug_info = frame_debug_info;
                         break;
                     }
                 }
@@ -1422,6 +1443,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                         variable_cache = Some(search_cache);
                         stack_frame_registers = Some(&stack_frame.registers);
                         frame_base = stack_frame.frame_base;
+                        debug_info = frame_debug_info;
                         break;
                     }
                 }
@@ -1462,7 +1484,7 @@ impl<P: ProtocolAdapter> DebugAdapter<P> {
                         && !variable_cache.has_children(parent_variable)?
                     {
                         if let Some(stack_frame_registers) = stack_frame_registers {
-                            target_core.core_data.debug_info.cache_deferred_variables(
+                            debug_info.cache_deferred_variables(
                                 variable_cache,
                                 &mut target_core.core,
                                 parent_variable,
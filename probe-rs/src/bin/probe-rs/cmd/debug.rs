@@ -14,7 +14,8 @@ use probe_rs::DebugProbeError;
 use probe_rs::{
     architecture::arm::Dump,
     debug::{
-        debug_info::DebugInfo, registers::DebugRegisters, stack_frame::StackFrame, VariableName,
+        debug_info::DebugInfo, registers::DebugRegisters, stack_frame::StackFrame, UnwindOptions,
+        VariableName,
     },
     Core, CoreType, InstructionSet, MemoryInterface, RegisterId, RegisterValue,
 };
@@ -496,13 +497,38 @@ impl DebugCli {
 
         cli.add_command(Command {
             name: "bt",
-            help_text: "Show backtrace",
+            help_text: "Show backtrace. Use `bt --no-inline` to skip the frames of inlined functions, and `bt --registers [NAME...]` to include the registers of each frame, optionally only the given ones",
+
+            function: |cli_data, args| {
+                let mut options = UnwindOptions::new();
+                let mut register_filter = None;
+
+                let mut args = args.iter();
+                while let Some(arg) = args.next() {
+                    match *arg {
+                        "--no-inline" => options = options.set_find_inlined(false),
+                        // All remaining arguments are register names.
+                        "--registers" => {
+                            register_filter = Some(
+                                args.by_ref()
+                                    .map(|name| name.to_string())
+                                    .collect::<Vec<_>>(),
+                            );
+                        }
+                        other => {
+                            println!("Unknown argument '{other}' for backtrace.");
+                            return Ok(CliState::Continue);
+                        }
+                    }
+                }
 
-            function: |cli_data, _args| {
                 match cli_data.state {
                     DebugState::Halted(ref mut halted_state) => {
                         if let Some(di) = &mut cli_data.debug_info {
-                            halted_state.stack_frames = di.unwind(&mut cli_data.core).unwrap();
+                            halted_state.stack_frames = di
+                                .unwind_with_options(&mut cli_data.core, options)
+                                .unwrap()
+                                .frames;
 
                             halted_state.frame_indices =
                                 halted_state.stack_frames.iter().map(|sf| sf.id).collect();
@@ -545,6 +571,14 @@ impl DebugCli {
                                         println!();
                                     }
                                 }
+
+                                if let Some(register_filter) = &register_filter {
+                                    for line in
+                                        frame.registers_table(register_filter).to_string().lines()
+                                    {
+                                        println!("       {line}");
+                                    }
+                                }
                             }
 
                             println!();
@@ -670,6 +704,12 @@ impl DebugCli {
                                 return Ok(CliState::Continue);
                             };
 
+                        // The variables of the frame have to be expanded with the debug information of its binary.
+                        let debug_info = cli_data
+                            .debug_info
+                            .as_ref()
+                            .map(|debug_info| debug_info.debug_info_for_frame(current_frame));
+
                         let local_variable_cache = if let Some(local_variable_cache) =
                             &mut current_frame.local_variables
                         {
@@ -686,18 +726,13 @@ impl DebugCli {
                             if locals.variable_node_type.is_deferred()
                                 && !local_variable_cache.has_children(&locals)?
                             {
-                                if let Err(error) = cli_data
-                                    .debug_info
-                                    .as_ref()
-                                    .unwrap()
-                                    .cache_deferred_variables(
-                                        local_variable_cache,
-                                        &mut cli_data.core,
-                                        &mut locals,
-                                        &current_frame.registers,
-                                        current_frame.frame_base,
-                                    )
-                                {
+                                if let Err(error) = debug_info.unwrap().cache_deferred_variables(
+                                    local_variable_cache,
+                                    &mut cli_data.core,
+                                    &mut locals,
+                                    &current_frame.registers,
+                                    current_frame.frame_base,
+                                ) {
                                     println!("Failed to cache local variables: {error}");
                                     return Ok(CliState::Continue);
                                 }
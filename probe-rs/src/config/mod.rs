@@ -36,9 +36,12 @@ pub use registry::{
     add_target_from_yaml, families, get_target_and_family_by_name, get_target_by_name,
     get_targets_by_family_name, search_chips, RegistryError,
 };
-pub use target::{DebugSequence, Target, TargetParseError, TargetSelector};
+pub use target::{
+    DebugCapabilities, DebugSequence, FlashSector, RamRequirements, Target, TargetParseError,
+    TargetSelector,
+};
 
 // Crate-internal API
 pub(crate) use chip_info::ChipInfo;
-pub(crate) use registry::get_target_by_chip_info;
+pub(crate) use registry::{get_target_by_chip_info, get_target_by_idcode};
 pub(crate) use target::CoreExt;
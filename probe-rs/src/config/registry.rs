@@ -105,11 +105,13 @@ fn add_generic_targets(vec: &mut Vec<ChipFamily>) {
                     name: "core".to_owned(),
                     core_type: CoreType::Riscv,
                     core_access_options: CoreAccessOptions::Riscv(RiscvCoreAccessOptions {}),
+                    reset_domain: None,
                 }],
                 memory_map: vec![],
                 flash_algorithms: vec![],
                 rtt_scan_ranges: None,
                 scan_chain: Some(vec![]),
+                max_debug_clock_khz: None,
             }],
             flash_algorithms: vec![],
             source: TargetDescriptionSource::Generic,
@@ -329,6 +331,30 @@ impl Registry {
         self.get_target(family, chip)
     }
 
+    fn get_target_by_idcode(&self, idcode: u32) -> Result<Target, RegistryError> {
+        let Some(name) = Target::identify_from_idcode(idcode) else {
+            tracing::debug!("Unknown IDCODE {:#010x}, unable to determine chip", idcode);
+            return Err(RegistryError::ChipAutodetectFailed);
+        };
+
+        // Only an exact match identifies the chip, e.g. `esp32c3` must not select `esp32c3-flashloader`.
+        let mut identified_chips = self.families.iter().flat_map(|family| {
+            family
+                .variants()
+                .iter()
+                .filter(|chip| chip.name.eq_ignore_ascii_case(&name))
+                .map(move |chip| (family, chip))
+        });
+
+        match (identified_chips.next(), identified_chips.next()) {
+            (Some((family, chip)), None) => self.get_target(family, chip),
+            _ => {
+                tracing::debug!("Chip {} for IDCODE {:#010x} is not unique", name, idcode);
+                Err(RegistryError::ChipAutodetectFailed)
+            }
+        }
+    }
+
     fn get_target(&self, family: &ChipFamily, chip: &Chip) -> Result<Target, RegistryError> {
         // The validity of the given `ChipFamily` is checked in the constructor.
         Target::new(family, &chip.name)
@@ -389,6 +415,11 @@ pub(crate) fn get_target_by_chip_info(chip_info: ChipInfo) -> Result<Target, Reg
     REGISTRY.lock().unwrap().get_target_by_chip_info(chip_info)
 }
 
+/// Try to retrieve a target based on the JTAG IDCODE read from a target.
+pub(crate) fn get_target_by_idcode(idcode: u32) -> Result<Target, RegistryError> {
+    REGISTRY.lock().unwrap().get_target_by_idcode(idcode)
+}
+
 /// Parse a target description and add the contained targets
 /// to the internal target registry.
 ///
@@ -461,6 +492,21 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn try_fetch_by_idcode() {
+        let registry = Registry::from_builtin_families();
+
+        // The ESP32-C3 has a flash loader variant, which must not make the chip ambiguous.
+        let target = registry.get_target_by_idcode(0x0000_5c25).unwrap();
+        assert_eq!(target.name, "esp32c3");
+
+        // The debug port of a Cortex-M4 does not identify the chip.
+        assert!(matches!(
+            registry.get_target_by_idcode(0x4ba0_0477),
+            Err(RegistryError::ChipAutodetectFailed)
+        ));
+    }
+
     #[test]
     fn try_fetch_not_found() {
         let registry = Registry::from_builtin_families();
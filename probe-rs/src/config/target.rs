@@ -1,5 +1,10 @@
 use super::{
-    Core, MemoryRegion, RawFlashAlgorithm, RegistryError, ScanChainElement, TargetDescriptionSource,
+    Core, MemoryRegion, RamRegion, RawFlashAlgorithm, RegistryError, ScanChainElement,
+    TargetDescriptionSource,
+};
+use crate::architecture::arm::core::registers::{
+    aarch32::AARCH32_CORE_REGSISTERS, aarch64::AARCH64_CORE_REGSISTERS,
+    cortex_m::CORTEX_M_CORE_REGISTERS,
 };
 use crate::architecture::arm::{
     ap::MemoryAp,
@@ -19,10 +24,13 @@ use crate::architecture::arm::{
     },
     ApAddress, DpAddress,
 };
+use crate::architecture::riscv::registers::RISCV_CORE_REGSISTERS;
 use crate::architecture::riscv::sequences::{esp32c3::ESP32C3, esp32c6h2::ESP32C6H2};
 use crate::architecture::riscv::sequences::{DefaultRiscvSequence, RiscvDebugSequence};
-use crate::flashing::FlashLoader;
-use probe_rs_target::{Architecture, ChipFamily, MemoryRange};
+use crate::flashing::{FlashAlgorithm, FlashError, FlashLoader};
+use crate::CoreRegisters;
+use probe_rs_target::{Architecture, ChipFamily, CoreType, MemoryRange};
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::architecture::arm::sequences::DefaultArmSequence;
@@ -53,6 +61,53 @@ pub struct Target {
     /// manually in the target.yaml file. It is used by some probes to determine
     /// the number devices in the scan chain and their ir lengths.
     pub scan_chain: Option<Vec<ScanChainElement>>,
+    /// The maximum debug clock in kHz the chip description recommends, if any.
+    pub max_debug_clock_khz: Option<u32>,
+}
+
+/// A group of contiguous, equally sized flash sectors, as returned by [Target::flash_geometry].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlashSector {
+    /// The name of the NVM region which contains the sectors.
+    pub region_name: Option<String>,
+    /// The address range covered by the sectors.
+    pub address_range: Range<u64>,
+    /// The size of each sector, which is the smallest unit that can be erased.
+    pub sector_size: u64,
+    /// The size of each page, which is the unit in which the flash is programmed.
+    pub page_size: u32,
+}
+
+/// The RAM used to run a flash algorithm, as returned by [Target::flash_algorithm_ram_requirements].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RamRequirements {
+    /// The name of the RAM region the flash algorithm is loaded into.
+    pub region_name: Option<String>,
+    /// The address range used by the flash algorithm.
+    pub address_range: Range<u64>,
+    /// The size of the code of the flash algorithm, including the header added by probe-rs, in bytes.
+    pub code_size: u64,
+    /// The size of the stack of the flash algorithm, in bytes.
+    pub stack_size: u64,
+    /// The size of the buffer for the data of a single page, in bytes.
+    pub data_size: u64,
+}
+
+/// The debug features of a core, as returned by [Target::debug_capabilities_for_core].
+///
+/// These are derived from the core type only. For Arm cores, the comparator counts are upper bounds,
+/// a core can implement fewer of them. The number of hardware breakpoints actually implemented
+/// by a core can be read with [crate::Core::available_breakpoint_units].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugCapabilities {
+    /// The core can halt on reset or on exceptions, see [crate::Core::enable_vector_catch].
+    pub vector_catch: bool,
+    /// The number of hardware breakpoint comparators, see [Target::debug_capabilities_for_core].
+    pub hardware_breakpoints: u32,
+    /// The number of hardware watchpoint comparators, see [Target::debug_capabilities_for_core].
+    pub hardware_watchpoints: u32,
+    /// The core supports debug monitor mode, where debug events are handled by an exception instead of halting the core.
+    pub monitor_mode: bool,
 }
 
 impl std::fmt::Debug for Target {
@@ -83,7 +138,7 @@ impl Target {
     /// In any case, this function will always just use the architecture of the first core in any further functionality.
     /// In practice we have never encountered a [`Chip`] with mixed architectures so this should not be of issue.
     ///
-    /// Furthermore, the user has to ensure that any [`Core`] in `flash_algorithms[n].cores` is present in `cores` as well.
+    /// Any [`Core`] in `flash_algorithms[n].cores` has to be present in `cores` as well, otherwise an error is returned.
     pub(crate) fn new(
         family: &ChipFamily,
         chip_name: impl AsRef<str>,
@@ -212,11 +267,13 @@ impl Target {
             }
             None => {
                 // By default we use all of the RAM ranges from the
-                // memory map.
+                // memory map, except the ones which are not suitable for RTT.
                 chip.memory_map
                     .iter()
                     .filter_map(|region| match region {
-                        MemoryRegion::Ram(region) => Some(region.range.clone()),
+                        MemoryRegion::Ram(region) if is_default_rtt_scan_region(region) => {
+                            Some(region.range.clone())
+                        }
                         _ => None,
                     })
                     .collect()
@@ -232,9 +289,22 @@ impl Target {
             debug_sequence,
             rtt_scan_regions,
             scan_chain: chip.scan_chain.clone(),
+            max_debug_clock_khz: chip.max_debug_clock_khz,
         })
     }
 
+    /// Create a new target from a target description in YAML format.
+    ///
+    /// The YAML has to describe a complete [`ChipFamily`], from which the variant named `chip_name` is selected.
+    /// The family is validated in the same way as targets from the registry.
+    ///
+    /// This is useful for quickly iterating on a new target description, without adding it to the registry.
+    pub fn from_yaml_str(yaml: &str, chip_name: &str) -> Result<Target, RegistryError> {
+        let family: ChipFamily = serde_yaml::from_str(yaml)?;
+
+        Target::new(&family, chip_name)
+    }
+
     /// Get the architecture of the target
     pub fn architecture(&self) -> Architecture {
         let target_arch = self.cores[0].core_type.architecture();
@@ -251,6 +321,26 @@ impl Target {
         target_arch
     }
 
+    /// The types of all the cores of the target, in the same order as [`Target::cores`].
+    pub fn core_types(&self) -> Vec<CoreType> {
+        self.cores.iter().map(|core| core.core_type).collect()
+    }
+
+    /// Returns true if any core of the target can have a floating point unit, based on its core type.
+    ///
+    /// The FPU is optional for most core types, so this has to be confirmed with
+    /// [`Core::fpu_support`](crate::Core::fpu_support) once attached.
+    pub fn may_have_fpu(&self) -> bool {
+        self.cores.iter().any(|core| core.core_type.may_have_fpu())
+    }
+
+    /// Returns true if all the cores of the target support the Thumb-2 instruction set.
+    pub fn supports_thumb2(&self) -> bool {
+        self.cores
+            .iter()
+            .all(|core| core.core_type.supports_thumb2())
+    }
+
     /// Return the default core of the target, usually the first core.
     ///
     /// This core should be used for operations such as debug_unlock,
@@ -260,6 +350,22 @@ impl Target {
         &self.cores[0]
     }
 
+    /// The recommended maximum debug clock in kHz for this target, if it is limited.
+    ///
+    /// This is the lower of the limit given in the chip description and the limit of the debug sequence.
+    /// `None` means that there is no known limit, and the speed of the debug probe should be used.
+    pub fn recommended_debug_clock(&self) -> Option<u32> {
+        let sequence_limit = match &self.debug_sequence {
+            DebugSequence::Arm(sequence) => sequence.max_debug_clock_khz(),
+            DebugSequence::Riscv(_) => None,
+        };
+
+        match (self.max_debug_clock_khz, sequence_limit) {
+            (Some(chip_limit), Some(sequence_limit)) => Some(chip_limit.min(sequence_limit)),
+            (chip_limit, sequence_limit) => chip_limit.or(sequence_limit),
+        }
+    }
+
     /// Source description of this target.
     pub fn source(&self) -> &TargetDescriptionSource {
         &self.source
@@ -276,11 +382,289 @@ impl Target {
         self.flash_algorithms.iter().find(|a| a.name == name)
     }
 
+    /// The flash algorithms which can run on the core with index `core_index`.
+    ///
+    /// On multi-core targets, a flash algorithm has to run on one of the cores it is assigned to.
+    /// Returns an empty list if there is no such core.
+    pub fn flash_algorithms_for_core(&self, core_index: usize) -> Vec<&RawFlashAlgorithm> {
+        let Some(core) = self.cores.get(core_index) else {
+            return Vec::new();
+        };
+
+        self.flash_algorithms
+            .iter()
+            .filter(|algorithm| algorithm.cores.contains(&core.name))
+            .collect()
+    }
+
+    /// The RAM region used to run `algorithm` on the core with index `core_index`.
+    ///
+    /// If the algorithm has a forced load address, the RAM region has to contain it. Otherwise, the CMSIS-Pack spec
+    /// says to use the first available RAM region. In theory, it should be the "first listed in the pack", but the
+    /// process of reading from the pack files obfuscates the list order, so we use the first one in the target
+    /// description which can be accessed by the core.
+    /// - See <https://open-cmsis-pack.github.io/Open-CMSIS-Pack-Spec/main/html/pdsc_family_pg.html#element_memory>.
+    pub(crate) fn flash_algorithm_ram(
+        &self,
+        core_index: usize,
+        algorithm: &RawFlashAlgorithm,
+    ) -> Result<&RamRegion, FlashError> {
+        let no_ram = || FlashError::NoRamDefined {
+            name: self.name.clone(),
+        };
+        let core_name = &self.cores.get(core_index).ok_or_else(no_ram)?.name;
+
+        self.memory_map
+            .iter()
+            .filter_map(|region| match region {
+                MemoryRegion::Ram(ram) => Some(ram),
+                _ => None,
+            })
+            .find(|ram| {
+                ram.cores.contains(core_name)
+                    && algorithm
+                        .load_address
+                        .map_or(true, |load_address| ram.range.contains(&load_address))
+            })
+            .ok_or_else(no_ram)
+    }
+
+    /// The RAM needed to run `algorithm` on the core with index `core_index`, i.e. its code, stack and a buffer for
+    /// one page of data.
+    ///
+    /// The RAM region and the layout are the same as when flashing, including the reduction of the stack size if
+    /// the algorithm doesn't fit otherwise. An error is returned if there is no suitable RAM region, or if the
+    /// algorithm doesn't fit into it even with the smallest stack. Additional page buffers are only used when
+    /// there is space left in the region, so they are not included.
+    pub fn flash_algorithm_ram_requirements(
+        &self,
+        core_index: usize,
+        algorithm: &RawFlashAlgorithm,
+    ) -> Result<RamRequirements, FlashError> {
+        let ram = self.flash_algorithm_ram(core_index, algorithm)?;
+        let layout = FlashAlgorithm::ram_layout(algorithm, ram, self.architecture())?;
+
+        let data_size = algorithm.flash_properties.page_size as u64;
+        let end = layout.load_address + layout.code_size + layout.stack_size + data_size;
+
+        Ok(RamRequirements {
+            region_name: ram.name.clone(),
+            address_range: layout.load_address..end,
+            code_size: layout.code_size,
+            stack_size: layout.stack_size,
+            data_size,
+        })
+    }
+
     /// Gets the core index from the core name
     pub(crate) fn core_index_by_name(&self, name: &str) -> Option<usize> {
         self.cores.iter().position(|c| c.name == name)
     }
 
+    /// The memory regions of this target, ordered by their start address.
+    ///
+    /// This is a sorted view of `memory_map`, which keeps the order of the target description.
+    /// Overlapping regions are not merged, use [Target::overlapping_memory_regions] to find them.
+    pub fn sorted_memory_map(&self) -> Vec<&MemoryRegion> {
+        let mut regions: Vec<&MemoryRegion> = self.memory_map.iter().collect();
+        regions.sort_by_key(|region| region.address_range().start);
+        regions
+    }
+
+    /// All pairs of memory regions with overlapping address ranges, in the order of [Target::sorted_memory_map].
+    pub fn overlapping_memory_regions(&self) -> Vec<(&MemoryRegion, &MemoryRegion)> {
+        let regions = self.sorted_memory_map();
+        let mut overlapping = Vec::new();
+
+        for (index, region) in regions.iter().enumerate() {
+            let range = region.address_range();
+
+            // The regions are sorted, so only the following regions which start before the end of this one can overlap.
+            for other in regions[index + 1..]
+                .iter()
+                .take_while(|other| other.address_range().start < range.end)
+            {
+                if !other.address_range().is_empty() && !range.is_empty() {
+                    overlapping.push((*region, *other));
+                }
+            }
+        }
+
+        overlapping
+    }
+
+    /// The sector and page layout of every NVM region of this target, e.g. for per-sector progress reporting.
+    ///
+    /// The layout is taken from the flash algorithm which would be used to program the region.
+    /// Regions with sectors of different sizes are reported as multiple entries, one for each group
+    /// of equally sized sectors. Regions without a usable flash algorithm are skipped.
+    pub fn flash_geometry(&self) -> Vec<FlashSector> {
+        let mut geometry = Vec::new();
+
+        for region in self.memory_map.iter() {
+            let MemoryRegion::Nvm(region) = region else {
+                continue;
+            };
+
+            let Ok(algorithm) = FlashLoader::get_flash_algorithm_for_region(region, self) else {
+                continue;
+            };
+            let properties = &algorithm.flash_properties;
+
+            for (index, sector) in properties.sectors.iter().enumerate() {
+                // Each sector description applies up to the start of the next one, or the end of the flash.
+                let start = properties.address_range.start + sector.address;
+                let end = properties
+                    .sectors
+                    .get(index + 1)
+                    .map_or(properties.address_range.end, |next| {
+                        properties.address_range.start + next.address
+                    });
+
+                let start = start.max(region.range.start);
+                let end = end.min(region.range.end);
+
+                if start < end {
+                    geometry.push(FlashSector {
+                        region_name: region.name.clone(),
+                        address_range: start..end,
+                        sector_size: sector.size,
+                        page_size: properties.page_size,
+                    });
+                }
+            }
+        }
+
+        geometry
+    }
+
+    /// The indices of the cores which can be used for RTT, in core order.
+    ///
+    /// RTT requires a core with a usable memory access path, which can access at least one RAM region.
+    /// For ARM cores, this is the memory AP of the core. RISC-V cores access the memory through their debug module,
+    /// which is only used if the target is a RISC-V target, so a RISC-V coprocessor on an ARM target is excluded.
+    pub fn rtt_capable_cores(&self) -> Vec<usize> {
+        let architecture = self.architecture();
+
+        self.cores
+            .iter()
+            .enumerate()
+            .filter(|(_, core)| {
+                let has_memory_access = match core.core_access_options {
+                    probe_rs_target::CoreAccessOptions::Arm(_) => core.memory_ap().is_some(),
+                    probe_rs_target::CoreAccessOptions::Riscv(_) => {
+                        architecture == Architecture::Riscv
+                    }
+                };
+
+                has_memory_access
+                    && self.memory_map.iter().any(|region| {
+                        matches!(region, MemoryRegion::Ram(ram) if ram.cores.contains(&core.name))
+                    })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The address range which the stack of the core with index `core_index` can occupy.
+    ///
+    /// This is derived from the RAM regions the core can access. If any of them is marked with `is_stack`,
+    /// only the marked regions are used. For multiple regions, the smallest range containing all of them is returned.
+    /// Returns `None` if there is no such core, or if the core can't access any RAM.
+    pub fn stack_region_for_core(&self, core_index: usize) -> Option<Range<u64>> {
+        let core = self.cores.get(core_index)?;
+
+        let ram_regions: Vec<&RamRegion> = self
+            .memory_map
+            .iter()
+            .filter_map(|region| match region {
+                MemoryRegion::Ram(ram) if ram.cores.contains(&core.name) => Some(ram),
+                _ => None,
+            })
+            .collect();
+
+        let has_stack_regions = ram_regions.iter().any(|ram| ram.is_stack);
+        let stack_regions = ram_regions
+            .iter()
+            .filter(|ram| ram.is_stack || !has_stack_regions);
+
+        let start = stack_regions.clone().map(|ram| ram.range.start).min()?;
+        let end = stack_regions.map(|ram| ram.range.end).max()?;
+
+        Some(start..end)
+    }
+
+    /// The debug features of the core with index `core_index`, or `None` if there is no such core.
+    ///
+    /// The capabilities are derived from the core type, and for Arm cores the comparator counts are upper bounds:
+    /// - ARMv6-M allows at most 4 breakpoint and 2 watchpoint comparators.
+    /// - For ARMv7-M and ARMv8-M, the counts are those of the largest Arm implementations (Cortex-M7, and
+    ///   Cortex-M55 or Cortex-M85 respectively). The ARMv8-M baseline profile (e.g. Cortex-M23) has fewer
+    ///   comparators and no debug monitor.
+    /// - ARMv7-A and ARMv8-A allow at most 16 breakpoints and 16 watchpoints.
+    /// - The number of RISC-V triggers is not limited by the debug specification. The trigger modules are
+    ///   shared between breakpoints and watchpoints, and the counts are only the minimum expected of a core.
+    ///
+    /// Use [crate::Core::available_breakpoint_units] to read the number of breakpoints a core implements.
+    pub fn debug_capabilities_for_core(&self, core_index: usize) -> Option<DebugCapabilities> {
+        let core = self.cores.get(core_index)?;
+
+        let (hardware_breakpoints, hardware_watchpoints, monitor_mode) = match core.core_type {
+            CoreType::Armv6m => (4, 2, false),
+            CoreType::Armv7m | CoreType::Armv7em => (8, 4, true),
+            CoreType::Armv8m => (8, 8, true),
+            CoreType::Armv7a | CoreType::Armv8a => (16, 16, true),
+            CoreType::Riscv => (2, 2, false),
+        };
+
+        Some(DebugCapabilities {
+            // Vector catch is only supported for Cortex-M cores.
+            vector_catch: core.core_type.is_cortex_m(),
+            hardware_breakpoints,
+            hardware_watchpoints,
+            monitor_mode,
+        })
+    }
+
+    /// The description of the registers of the core with index `core_index`, or `None` if there is no such core.
+    ///
+    /// The register set is selected by the core type, so that [crate::debug::DebugRegisters] can be built for a core
+    /// without connecting to it, e.g. to unwind the stack from a snapshot of the registers. Optional registers, like
+    /// the floating point registers, are not included, and ARMv8-A cores are assumed to be in AArch64 state.
+    pub fn core_registers_for_core(&self, core_index: usize) -> Option<&'static CoreRegisters> {
+        let core = self.cores.get(core_index)?;
+
+        let registers: &'static CoreRegisters = match core.core_type {
+            CoreType::Armv6m | CoreType::Armv7m | CoreType::Armv7em | CoreType::Armv8m => {
+                &CORTEX_M_CORE_REGISTERS
+            }
+            CoreType::Armv7a => &AARCH32_CORE_REGSISTERS,
+            CoreType::Armv8a => &AARCH64_CORE_REGSISTERS,
+            CoreType::Riscv => &RISCV_CORE_REGSISTERS,
+        };
+
+        Some(registers)
+    }
+
+    /// The indices of the cores, grouped by the reset domain they belong to.
+    ///
+    /// Resetting a core also resets all other cores in its domain. The domains are taken from the
+    /// `reset_domain` of the cores in the chip description. Cores without a `reset_domain` share one domain,
+    /// so by default all cores are reset together. The domains are returned in the order of their first core.
+    pub fn reset_domains(&self) -> Vec<Vec<usize>> {
+        let mut domains: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+
+        for (index, core) in self.cores.iter().enumerate() {
+            let domain = core.reset_domain.as_deref();
+            match domains.iter_mut().find(|(name, _)| *name == domain) {
+                Some((_, cores)) => cores.push(index),
+                None => domains.push((domain, vec![index])),
+            }
+        }
+
+        domains.into_iter().map(|(_, cores)| cores).collect()
+    }
+
     /// Gets the first found [MemoryRegion] that contains the given address
     pub(crate) fn get_memory_region_by_address(&self, address: u64) -> Option<&MemoryRegion> {
         self.memory_map.iter().find(|region| match region {
@@ -290,6 +674,37 @@ impl Target {
             _ => false,
         })
     }
+
+    /// Identify the chip from a JTAG IDCODE, as used for [`TargetSelector::Auto`].
+    ///
+    /// The returned name is the exact name of the chip in the registry, e.g. `esp32c3`.
+    /// The version field of the IDCODE is ignored, so all silicon revisions are matched.
+    ///
+    /// Only TAPs with a chip specific IDCODE can be identified. The IDCODE of an ARM debug port only
+    /// identifies the debug port itself, so `None` is returned for it.
+    pub fn identify_from_idcode(idcode: u32) -> Option<String> {
+        let chip = match idcode & 0x0fff_ffff {
+            0x0000_5c25 => "esp32c3",
+            0x0000_dc25 => "esp32c6",
+            0x0001_0c25 => "esp32h2",
+            _ => return None,
+        };
+
+        Some(chip.to_string())
+    }
+}
+
+/// Decides if a RAM region is scanned for an RTT header, when the chip description
+/// does not specify `rtt_scan_ranges`.
+///
+/// Regions marked as `is_reserved` are excluded, because they are not used by the
+/// application, so they will never contain the RTT header. Scanning them would only
+/// slow down the attach, and could lead to false matches.
+///
+/// Boot memory is not excluded, because on chips which run code from RAM, the RTT
+/// header is usually located in the same region.
+fn is_default_rtt_scan_region(region: &RamRegion) -> bool {
+    !region.is_reserved
 }
 
 /// Selector for the debug target.
@@ -369,3 +784,638 @@ impl CoreExt for Core {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use probe_rs_target::{
+        ArmCoreAccessOptions, Chip, CoreAccessOptions, FlashProperties, GenericRegion, NvmRegion,
+        RiscvCoreAccessOptions, SectorDescription,
+    };
+
+    const TEST_FAMILY: &str = r#"
+name: TEST_FAMILY
+variants:
+  - name: TEST_CHIP
+    cores:
+      - name: main
+        type: armv7em
+        core_access_options:
+          !Arm
+            ap: 0x0
+            psel: 0x0
+    memory_map:
+      - !Nvm
+          range:
+            start: 0x08000000
+            end: 0x08100000
+          is_boot_memory: true
+          cores:
+            - main
+      - !Ram
+          range:
+            start: 0x20000000
+            end: 0x20020000
+          cores:
+            - main
+flash_algorithms: []
+"#;
+
+    /// The chip family described by [`TEST_FAMILY`], for tests which modify it.
+    fn test_family() -> ChipFamily {
+        serde_yaml::from_str(TEST_FAMILY).unwrap()
+    }
+
+    /// The `TEST_CHIP` variant of `family`.
+    fn test_chip(family: &mut ChipFamily) -> &mut Chip {
+        &mut family.variants[0]
+    }
+
+    /// A RAM region which can be accessed by the `main` core.
+    fn ram(range: Range<u64>) -> RamRegion {
+        RamRegion {
+            name: None,
+            range,
+            is_boot_memory: false,
+            is_reserved: false,
+            is_stack: false,
+            cores: vec!["main".to_string()],
+        }
+    }
+
+    /// An additional ARM core, which is accessed through `ap`.
+    fn arm_core(name: &str, ap: u8) -> Core {
+        Core {
+            name: name.to_string(),
+            core_type: CoreType::Armv7em,
+            core_access_options: CoreAccessOptions::Arm(ArmCoreAccessOptions {
+                ap,
+                ..Default::default()
+            }),
+            reset_domain: None,
+        }
+    }
+
+    /// Change the type of the `main` core of `family`, with matching access options.
+    fn set_core_type(family: &mut ChipFamily, core_type: CoreType) {
+        let core = &mut test_chip(family).cores[0];
+        core.core_type = core_type;
+        core.core_access_options = match core_type.architecture() {
+            // Cortex-A cores need the base addresses of their debug components.
+            Architecture::Arm => CoreAccessOptions::Arm(ArmCoreAccessOptions {
+                debug_base: Some(0x8001_0000),
+                cti_base: Some(0x8002_0000),
+                ..Default::default()
+            }),
+            Architecture::Riscv => CoreAccessOptions::Riscv(RiscvCoreAccessOptions {}),
+        };
+    }
+
+    /// The `TEST_CHIP` target, after `modify` was applied to [`test_family`].
+    fn test_target(modify: impl FnOnce(&mut ChipFamily)) -> Target {
+        let mut family = test_family();
+        modify(&mut family);
+        Target::new(&family, "TEST_CHIP").unwrap()
+    }
+
+    /// A flash algorithm for the flash of `TEST_CHIP`, which runs on `core`.
+    fn flash_algorithm(name: &str, core: &str) -> RawFlashAlgorithm {
+        RawFlashAlgorithm {
+            name: name.to_string(),
+            description: name.to_string(),
+            instructions: vec![0; 4],
+            pc_init: Some(0x1),
+            pc_uninit: Some(0x3),
+            pc_program_page: 0x5,
+            pc_erase_sector: 0x7,
+            data_section_offset: 0x10,
+            flash_properties: FlashProperties {
+                address_range: 0x0800_0000..0x0810_0000,
+                page_size: 0x400,
+                erased_byte_value: 0xff,
+                program_page_timeout: 100,
+                erase_sector_timeout: 2000,
+                sectors: vec![SectorDescription {
+                    size: 0x4000,
+                    address: 0x0,
+                }],
+            },
+            cores: vec![core.to_string()],
+            ..Default::default()
+        }
+    }
+
+    /// Add `algorithm` to `family`, and use it for `TEST_CHIP`.
+    fn add_flash_algorithm(family: &mut ChipFamily, algorithm: RawFlashAlgorithm) {
+        test_chip(family)
+            .flash_algorithms
+            .push(algorithm.name.clone());
+        family.flash_algorithms.push(algorithm);
+    }
+
+    #[test]
+    fn target_from_yaml_str() {
+        let target = Target::from_yaml_str(TEST_FAMILY, "TEST_CHIP").unwrap();
+
+        assert_eq!(target.name, "TEST_CHIP");
+        assert_eq!(target.architecture(), Architecture::Arm);
+        assert_eq!(target.memory_map.len(), 2);
+        assert_eq!(target.rtt_scan_regions, vec![0x2000_0000..0x2002_0000]);
+    }
+
+    #[test]
+    fn target_from_yaml_str_unknown_chip() {
+        assert!(matches!(
+            Target::from_yaml_str(TEST_FAMILY, "NOT_A_CHIP"),
+            Err(RegistryError::ChipNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn target_from_yaml_str_invalid_yaml() {
+        assert!(matches!(
+            Target::from_yaml_str("name: [", "TEST_CHIP"),
+            Err(RegistryError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn target_from_invalid_family() {
+        let mut family = test_family();
+        test_chip(&mut family).cores[0].name = "other".to_string();
+
+        // The memory regions are now assigned to a non-existent core.
+        assert!(matches!(
+            Target::new(&family, "TEST_CHIP"),
+            Err(RegistryError::InvalidChipFamilyDefinition(_, _))
+        ));
+    }
+
+    #[test]
+    fn recommended_debug_clock() {
+        let cases: [(fn(&mut ChipFamily), Option<u32>); 2] = [
+            // Without a limit in the chip description, the speed of the probe is used.
+            (|_| {}, None),
+            (
+                |family| test_chip(family).max_debug_clock_khz = Some(1000),
+                Some(1000),
+            ),
+        ];
+
+        for (modify, expected) in cases {
+            assert_eq!(test_target(modify).recommended_debug_clock(), expected);
+        }
+    }
+
+    #[test]
+    fn rtt_scan_regions() {
+        let cases: [(&str, fn(&mut ChipFamily), Vec<Range<u64>>); 3] = [
+            ("all RAM", |_| {}, vec![0x2000_0000..0x2002_0000]),
+            (
+                "reserved RAM is excluded",
+                |family| {
+                    test_chip(family)
+                        .memory_map
+                        .push(MemoryRegion::Ram(RamRegion {
+                            is_reserved: true,
+                            ..ram(0x2002_0000..0x2003_0000)
+                        }))
+                },
+                vec![0x2000_0000..0x2002_0000],
+            ),
+            (
+                "explicit ranges can include reserved RAM",
+                |family| {
+                    let chip = test_chip(family);
+                    chip.memory_map.push(MemoryRegion::Ram(RamRegion {
+                        is_reserved: true,
+                        ..ram(0x2002_0000..0x2003_0000)
+                    }));
+                    chip.rtt_scan_ranges = Some(vec![0x2002_0000..0x2002_1000]);
+                },
+                vec![0x2002_0000..0x2002_1000],
+            ),
+        ];
+
+        for (case, modify, expected) in cases {
+            assert_eq!(test_target(modify).rtt_scan_regions, expected, "{case}");
+        }
+    }
+
+    #[test]
+    fn stack_region_for_core() {
+        let cases: [(&str, fn(&mut ChipFamily), Range<u64>); 2] = [
+            (
+                "dedicated stack RAM",
+                |family| {
+                    test_chip(family)
+                        .memory_map
+                        .push(MemoryRegion::Ram(RamRegion {
+                            is_stack: true,
+                            ..ram(0x2004_0000..0x2004_1000)
+                        }))
+                },
+                0x2004_0000..0x2004_1000,
+            ),
+            (
+                "spans all RAM",
+                |family| {
+                    test_chip(family)
+                        .memory_map
+                        .push(MemoryRegion::Ram(ram(0x2004_0000..0x2004_1000)))
+                },
+                0x2000_0000..0x2004_1000,
+            ),
+        ];
+
+        for (case, modify, expected) in cases {
+            let target = test_target(modify);
+            assert_eq!(target.stack_region_for_core(0), Some(expected), "{case}");
+            assert_eq!(target.stack_region_for_core(1), None, "{case}");
+        }
+    }
+
+    #[test]
+    fn capabilities_of_core_types() {
+        // (core type, may have an FPU, supports Thumb-2)
+        let cases = [
+            (CoreType::Armv6m, false, true),
+            (CoreType::Armv7em, true, true),
+            (CoreType::Armv8m, true, true),
+            (CoreType::Riscv, true, false),
+        ];
+
+        for (core_type, may_have_fpu, supports_thumb2) in cases {
+            let target = test_target(|family| set_core_type(family, core_type));
+
+            assert_eq!(target.core_types(), vec![core_type]);
+            assert_eq!(target.architecture(), core_type.architecture());
+            assert_eq!(target.may_have_fpu(), may_have_fpu, "{core_type:?}");
+            assert_eq!(target.supports_thumb2(), supports_thumb2, "{core_type:?}");
+        }
+    }
+
+    #[test]
+    fn sorted_memory_map_keeps_description_order() {
+        let target = test_target(|family| {
+            test_chip(family)
+                .memory_map
+                .push(MemoryRegion::Generic(GenericRegion {
+                    name: None,
+                    range: 0x0000_0000..0x0000_1000,
+                    cores: vec!["main".to_string()],
+                }))
+        });
+
+        let starts: Vec<u64> = target
+            .sorted_memory_map()
+            .iter()
+            .map(|region| region.address_range().start)
+            .collect();
+        assert_eq!(starts, vec![0x0000_0000, 0x0800_0000, 0x2000_0000]);
+
+        // The memory map itself is not reordered.
+        assert!(matches!(target.memory_map[0], MemoryRegion::Nvm(_)));
+        assert!(target.overlapping_memory_regions().is_empty());
+    }
+
+    #[test]
+    fn overlapping_memory_regions_are_reported() {
+        let target = test_target(|family| {
+            test_chip(family)
+                .memory_map
+                .push(MemoryRegion::Ram(ram(0x2001_f000..0x2003_0000)))
+        });
+
+        // Both regions are kept, instead of being merged.
+        assert_eq!(target.sorted_memory_map().len(), 3);
+
+        let overlapping = target.overlapping_memory_regions();
+        assert_eq!(overlapping.len(), 1);
+        assert_eq!(overlapping[0].0.address_range(), 0x2000_0000..0x2002_0000);
+        assert_eq!(overlapping[0].1.address_range(), 0x2001_f000..0x2003_0000);
+    }
+
+    #[test]
+    fn flash_geometry_of_two_banks() {
+        let mut family = test_family();
+        let chip = test_chip(&mut family);
+        if let MemoryRegion::Nvm(bank_1) = &mut chip.memory_map[0] {
+            bank_1.name = Some("BANK_1".to_string());
+        }
+        chip.memory_map.push(MemoryRegion::Nvm(NvmRegion {
+            name: Some("BANK_2".to_string()),
+            range: 0x0810_0000..0x0814_0000,
+            is_boot_memory: false,
+            cores: vec!["main".to_string()],
+        }));
+
+        let mut bank_1 = flash_algorithm("bank_1", "main");
+        bank_1.flash_properties.sectors = vec![
+            SectorDescription {
+                size: 0x4000,
+                address: 0x0,
+            },
+            SectorDescription {
+                size: 0x1_0000,
+                address: 0x1_0000,
+            },
+            SectorDescription {
+                size: 0x2_0000,
+                address: 0x2_0000,
+            },
+        ];
+        add_flash_algorithm(&mut family, bank_1);
+
+        let mut bank_2 = flash_algorithm("bank_2", "main");
+        bank_2.flash_properties = FlashProperties {
+            address_range: 0x0810_0000..0x0814_0000,
+            page_size: 0x100,
+            erase_sector_timeout: 500,
+            sectors: vec![SectorDescription {
+                size: 0x800,
+                address: 0x0,
+            }],
+            ..bank_2.flash_properties
+        };
+        add_flash_algorithm(&mut family, bank_2);
+
+        let target = Target::new(&family, "TEST_CHIP").unwrap();
+
+        let sector =
+            |region: &str, address_range: Range<u64>, sector_size, page_size| FlashSector {
+                region_name: Some(region.to_string()),
+                address_range,
+                sector_size,
+                page_size,
+            };
+
+        assert_eq!(
+            target.flash_geometry(),
+            vec![
+                sector("BANK_1", 0x0800_0000..0x0801_0000, 0x4000, 0x400),
+                sector("BANK_1", 0x0801_0000..0x0802_0000, 0x1_0000, 0x400),
+                sector("BANK_1", 0x0802_0000..0x0810_0000, 0x2_0000, 0x400),
+                sector("BANK_2", 0x0810_0000..0x0814_0000, 0x800, 0x100),
+            ]
+        );
+    }
+
+    #[test]
+    fn rtt_capable_cores_exclude_riscv_coprocessor() {
+        let mut target = Target::new(&test_family(), "TEST_CHIP").unwrap();
+
+        // Mixed architectures are rejected by the chip family validation, so the coprocessor is added afterwards.
+        target.cores.push(Core {
+            name: "coprocessor".to_string(),
+            core_type: CoreType::Riscv,
+            core_access_options: CoreAccessOptions::Riscv(RiscvCoreAccessOptions {}),
+            reset_domain: None,
+        });
+        for region in target.memory_map.iter_mut() {
+            if let MemoryRegion::Ram(ram) = region {
+                ram.cores.push("coprocessor".to_string());
+            }
+        }
+
+        assert_eq!(target.rtt_capable_cores(), vec![0]);
+    }
+
+    #[test]
+    fn rtt_capable_cores_need_ram() {
+        let cases: [(&str, fn(&mut ChipFamily)); 2] = [
+            ("core without RAM", |family| {
+                test_chip(family).cores.push(arm_core("without_ram", 0x1))
+            }),
+            // RISC-V cores access the memory through their debug module.
+            ("RISC-V core", |family| {
+                set_core_type(family, CoreType::Riscv)
+            }),
+        ];
+
+        for (case, modify) in cases {
+            assert_eq!(test_target(modify).rtt_capable_cores(), vec![0], "{case}");
+        }
+    }
+
+    #[test]
+    fn identify_from_idcode() {
+        assert_eq!(
+            Target::identify_from_idcode(0x0000_5c25).as_deref(),
+            Some("esp32c3")
+        );
+        // The version field is ignored.
+        assert_eq!(
+            Target::identify_from_idcode(0x1000_5c25).as_deref(),
+            Some("esp32c3")
+        );
+        // The debug port of a Cortex-M4 does not identify the chip.
+        assert_eq!(Target::identify_from_idcode(0x4ba0_0477), None);
+    }
+
+    #[test]
+    fn reset_domains() {
+        // Without a reset domain, all cores are reset together.
+        let mut family = test_family();
+        test_chip(&mut family).cores.push(arm_core("network", 0x1));
+        let target = Target::new(&family, "TEST_CHIP").unwrap();
+        assert_eq!(target.reset_domains(), vec![vec![0, 1]]);
+
+        let cores = &mut test_chip(&mut family).cores;
+        cores[0].reset_domain = Some("application".to_string());
+        cores[1].reset_domain = Some("network".to_string());
+        let target = Target::new(&family, "TEST_CHIP").unwrap();
+        assert_eq!(target.reset_domains(), vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn flash_algorithms_for_core() {
+        let family_with_algorithms = |network_core: &str| {
+            let mut family = test_family();
+            test_chip(&mut family).cores.push(arm_core("network", 0x1));
+            add_flash_algorithm(&mut family, flash_algorithm("main_flash", "main"));
+            add_flash_algorithm(&mut family, flash_algorithm("network_flash", network_core));
+            family
+        };
+
+        let target = Target::new(&family_with_algorithms("network"), "TEST_CHIP").unwrap();
+        let names = |core_index| -> Vec<String> {
+            target
+                .flash_algorithms_for_core(core_index)
+                .iter()
+                .map(|algorithm| algorithm.name.clone())
+                .collect()
+        };
+        assert_eq!(names(0), ["main_flash"]);
+        assert_eq!(names(1), ["network_flash"]);
+        assert!(names(2).is_empty());
+
+        // Algorithms assigned to a core which does not exist are rejected.
+        assert!(matches!(
+            Target::new(&family_with_algorithms("coprocessor"), "TEST_CHIP"),
+            Err(RegistryError::InvalidChipFamilyDefinition(_, _))
+        ));
+    }
+
+    #[test]
+    fn flash_algorithm_ram_requirements() {
+        let target_with_algorithm = |algorithm: RawFlashAlgorithm| {
+            let mut family = test_family();
+            add_flash_algorithm(&mut family, algorithm);
+            Target::new(&family, "TEST_CHIP").unwrap()
+        };
+        let with_stack_size = |stack_size: u32| RawFlashAlgorithm {
+            stack_size: Some(stack_size),
+            ..flash_algorithm("main_flash", "main")
+        };
+
+        let target = target_with_algorithm(with_stack_size(0x200));
+        let algorithm = target.flash_algorithm_by_name("main_flash").unwrap();
+        assert_eq!(
+            target
+                .flash_algorithm_ram_requirements(0, algorithm)
+                .unwrap(),
+            RamRequirements {
+                region_name: None,
+                // The header of 8 words is placed in front of the 4 bytes of instructions.
+                address_range: 0x2000_0000..0x2000_0624,
+                code_size: 0x24,
+                stack_size: 0x200,
+                data_size: 0x400,
+            }
+        );
+        assert!(matches!(
+            target.flash_algorithm_ram_requirements(1, algorithm),
+            Err(FlashError::NoRamDefined { .. })
+        ));
+
+        // A stack larger than the 128 KiB of RAM is reduced until the algorithm fits.
+        let target = target_with_algorithm(with_stack_size(0x20000));
+        let algorithm = target.flash_algorithm_by_name("main_flash").unwrap();
+        assert_eq!(
+            target
+                .flash_algorithm_ram_requirements(0, algorithm)
+                .unwrap(),
+            RamRequirements {
+                region_name: None,
+                address_range: 0x2000_0000..0x2001_ffe4,
+                code_size: 0x24,
+                stack_size: 0x1_fbc0,
+                data_size: 0x400,
+            }
+        );
+
+        // A single page is larger than the RAM.
+        let mut algorithm = with_stack_size(0x200);
+        algorithm.flash_properties.page_size = 0x2_0000;
+        let target = target_with_algorithm(algorithm);
+        let algorithm = target.flash_algorithm_by_name("main_flash").unwrap();
+        assert!(matches!(
+            target.flash_algorithm_ram_requirements(0, algorithm),
+            Err(FlashError::FlashAlgorithmDoesNotFitInRam {
+                required_size: 0x2_0064,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn flash_algorithm_ram_requirements_match_assembled_algorithm() {
+        let mut family = test_family();
+        let chip = test_chip(&mut family);
+        chip.cores.push(arm_core("network", 0x1));
+        chip.memory_map.push(MemoryRegion::Ram(RamRegion {
+            name: Some("NETWORK_RAM".to_string()),
+            cores: vec!["network".to_string()],
+            ..ram(0x2100_0000..0x2100_1000)
+        }));
+        add_flash_algorithm(
+            &mut family,
+            RawFlashAlgorithm {
+                stack_size: Some(0x1000),
+                ..flash_algorithm("network_flash", "network")
+            },
+        );
+        let target = Target::new(&family, "TEST_CHIP").unwrap();
+        let algorithm = target.flash_algorithm_by_name("network_flash").unwrap();
+
+        // The RAM is selected by the core the algorithm runs on, and the stack is reduced to fit into it.
+        let requirements = target
+            .flash_algorithm_ram_requirements(1, algorithm)
+            .unwrap();
+        assert_eq!(
+            requirements,
+            RamRequirements {
+                region_name: Some("NETWORK_RAM".to_string()),
+                address_range: 0x2100_0000..0x2100_0fe4,
+                code_size: 0x24,
+                stack_size: 0xbc0,
+                data_size: 0x400,
+            }
+        );
+
+        let ram = target.flash_algorithm_ram(1, algorithm).unwrap();
+        let assembled = FlashAlgorithm::assemble_from_raw(algorithm, ram, &target).unwrap();
+        let begin_stack =
+            requirements.address_range.start + requirements.code_size + requirements.stack_size;
+        assert_eq!(assembled.load_address, requirements.address_range.start);
+        assert_eq!(assembled.begin_stack, begin_stack);
+        assert_eq!(assembled.page_buffers, vec![begin_stack]);
+        assert_eq!(
+            begin_stack + requirements.data_size,
+            requirements.address_range.end
+        );
+    }
+
+    #[test]
+    fn debug_capabilities_for_core() {
+        // (core type, vector catch, hardware breakpoints, hardware watchpoints, monitor mode)
+        let cases = [
+            (CoreType::Armv6m, true, 4, 2, false),
+            (CoreType::Armv7m, true, 8, 4, true),
+            (CoreType::Armv7em, true, 8, 4, true),
+            (CoreType::Armv8m, true, 8, 8, true),
+            (CoreType::Armv7a, false, 16, 16, true),
+            (CoreType::Armv8a, false, 16, 16, true),
+            (CoreType::Riscv, false, 2, 2, false),
+        ];
+
+        for (core_type, vector_catch, hardware_breakpoints, hardware_watchpoints, monitor_mode) in
+            cases
+        {
+            let target = test_target(|family| set_core_type(family, core_type));
+
+            assert_eq!(
+                target.debug_capabilities_for_core(0),
+                Some(DebugCapabilities {
+                    vector_catch,
+                    hardware_breakpoints,
+                    hardware_watchpoints,
+                    monitor_mode,
+                }),
+                "{core_type:?}"
+            );
+            assert_eq!(target.debug_capabilities_for_core(1), None);
+        }
+    }
+
+    #[test]
+    fn core_registers_for_core() {
+        let cases: [(CoreType, &CoreRegisters); 4] = [
+            (CoreType::Armv7em, &*CORTEX_M_CORE_REGISTERS),
+            (CoreType::Armv7a, &*AARCH32_CORE_REGSISTERS),
+            (CoreType::Armv8a, &*AARCH64_CORE_REGSISTERS),
+            (CoreType::Riscv, &*RISCV_CORE_REGSISTERS),
+        ];
+
+        for (core_type, registers) in cases {
+            let target = test_target(|family| set_core_type(family, core_type));
+
+            assert!(
+                std::ptr::eq(target.core_registers_for_core(0).unwrap(), registers),
+                "{core_type:?}"
+            );
+            assert!(target.core_registers_for_core(1).is_none());
+        }
+    }
+}
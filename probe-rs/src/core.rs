@@ -1,6 +1,7 @@
 use crate::{
-    architecture::arm::sequences::ArmDebugSequence, debug::DebugRegisters, error, CoreType, Error,
-    InstructionSet, MemoryInterface, Target,
+    architecture::{arm::sequences::ArmDebugSequence, riscv::sequences::RiscvDebugSequence},
+    debug::DebugRegisters,
+    error, CoreType, Error, InstructionSet, MemoryInterface, Target,
 };
 use anyhow::{anyhow, Result};
 pub use probe_rs_target::{Architecture, CoreAccessOptions};
@@ -374,7 +375,14 @@ impl<'probe> Core<'probe> {
                 }
             }
             CoreAccessOptions::Riscv(options) => {
-                let core_state = CoreState::new(ResolvedCoreOptions::Riscv { options });
+                let sequence = match &target.debug_sequence {
+                    crate::config::DebugSequence::Riscv(seq) => seq.clone(),
+                    crate::config::DebugSequence::Arm(_) => panic!(
+                        "Mismatch between sequence and core kind. This is a bug, please report it."
+                    ),
+                };
+
+                let core_state = CoreState::new(ResolvedCoreOptions::Riscv { sequence, options });
                 CombinedCoreState {
                     id,
                     core_state,
@@ -678,6 +686,7 @@ pub enum ResolvedCoreOptions {
         options: ArmCoreAccessOptions,
     },
     Riscv {
+        sequence: Arc<dyn RiscvDebugSequence>,
         options: RiscvCoreAccessOptions,
     },
 }
@@ -690,7 +699,11 @@ impl std::fmt::Debug for ResolvedCoreOptions {
                 .field("sequence", &"<ArmDebugSequence>")
                 .field("options", options)
                 .finish(),
-            Self::Riscv { options } => f.debug_struct("Riscv").field("options", options).finish(),
+            Self::Riscv { options, .. } => f
+                .debug_struct("Riscv")
+                .field("sequence", &"<RiscvDebugSequence>")
+                .field("options", options)
+                .finish(),
         }
     }
 }
@@ -139,9 +139,21 @@ impl CombinedCoreState {
         &'probe mut self,
         interface: &'probe mut RiscvCommunicationInterface,
     ) -> Result<Core<'probe>, Error> {
+        let debug_sequence = match &self.core_state.core_access_options {
+            ResolvedCoreOptions::Riscv { sequence, .. } => sequence.clone(),
+            ResolvedCoreOptions::Arm { .. } => {
+                return Err(Error::UnableToOpenProbe(
+                    "Core architecture and Probe mismatch.",
+                ))
+            }
+        };
+
         Ok(match &mut self.specific_state {
             SpecificCoreState::Riscv(s) => Core::new(crate::architecture::riscv::Riscv32::new(
-                interface, s, self.id,
+                interface,
+                s,
+                debug_sequence,
+                self.id,
             )),
             _ => {
                 return Err(Error::UnableToOpenProbe(
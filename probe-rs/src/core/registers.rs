@@ -76,12 +76,13 @@ impl Display for RegisterRole {
 /// The rules for these are based on the 'Procedure Calling Standard' for each of the supported architectures:
 /// - Implemented: [AAPCS32](https://github.com/ARM-software/abi-aa/blob/main/aapcs32/aapcs32.rst#core-registers)
 /// - To be Implemented: [AAPCS64](https://github.com/ARM-software/abi-aa/blob/main/aapcs32/aapcs32.rst#core-registers)
-/// - To be Implemented: [RISC-V PCS](https://github.com/riscv-non-isa/riscv-elf-psabi-doc/releases/download/v1.0/riscv-abi.pdf)
+/// - Implemented: [RISC-V PCS](https://github.com/riscv-non-isa/riscv-elf-psabi-doc/releases/download/v1.0/riscv-abi.pdf)
 ///
 /// Please note that the `Procedure Calling Standard` define register rules for the act of calling and/or returning from functions,
 /// while the timing of a stack unwinding is different (the `callee` has not yet completed / executed the epilogue),
 /// and the rules about preserving register values have to take this into account.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum UnwindRule {
     /// Callee-saved, a.k.a non-volatile registers, or call-preserved.
     /// If there is DWARF unwind `RegisterRule` we will apply it during unwind,
@@ -98,6 +99,16 @@ pub enum UnwindRule {
     /// These are typically found in either the DWARF unwind information,
     /// or requires additional platform specific registers to be read.
     SpecialRule,
+    /// The stack pointer. Without a DWARF unwind `RegisterRule`, the value in the calling frame
+    /// is the canonical frame address (CFA) of the current frame.
+    CanonicalFrameAddress,
+    /// The register which holds the return address, e.g. the ARM `LR` or the RISC-V `ra`.
+    /// Without a DWARF unwind `RegisterRule`, the value in the calling frame is unknown,
+    /// but the current value is used to unwind the program counter.
+    ReturnAddress,
+    /// The program counter. Without a DWARF unwind `RegisterRule`, the value in the calling frame
+    /// is the unwound return address, i.e. the first instruction after the call.
+    ProgramCounter,
 }
 
 /// Describes a core (or CPU / hardware) register with its properties.
@@ -6,27 +6,70 @@ use crate::core::{exception_handler_for_core, UnwindRule};
 use crate::{
     core::Core,
     core::{ExceptionInterface, RegisterRole, RegisterValue},
-    debug::{registers, source_statement::SourceStatements},
+    debug::{registers, source_statement::SourceStatements, SteppingMode},
     MemoryInterface,
 };
 use gimli::{
     BaseAddresses, ColumnType, DebugFrame, FileEntry, LineProgramHeader, UnwindContext,
     UnwindSection,
 };
-use object::read::{Object, ObjectSection};
-use probe_rs_target::InstructionSet;
+use object::read::{Object, ObjectSection, ObjectSymbol};
+use probe_rs_target::{CoreType, InstructionSet};
 use typed_path::{TypedPath, TypedPathBuf};
 
 use std::{
-    borrow, cmp::Ordering, convert::TryInto, num::NonZeroU64, ops::ControlFlow, path::Path, rc::Rc,
+    borrow,
+    cmp::Ordering,
+    collections::HashMap,
+    convert::TryInto,
+    num::NonZeroU64,
+    ops::{ControlFlow, Range},
+    path::Path,
+    rc::Rc,
     str::from_utf8,
 };
 
-pub(crate) type GimliReader = gimli::EndianReader<gimli::LittleEndian, std::rc::Rc<[u8]>>;
+pub(crate) type GimliReader = gimli::EndianReader<gimli::RunTimeEndian, SectionData>;
 
 pub(crate) type GimliAttribute = gimli::Attribute<GimliReader>;
 
-pub(crate) type DwarfReader = gimli::read::EndianRcSlice<gimli::LittleEndian>;
+pub(crate) type DwarfReader = GimliReader;
+
+/// The data of a DWARF section.
+#[derive(Clone)]
+pub(crate) enum SectionData {
+    /// The section was copied into memory, e.g. because it had to be decompressed.
+    Owned(Rc<[u8]>),
+    /// The section is a range of a memory mapped file.
+    Mapped {
+        mapping: Rc<memmap2::Mmap>,
+        range: Range<usize>,
+    },
+}
+
+impl std::ops::Deref for SectionData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            SectionData::Owned(data) => data,
+            SectionData::Mapped { mapping, range } => &mapping[range.clone()],
+        }
+    }
+}
+
+impl std::fmt::Debug for SectionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SectionData::Owned(data) => write!(f, "Owned({} bytes)", data.len()),
+            SectionData::Mapped { range, .. } => write!(f, "Mapped({range:#x?})"),
+        }
+    }
+}
+
+// Both the `Rc` and the memory mapping never move the data they point to, even when cloned.
+unsafe impl gimli::StableDeref for SectionData {}
+unsafe impl gimli::CloneStableDeref for SectionData {}
 
 /// Capture the required information when a breakpoint is set based on a requested source location.
 /// It is possible that the requested source location cannot be resolved to a valid instruction address,
@@ -40,6 +83,290 @@ pub struct VerifiedBreakpoint {
     pub source_location: SourceLocation,
 }
 
+/// Information about a compilation unit, as returned by [`DebugInfo::units_for_file`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitMetadata {
+    /// The name of the compilation unit, which is usually the path of the main source file.
+    pub name: Option<String>,
+    /// The compilation directory of the unit, from `DW_AT_comp_dir`.
+    pub comp_dir: Option<TypedPathBuf>,
+    /// The compiler which produced the unit, from `DW_AT_producer`.
+    pub producer: Option<String>,
+}
+
+/// A problem with the debug information, as reported by [`DebugInfo::verify`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum DebugWarning {
+    /// The `.debug_line` section is missing, so addresses can not be mapped to source locations.
+    #[error("The `.debug_line` section is missing, source locations will not be available.")]
+    MissingLineSection,
+    /// The `.debug_frame` section is missing or empty, so the stack can not be unwound.
+    #[error("The `.debug_frame` section is missing or empty, the stack can not be unwound.")]
+    MissingFrameSection,
+    /// The compilation unit has no line program.
+    #[error("The compilation unit `{unit}` has no line program.")]
+    UnitWithoutLineProgram {
+        /// The name of the compilation unit.
+        unit: String,
+    },
+    /// The function ends before it starts.
+    #[error(
+        "The function `{function}` has an invalid address range {low_pc:#010x}..{high_pc:#010x}."
+    )]
+    InvalidFunctionRange {
+        /// The name of the function.
+        function: String,
+        /// The value of `DW_AT_low_pc`.
+        low_pc: u64,
+        /// The value of `DW_AT_high_pc`.
+        high_pc: u64,
+    },
+}
+
+/// A record of the rule that was applied to unwind a single register, and the resulting value.
+///
+/// These are collected by [`DebugInfo::unwind_with_trace`], to provide a structured view of the stack unwind.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisterUnwindStep {
+    /// The index (in the list of unwound `StackFrame`s) of the frame whose registers were used to unwind the register.
+    pub frame_index: usize,
+    /// The name of the register that was unwound.
+    pub register_name: String,
+    /// A description of the rule that was applied to unwind the register.
+    pub rule: String,
+    /// The value of the register in the calling frame, if it could be determined.
+    pub value: Option<RegisterValue>,
+}
+
+/// The reason why a stack unwind ended, as returned by [`DebugInfo::unwind_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnwindOutcome {
+    /// The unwind ended normally, e.g. at the bottom of the stack, or because no more unwind information was available.
+    #[default]
+    Complete,
+    /// The CFA (canonical frame address) of the last frame is outside of the valid stack memory,
+    /// so the stack is likely corrupt, e.g. after a stack overflow.
+    StackCorrupt {
+        /// The invalid CFA.
+        cfa: u64,
+    },
+}
+
+/// The stack frames of an unwind, together with the reason why the unwind ended.
+#[derive(Debug)]
+pub struct StackUnwind {
+    /// The unwound stack frames, starting with the frame of the current PC.
+    pub frames: Vec<StackFrame>,
+    /// The reason why the unwind ended.
+    pub outcome: UnwindOutcome,
+    /// Set if a pointer authentication code was removed from an A64 return address,
+    /// see [`UnwindOptions::set_virtual_address_bits`].
+    pub pointer_authentication_stripped: bool,
+}
+
+/// The call frame information from the `.debug_frame` section which applies to a single address.
+///
+/// Returned by [`DebugInfo::unwind_table_row`], to help diagnose incorrect stack unwinds.
+/// The rules use the same notation as `readelf --debug-dump=frames-interp`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct UnwindRowDump {
+    /// The offset of the common information entry (CIE) in the `.debug_frame` section.
+    pub cie_offset: usize,
+    /// The address range covered by the frame description entry (FDE).
+    pub fde_range: Range<u64>,
+    /// The address range covered by the unwind table row.
+    pub row_range: Range<u64>,
+    /// The DWARF register number which holds the return address.
+    pub return_address_register: u16,
+    /// The rule used to compute the canonical frame address (CFA), e.g. `r7+8`.
+    pub cfa_rule: String,
+    /// The rules for the registers saved in this row, ordered by DWARF register number, e.g. `c-4`.
+    pub register_rules: Vec<(u16, String)>,
+}
+
+/// The addresses of the return instructions of a function, returned by [`DebugInfo::function_return_addresses`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReturnAddresses {
+    /// The addresses of the rows of the line program which are marked with `epilogue_begin`, in ascending order.
+    Epilogue(Vec<u64>),
+    /// The address of the last row of the function, because the line program has no `epilogue_begin` markers
+    /// (e.g. GCC doesn't emit them). This is only a best effort, the function may also return from other instructions.
+    LastRow(u64),
+}
+
+impl ReturnAddresses {
+    /// The addresses of the return instructions, in ascending order.
+    pub fn addresses(&self) -> &[u64] {
+        match self {
+            ReturnAddresses::Epilogue(addresses) => addresses,
+            ReturnAddresses::LastRow(address) => std::slice::from_ref(address),
+        }
+    }
+}
+
+/// The name used for a `StackFrame` whose function could not be determined from the debug information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnknownFunctionPlaceholder {
+    /// `<unknown function @ 0x00000000>`
+    #[default]
+    Descriptive,
+    /// Just the address, e.g. `0x00000000`.
+    Address,
+    /// `??`, as used by `addr2line`.
+    QuestionMarks,
+}
+
+impl UnknownFunctionPlaceholder {
+    /// Formats the placeholder for the function at `address`.
+    ///
+    /// Addresses are zero padded to the width of the target's address size, i.e. 8 hex digits
+    /// for 4 byte addresses and 16 hex digits for 8 byte addresses.
+    pub fn format(&self, address: u64, address_size_bytes: usize) -> String {
+        let width = address_size_bytes * 2 + 2;
+        match self {
+            UnknownFunctionPlaceholder::Descriptive => {
+                format!("<unknown function @ {address:#0width$x}>")
+            }
+            UnknownFunctionPlaceholder::Address => format!("{address:#0width$x}"),
+            UnknownFunctionPlaceholder::QuestionMarks => "??".to_string(),
+        }
+    }
+}
+
+/// Options which control the work performed during a stack unwind, see [`DebugInfo::unwind_with_options`].
+///
+/// The options can be combined, e.g. to unwind only a few frames without variable caches, while tracing the unwound registers:
+///
+/// ```no_run
+/// # use probe_rs::debug::{DebugInfo, UnwindOptions};
+/// # fn unwind(debug_info: &DebugInfo, core: &mut probe_rs::Core) -> Result<(), probe_rs::Error> {
+/// let mut trace = Vec::new();
+/// let unwind = debug_info.unwind_with_options(
+///     core,
+///     UnwindOptions::new()
+///         .set_variable_caches(false)
+///         .set_max_frames(5)
+///         .set_trace(&mut trace),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct UnwindOptions<'a> {
+    /// Create the static and function variable caches for each `StackFrame`.
+    pub(crate) populate_variable_caches: bool,
+    /// The maximum number of `StackFrame`s that will be returned.
+    pub(crate) max_frames: Option<usize>,
+    /// Create additional `StackFrame`s for the inlined functions of each frame.
+    pub(crate) find_inlined: bool,
+    /// Recover the arguments of the calls made by inlined functions from their call site parameters,
+    /// see [`StackFrame::call_site_arguments`].
+    pub(crate) find_call_site_arguments: bool,
+    /// If provided, collects a [`RegisterUnwindStep`] for every register that is unwound.
+    pub(crate) unwind_trace: Option<&'a mut Vec<RegisterUnwindStep>>,
+    /// If provided, the CFA of every frame is checked with this predicate before any registers are read from the stack.
+    /// The unwind will terminate when the CFA does not point to valid stack memory, e.g. after a stack overflow.
+    pub(crate) stack_validity: Option<&'a dyn Fn(u64) -> bool>,
+    /// If provided, this is called with every `StackFrame` as soon as it has been unwound.
+    /// The unwind stops when it returns [`ControlFlow::Break`], and the frames after the current one are discarded.
+    pub(crate) on_frame: Option<&'a mut dyn FnMut(&StackFrame) -> ControlFlow<()>>,
+    /// The size of the virtual addresses of an A64 target, used to strip pointer authentication codes from return addresses.
+    pub(crate) virtual_address_bits: Option<u8>,
+}
+
+impl Default for UnwindOptions<'_> {
+    fn default() -> Self {
+        Self {
+            populate_variable_caches: true,
+            max_frames: None,
+            find_inlined: true,
+            find_call_site_arguments: false,
+            unwind_trace: None,
+            stack_validity: None,
+            on_frame: None,
+            virtual_address_bits: None,
+        }
+    }
+}
+
+impl<'a> UnwindOptions<'a> {
+    /// Create the options for a full unwind, as done by [`DebugInfo::unwind`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the static and function variable caches for each `StackFrame`. This is enabled by default.
+    ///
+    /// Without them, the `static_variables` and `local_variables` of the `StackFrame`s are always `None`,
+    /// which makes the unwind considerably faster when only a symbolic backtrace is required.
+    pub fn set_variable_caches(mut self, enabled: bool) -> Self {
+        self.populate_variable_caches = enabled;
+        self
+    }
+
+    /// Return at most `max_frames` `StackFrame`s.
+    pub fn set_max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    /// Create additional `StackFrame`s for the inlined functions of each frame. This is enabled by default.
+    ///
+    /// Without them, only the frames of the non-inlined functions are returned, which is much cheaper
+    /// for functions with a large tree of inlined functions.
+    pub fn set_find_inlined(mut self, enabled: bool) -> Self {
+        self.find_inlined = enabled;
+        self
+    }
+
+    /// Recover the [`StackFrame::call_site_arguments`] of the frames of inlined functions. This is disabled by default.
+    ///
+    /// The arguments are read from the `DW_TAG_call_site_parameter` entries of the calls made by the inlined
+    /// functions, which often describe values that are otherwise optimized out. Evaluating them is expensive.
+    pub fn set_call_site_arguments(mut self, enabled: bool) -> Self {
+        self.find_call_site_arguments = enabled;
+        self
+    }
+
+    /// Collect a [`RegisterUnwindStep`] in `trace` for every register of every frame that is unwound.
+    ///
+    /// This provides a machine readable view of the unwind rules applied, e.g. for building an unwind debugging UI.
+    pub fn set_trace(mut self, trace: &'a mut Vec<RegisterUnwindStep>) -> Self {
+        self.unwind_trace = Some(trace);
+        self
+    }
+
+    /// Check the CFA (canonical frame address) of every frame with `stack_validity`, before any registers are read from the stack.
+    ///
+    /// If it returns `false`, the stack is considered to be corrupt (e.g. after a stack overflow), and the unwind ends
+    /// with the current frame and [`UnwindOutcome::StackCorrupt`], instead of producing a long backtrace from garbage values.
+    pub fn set_stack_validity(mut self, stack_validity: &'a dyn Fn(u64) -> bool) -> Self {
+        self.stack_validity = Some(stack_validity);
+        self
+    }
+
+    /// Pass each `StackFrame` to `on_frame` as soon as it has been unwound.
+    ///
+    /// This allows a UI to display the frames while the unwind is still in progress.
+    /// The unwind stops early when `on_frame` returns [`ControlFlow::Break`].
+    pub fn set_on_frame(
+        mut self,
+        on_frame: &'a mut dyn FnMut(&StackFrame) -> ControlFlow<()>,
+    ) -> Self {
+        self.on_frame = Some(on_frame);
+        self
+    }
+
+    /// Set the size of the virtual addresses of an A64 target, i.e. `64 - TCR_EL1.T0SZ`.
+    ///
+    /// On cores with pointer authentication, the bits of a return address above this size hold the pointer
+    /// authentication code, which is removed before the address is used as the program counter of the calling frame.
+    /// By default, the smallest size which covers all the functions of the debug information is used.
+    pub fn set_virtual_address_bits(mut self, virtual_address_bits: u8) -> Self {
+        self.virtual_address_bits = Some(virtual_address_bits);
+        self
+    }
+}
+
 /// Debug information which is parsed from DWARF debugging information.
 pub struct DebugInfo {
     pub(crate) dwarf: gimli::Dwarf<DwarfReader>,
@@ -47,6 +374,63 @@ pub struct DebugInfo {
     pub(crate) locations_section: gimli::LocationLists<DwarfReader>,
     pub(crate) address_section: gimli::DebugAddr<DwarfReader>,
     pub(crate) debug_line_section: gimli::DebugLine<DwarfReader>,
+    /// Replaces the `DW_AT_comp_dir` of every unit when resolving relative source file paths.
+    pub(crate) comp_dir_override: Option<TypedPathBuf>,
+    /// Resolve relative source file paths against the compilation directory.
+    pub(crate) resolve_relative_paths: bool,
+    /// The entry point from the header of the object file.
+    pub(crate) entry_point: Option<u64>,
+    /// The addresses of the symbols defined in the symbol table of the object file.
+    pub(crate) symbols: HashMap<String, u64>,
+    /// The name used for stack frames of unknown functions.
+    pub(crate) unknown_function_placeholder: UnknownFunctionPlaceholder,
+    /// The function symbols of the symbol table, ordered by address.
+    pub(crate) function_symbols: Vec<FunctionSymbol>,
+    /// The loadable segments from the program headers of an ELF file.
+    pub(crate) load_segments: Vec<LoadSegment>,
+    /// Map runtime addresses to the addresses used by the debug information, see [`DebugInfo::translate_address`].
+    pub(crate) translate_load_addresses: bool,
+    /// Use the function symbols to name functions without debug information.
+    pub(crate) symbol_fallback: bool,
+    /// The debug information of other binaries, see [`DebugInfo::merge`].
+    pub(crate) merged: Vec<DebugInfo>,
+    /// The offset at which this binary is loaded, if it was [merged](DebugInfo::merge) into another one.
+    pub(crate) load_offset: u64,
+    /// The memory mapped file which the sections refer to, if it was loaded with [`DebugInfo::from_file_mmap`].
+    pub(crate) mapping: Option<Rc<memmap2::Mmap>>,
+}
+
+/// A function from the symbol table of the object file.
+pub(crate) struct FunctionSymbol {
+    pub(crate) name: String,
+    /// The address of the first instruction, with bit 0 cleared for Thumb functions.
+    pub(crate) address: u64,
+    /// The size of the function, which is `0` if it is not known.
+    pub(crate) size: u64,
+}
+
+/// A loadable segment from the program headers of an ELF file.
+pub(crate) struct LoadSegment {
+    /// The address at which the segment is linked, which is used by the debug information.
+    pub(crate) virtual_address: u64,
+    /// The address at which the segment is loaded, e.g. the flash address of firmware which is executed in place.
+    pub(crate) physical_address: u64,
+    /// The size of the segment in memory.
+    pub(crate) size: u64,
+}
+
+impl LoadSegment {
+    fn contains_virtual(&self, address: u64) -> bool {
+        address
+            .checked_sub(self.virtual_address)
+            .map_or(false, |offset| offset < self.size)
+    }
+
+    fn contains_physical(&self, address: u64) -> bool {
+        address
+            .checked_sub(self.physical_address)
+            .map_or(false, |offset| offset < self.size)
+    }
 }
 
 impl DebugInfo {
@@ -57,20 +441,104 @@ impl DebugInfo {
         DebugInfo::from_raw(&data)
     }
 
-    /// Parse debug information directly from a buffer containing an ELF file.
+    /// Read debug info from a memory mapped ELF file.
+    ///
+    /// Unlike [`DebugInfo::from_file`], the file is not read into memory, and the DWARF sections
+    /// refer directly to the mapped file instead of being copied. Only compressed sections are
+    /// decompressed into memory. This reduces the memory usage for large binaries.
+    ///
+    /// The file must not be modified while the returned `DebugInfo` is in use.
+    pub fn from_file_mmap<P: AsRef<Path>>(path: P) -> Result<DebugInfo, DebugError> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: The mapping is only read, and modifying the file while it is mapped is documented as unsupported.
+        let mapping = Rc::new(unsafe { memmap2::Mmap::map(&file)? });
+
+        let object = object::File::parse(&**mapping)?;
+
+        DebugInfo::from_objects(&object, &object, Some(&mapping))
+    }
+
+    /// Read debug info from a pair of files, where the debug sections were moved from the `runnable` ELF file
+    /// into the separate `debug` file, e.g. with `objcopy --only-keep-debug`.
+    ///
+    /// The DWARF sections are read from `debug`, while the entry point and symbols are taken from `runnable`.
+    /// A warning is logged if the two files don't seem to belong to the same build.
+    pub fn from_files<P: AsRef<Path>, Q: AsRef<Path>>(
+        runnable: P,
+        debug: Q,
+    ) -> Result<DebugInfo, DebugError> {
+        let runnable_data = std::fs::read(runnable)?;
+        let debug_data = std::fs::read(debug)?;
+
+        let runnable_object = object::File::parse(&*runnable_data)?;
+        let debug_object = object::File::parse(&*debug_data)?;
+
+        if !is_same_build(&runnable_object, &debug_object) {
+            tracing::warn!(
+                "The debug information file does not seem to belong to the runnable file, debug information may be wrong."
+            );
+        }
+
+        DebugInfo::from_objects(&runnable_object, &debug_object, None)
+    }
+
+    /// Parse debug information directly from a buffer containing an ELF, Mach-O or PE/COFF file.
+    ///
+    /// The DWARF sections are looked up using their ELF names, which the `object` crate maps
+    /// to the names used by the other formats, e.g. `__debug_info` in the `__DWARF` segment of a Mach-O file.
     pub fn from_raw(data: &[u8]) -> Result<Self, DebugError> {
         let object = object::File::parse(data)?;
 
-        // Load a section and return as `Cow<[u8]>`.
+        DebugInfo::from_objects(&object, &object, None)
+    }
+
+    /// Load the DWARF sections from `debug_object`, and the entry point and symbols from `runnable_object`.
+    ///
+    /// If `runnable_object` has no symbol table, e.g. because it was stripped, the symbols of `debug_object` are used.
+    ///
+    /// If `debug_object` was parsed from `mapping`, uncompressed sections refer to the mapping instead of being copied.
+    fn from_objects(
+        runnable_object: &object::File,
+        debug_object: &object::File,
+        mapping: Option<&Rc<memmap2::Mmap>>,
+    ) -> Result<Self, DebugError> {
+        // The DWARF sections use the byte order of the object file.
+        let endian = if debug_object.is_little_endian() {
+            gimli::RunTimeEndian::Little
+        } else {
+            gimli::RunTimeEndian::Big
+        };
+
+        // Load a section, referring to the mapped file if possible.
         let load_section = |id: gimli::SectionId| -> Result<DwarfReader, gimli::Error> {
-            let data = object
-                .section_by_name(id.name())
+            let section = debug_object.section_by_name(id.name());
+
+            if let (Some(mapping), Some(section)) = (mapping, &section) {
+                if let Ok(file_range) = section.compressed_file_range() {
+                    if file_range.format == object::CompressionFormat::None {
+                        let start = file_range.offset as usize;
+                        let range = start..start + file_range.compressed_size as usize;
+
+                        if range.end <= mapping.len() {
+                            return Ok(gimli::EndianReader::new(
+                                SectionData::Mapped {
+                                    mapping: mapping.clone(),
+                                    range,
+                                },
+                                endian,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            let data = section
                 .and_then(|section| section.uncompressed_data().ok())
                 .unwrap_or_else(|| borrow::Cow::Borrowed(&[][..]));
 
-            Ok(gimli::read::EndianRcSlice::new(
-                Rc::from(&*data),
-                gimli::LittleEndian,
+            Ok(gimli::EndianReader::new(
+                SectionData::Owned(Rc::from(&*data)),
+                endian,
             ))
         };
 
@@ -85,18 +553,299 @@ impl DebugInfo {
         let locations_section = gimli::LocationLists::new(debug_loc, debug_loc_lists);
         let debug_line_section = gimli::DebugLine::load(load_section)?;
 
+        // An entry point of zero is used by object files which don't have one.
+        let entry_point = Some(runnable_object.entry()).filter(|entry| *entry != 0);
+
+        let symbol_object = if runnable_object.symbol_table().is_some() {
+            runnable_object
+        } else {
+            debug_object
+        };
+
+        // Bit 0 of the address of Thumb functions is set, which is not part of the address of the first instruction.
+        let address_mask = if symbol_object.architecture() == object::Architecture::Arm {
+            !1
+        } else {
+            !0
+        };
+
+        let mut symbols = HashMap::new();
+        let mut function_symbols = Vec::new();
+        for symbol in symbol_object.symbols() {
+            if symbol.is_undefined() {
+                continue;
+            }
+            let Ok(name) = symbol.name() else {
+                continue;
+            };
+            if name.is_empty() {
+                continue;
+            }
+            if symbol.kind() == object::SymbolKind::Text {
+                function_symbols.push(FunctionSymbol {
+                    name: name.to_string(),
+                    address: symbol.address() & address_mask,
+                    size: symbol.size(),
+                });
+            }
+            // Prefer global symbols over local symbols with the same name.
+            if symbol.is_global() || !symbols.contains_key(name) {
+                symbols.insert(name.to_string(), symbol.address());
+            }
+        }
+        function_symbols.sort_by_key(|symbol| symbol.address);
+
+        let load_segments = match runnable_object {
+            object::File::Elf32(elf) => load_segments(elf),
+            object::File::Elf64(elf) => load_segments(elf),
+            _ => Vec::new(),
+        };
+
         Ok(DebugInfo {
             dwarf: dwarf_cow,
             frame_section,
             locations_section,
             address_section,
             debug_line_section,
+            comp_dir_override: None,
+            resolve_relative_paths: true,
+            entry_point,
+            symbols,
+            unknown_function_placeholder: UnknownFunctionPlaceholder::default(),
+            function_symbols,
+            load_segments,
+            translate_load_addresses: false,
+            symbol_fallback: false,
+            merged: Vec::new(),
+            load_offset: 0,
+            mapping: mapping.cloned(),
         })
     }
 
+    /// Get the entry point of the program, as specified in the header of the object file.
+    ///
+    /// For Thumb code, bit 0 of the entry point is set, and has to be cleared to get the address of the first instruction.
+    pub fn entry_point(&self) -> Option<u64> {
+        self.entry_point
+    }
+
+    /// Get the address of the symbol called `name` from the symbol table of the object file.
+    ///
+    /// This works for symbols without debug information, e.g. symbols defined in assembly or by the linker.
+    /// For Thumb functions, bit 0 of the address is set.
+    pub fn symbol_address(&self, name: &str) -> Option<u64> {
+        self.symbols.get(name).copied()
+    }
+
+    /// Use `comp_dir` instead of the compilation directory recorded in the debug information
+    /// when resolving relative source file paths.
+    ///
+    /// This is useful when the whole source tree was moved after the binary was built,
+    /// e.g. for firmware built in a container. Absolute source file paths are not affected.
+    pub fn set_comp_dir_override(&mut self, comp_dir: Option<TypedPathBuf>) {
+        self.comp_dir_override = comp_dir;
+    }
+
+    /// Control whether relative source file paths are resolved against the compilation directory.
+    ///
+    /// By default, relative paths are resolved, so that source locations have an absolute path.
+    /// Clients which resolve paths against their own workspace root can disable this,
+    /// to get the paths as they are recorded in the debug information.
+    pub fn set_resolve_relative_paths(&mut self, resolve: bool) {
+        self.resolve_relative_paths = resolve;
+    }
+
+    /// Set the name used for the `StackFrame`s of functions which can not be found in the debug information.
+    ///
+    /// The default is `<unknown function @ 0x...>`, but tools which post-process backtraces
+    /// may prefer just the address, or the `??` used by `addr2line`.
+    pub fn set_unknown_function_placeholder(&mut self, placeholder: UnknownFunctionPlaceholder) {
+        self.unknown_function_placeholder = placeholder;
+    }
+
+    /// Get the name of the function at `address` from the symbol table of the object file, e.g. `memcpy+0x4`.
+    ///
+    /// This uses the nearest function symbol at or before `address`, and works for functions
+    /// without debug information, e.g. routines written in assembly.
+    /// The offset of `address` from the start of the function is appended, unless it is zero.
+    pub fn symbol_name(&self, address: u64) -> Option<String> {
+        let index = self
+            .function_symbols
+            .partition_point(|symbol| symbol.address <= address);
+        let symbol = self.function_symbols[..index].last()?;
+
+        let offset = address - symbol.address;
+        if symbol.size != 0 && offset >= symbol.size {
+            return None;
+        }
+
+        if offset == 0 {
+            Some(symbol.name.clone())
+        } else {
+            Some(format!("{}+{offset:#x}", symbol.name))
+        }
+    }
+
+    /// Use the symbol table to name functions without debug information, see [`DebugInfo::symbol_name`].
+    ///
+    /// This is disabled by default. When enabled, [`DebugInfo::function_name`] returns
+    /// the symbol name if the debug information does not contain a function at the address,
+    /// and the symbol name is used instead of the [unknown function placeholder](DebugInfo::set_unknown_function_placeholder)
+    /// for the `StackFrame`s of such functions.
+    pub fn set_symbol_fallback(&mut self, enabled: bool) {
+        self.symbol_fallback = enabled;
+    }
+
+    /// The name of a `StackFrame` for the function at `address`, if it has no name in the debug information.
+    fn unknown_function_name(&self, address: u64, address_size_bytes: usize) -> String {
+        self.symbol_fallback
+            .then(|| self.symbol_name(address))
+            .flatten()
+            .unwrap_or_else(|| {
+                self.unknown_function_placeholder
+                    .format(address, address_size_bytes)
+            })
+    }
+
+    /// Map a runtime address, e.g. a PC read from the core, to the address used by the debug information.
+    ///
+    /// For firmware which is executed at a different address than it was linked for, e.g. position-independent
+    /// code which is executed in place from flash, the program headers of the ELF file give the load (physical)
+    /// address of every segment in addition to the virtual address used by the debug information.
+    /// An address inside the load address range of a segment is moved to its virtual address range.
+    ///
+    /// Addresses which are already inside the virtual address range of a segment, and all addresses
+    /// of binaries where the load and virtual addresses are the same, are returned unchanged.
+    pub fn translate_address(&self, address: u64) -> u64 {
+        if self
+            .load_segments
+            .iter()
+            .any(|segment| segment.contains_virtual(address))
+        {
+            return address;
+        }
+
+        self.load_segments
+            .iter()
+            .find(|segment| segment.contains_physical(address))
+            .map_or(address, |segment| {
+                segment.virtual_address + (address - segment.physical_address)
+            })
+    }
+
+    /// Use [`DebugInfo::translate_address`] for the addresses passed to [`DebugInfo::get_source_location`]
+    /// and for the PCs of a stack unwind.
+    ///
+    /// This is disabled by default. When enabled, the `pc` of the unwound `StackFrame`s is the translated address.
+    pub fn set_translate_load_addresses(&mut self, enabled: bool) {
+        self.translate_load_addresses = enabled;
+    }
+
+    /// The address used to look up `address` in the debug information.
+    fn lookup_address(&self, address: u64) -> u64 {
+        if self.translate_load_addresses {
+            self.translate_address(address)
+        } else {
+            address
+        }
+    }
+
+    /// Add the debug information of another binary, e.g. the application in addition to the bootloader,
+    /// so that functions, source locations, breakpoints, line tables and stack frames are resolved across both.
+    ///
+    /// All addresses in `other`, including those of static variables, are moved by `offset`, which is the address at which it is loaded.
+    /// If the address ranges of the binaries overlap, the one with the smallest compilation unit range
+    /// containing an address is used for that address.
+    pub fn merge(&mut self, mut other: DebugInfo, offset: u64) {
+        for mut debug_info in std::mem::take(&mut other.merged) {
+            debug_info.load_offset = debug_info.load_offset.wrapping_add(offset);
+            self.merged.push(debug_info);
+        }
+        other.load_offset = offset;
+        self.merged.push(other);
+    }
+
+    /// The debug information of the binary which `frame` was resolved from.
+    ///
+    /// This is `self`, unless the frame belongs to a [merged](DebugInfo::merge) binary. The variables of the frame
+    /// have to be expanded with [`DebugInfo::cache_deferred_variables`] of the returned debug information.
+    pub fn debug_info_for_frame(&self, frame: &StackFrame) -> &DebugInfo {
+        frame
+            .binary_index
+            .checked_sub(1)
+            .and_then(|index| self.merged.get(index))
+            .unwrap_or(self)
+    }
+
+    /// `self` and all [merged](DebugInfo::merge) binaries, together with the offset at which they are loaded.
+    fn binaries(&self) -> impl Iterator<Item = (&DebugInfo, u64)> {
+        std::iter::once((self, 0)).chain(
+            self.merged
+                .iter()
+                .map(|debug_info| (debug_info, debug_info.load_offset)),
+        )
+    }
+
+    /// The index which identifies `debug_info` in [`StackFrame::binary_index`].
+    fn binary_index(&self, debug_info: &DebugInfo) -> usize {
+        self.merged
+            .iter()
+            .position(|merged| std::ptr::eq(merged, debug_info))
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Select the debug information which describes `address`, together with the offset at which it is loaded.
+    ///
+    /// This is `self` with an offset of zero, unless a [merged](DebugInfo::merge) binary is more specific.
+    fn debug_info_for_address(&self, address: u64) -> (&DebugInfo, u64) {
+        // Without merged binaries, there is no need to look up the unit ranges.
+        if self.merged.is_empty() {
+            return (self, 0);
+        }
+
+        let mut best: (&DebugInfo, u64) = (self, 0);
+        let mut best_size = self.unit_range_size(address);
+
+        for debug_info in &self.merged {
+            let Some(local_address) = address.checked_sub(debug_info.load_offset) else {
+                continue;
+            };
+            let Some(size) = debug_info.unit_range_size(local_address) else {
+                continue;
+            };
+
+            if best_size.map_or(true, |best_size| size < best_size) {
+                best = (debug_info, debug_info.load_offset);
+                best_size = Some(size);
+            }
+        }
+
+        best
+    }
+
+    /// The size of the smallest compilation unit address range which contains `address`.
+    fn unit_range_size(&self, address: u64) -> Option<u64> {
+        let mut smallest: Option<u64> = None;
+        let mut units = self.get_units();
+
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let Ok(mut ranges) = self.dwarf.unit_ranges(&unit_info.unit) else {
+                continue;
+            };
+            while let Ok(Some(range)) = ranges.next() {
+                if range.begin <= address && address < range.end {
+                    let size = range.end - range.begin;
+                    smallest = Some(smallest.map_or(size, |smallest| smallest.min(size)));
+                }
+            }
+        }
+
+        smallest
+    }
+
     /// Get the name of the function at the given address.
     ///
-    /// If no function is found, `None` will be returend.
+    /// If no function is found, `None` will be returend, unless the [symbol fallback](DebugInfo::set_symbol_fallback) is enabled.
     ///
     /// ## Inlined functions
     /// Multiple nested inline functions could exist at the given address.
@@ -106,6 +855,11 @@ impl DebugInfo {
         address: u64,
         find_inlined: bool,
     ) -> Result<Option<String>, DebugError> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info.function_name(address - offset, find_inlined);
+        }
+
         let mut units = self.dwarf.units();
 
         while let Some(unit_info) = self.get_next_unit_info(&mut units) {
@@ -122,11 +876,23 @@ impl DebugInfo {
             }
         }
 
+        if self.symbol_fallback {
+            return Ok(self.symbol_name(address));
+        }
+
         Ok(None)
     }
 
     /// Try get the [`SourceLocation`] for a given address.
     pub fn get_source_location(&self, address: u64) -> Option<SourceLocation> {
+        let address = self.lookup_address(address);
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info
+                .get_source_location(address - offset)
+                .map(|location| relocate_source_location(location, offset));
+        }
+
         let mut units = self.dwarf.units();
 
         while let Ok(Some(header)) = units.next() {
@@ -198,6 +964,7 @@ impl DebugInfo {
                                                                     high_pc: Some(
                                                                         target_seq.end as u32,
                                                                     ),
+                                                                    statement_range: None,
                                                                 });
                                                             }
                                                         }
@@ -230,6 +997,7 @@ impl DebugInfo {
                                                                 high_pc: Some(
                                                                     target_seq.end as u32,
                                                                 ),
+                                                                statement_range: None,
                                                             });
                                                         }
                                                     }
@@ -262,55 +1030,269 @@ impl DebugInfo {
         None
     }
 
-    pub(crate) fn get_units(&self) -> UnitIter {
-        self.dwarf.units()
-    }
+    /// Get the address range of the line program sequence which contains `address`.
+    ///
+    /// A sequence is a contiguous range of instructions, e.g. a function, so this can be used to
+    /// determine how much code around `address` can be disassembled. These are the same bounds as
+    /// the `low_pc` and `high_pc` returned by [`DebugInfo::get_source_location`].
+    /// Returns `None` if `address` is not in any sequence.
+    pub fn sequence_range(&self, address: u64) -> Option<Range<u64>> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info
+                .sequence_range(address - offset)
+                .map(|range| range.start + offset..range.end + offset);
+        }
 
-    pub(crate) fn get_next_unit_info(&self, units: &mut UnitIter) -> Option<UnitInfo> {
+        let mut units = self.dwarf.units();
         while let Ok(Some(header)) = units.next() {
-            if let Ok(unit) = self.dwarf.unit(header) {
-                return Some(UnitInfo {
-                    debug_info: self,
-                    unit,
-                });
+            let Ok(unit) = self.dwarf.unit(header) else {
+                continue;
+            };
+            let Some(line_program) = unit.line_program.as_ref() else {
+                continue;
+            };
+            let Ok((_, sequences)) = line_program.clone().sequences() else {
+                continue;
             };
+
+            if let Some(sequence) = sequences
+                .iter()
+                .find(|sequence| sequence.start <= address && address < sequence.end)
+            {
+                return Some(sequence.start..sequence.end);
+            }
         }
+
         None
     }
 
-    /// We do not actually resolve the children of `[VariableName::StaticScope]` automatically, and only create the necessary header in the `VariableCache`.
-    /// This allows us to resolve the `[VariableName::StaticScope]` on demand/lazily, when a user requests it from the debug client.
-    /// This saves a lot of overhead when a user only wants to see the `[VariableName::LocalScope]` or `[VariableName::Registers]` while stepping through code (the most common use cases)
-    pub(crate) fn create_static_scope_cache(
-        &self,
-        core: &mut dyn MemoryInterface,
-        unit_info: &UnitInfo,
-    ) -> Result<VariableCache, DebugError> {
-        let mut static_variable_cache = VariableCache::new();
-
-        // Only process statics for this unit header.
-        let abbrevs = &unit_info.unit.abbreviations;
-        // Navigate the current unit from the header down.
-        if let Ok(mut header_tree) = unit_info.unit.header.entries_tree(abbrevs, None) {
-            let unit_node = header_tree.root()?;
-            let mut static_root_variable = Variable::new(
-                unit_info.unit.header.offset().as_debug_info_offset(),
-                Some(unit_node.entry().offset()),
-            );
-            static_root_variable.variable_node_type = VariableNodeType::DirectLookup;
-            static_root_variable.name = VariableName::StaticScopeRoot;
-            static_variable_cache.cache_variable(None, static_root_variable, core)?;
+    /// Get the [`SourceLocation`] of the statement which contains `address`.
+    ///
+    /// This is the location of the last row at or before `address` which starts a statement (`is_stmt`),
+    /// so rows inside a statement, e.g. the line 0 rows emitted for compiler generated code, are skipped.
+    /// This is the most accurate location for the program counter of a halted core, which usually points
+    /// to the start of a statement. Unlike [`DebugInfo::get_source_location`], it is not suitable for return addresses.
+    pub(crate) fn statement_location(&self, address: u64) -> Option<SourceLocation> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            let relocate = |pc: u32| (u64::from(pc) + offset) as u32;
+
+            return debug_info
+                .statement_location(address - offset)
+                .map(|location| SourceLocation {
+                    low_pc: location.low_pc.map(relocate),
+                    high_pc: location.high_pc.map(relocate),
+                    statement_range: location
+                        .statement_range
+                        .as_ref()
+                        .map(|range| range.start + offset..range.end + offset),
+                    ..location
+                });
         }
-        Ok(static_variable_cache)
-    }
 
-    /// Creates the unpopulated cache for `function` variables
-    pub(crate) fn create_function_scope_cache(
-        &self,
-        memory: &mut dyn MemoryInterface,
-        die_cursor_state: &FunctionDie,
-        unit_info: &UnitInfo,
-    ) -> Result<VariableCache, DebugError> {
+        let mut unit_iter = self.dwarf.units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let in_unit = self
+                .dwarf
+                .unit_ranges(&unit_info.unit)
+                .and_then(|mut ranges| {
+                    while let Some(range) = ranges.next()? {
+                        if range.begin <= address && address < range.end {
+                            return Ok(true);
+                        }
+                    }
+                    Ok(false)
+                })
+                .unwrap_or(false);
+            if !in_unit {
+                continue;
+            }
+
+            let line_program = unit_info.unit.line_program.clone()?;
+            let (program, sequences) = line_program.sequences().ok()?;
+            let sequence = sequences
+                .into_iter()
+                .find(|sequence| sequence.start <= address && address < sequence.end)?;
+
+            let mut statement: Option<SourceLocation> = None;
+            let mut rows = program.resume_from(&sequence);
+            while let Ok(Some((header, row))) = rows.next_row() {
+                if row.end_sequence() || row.address() > address {
+                    // The statement ends where the next one starts, or at the end of the sequence.
+                    if row.end_sequence() || row.is_stmt() {
+                        if let Some(range) = statement
+                            .as_mut()
+                            .and_then(|statement| statement.statement_range.as_mut())
+                        {
+                            range.end = row.address();
+                        }
+                        break;
+                    }
+                    continue;
+                }
+                if !row.is_stmt() {
+                    continue;
+                }
+                if let Some(file_entry) = row.file(header) {
+                    if let Some((file, directory)) =
+                        self.find_file_and_directory(&unit_info.unit, header, file_entry)
+                    {
+                        statement = Some(SourceLocation {
+                            line: row.line().map(NonZeroU64::get),
+                            column: Some(row.column().into()),
+                            file,
+                            directory,
+                            low_pc: Some(sequence.start as u32),
+                            high_pc: Some(sequence.end as u32),
+                            statement_range: Some(row.address()..sequence.end),
+                        });
+                    }
+                }
+            }
+
+            return statement;
+        }
+
+        None
+    }
+
+    /// Find the closest line row at or before `address`, together with the distance in bytes from that row.
+    ///
+    /// Unlike [`DebugInfo::get_source_location`], this also returns a location for addresses which are not covered
+    /// by a line sequence, e.g. padding between functions, so that an approximate location like `main.rs:42 +0x6` can be shown.
+    /// Only the rows of the closest sequence starting at or before `address` are searched, so that function boundaries are not crossed.
+    /// No location is returned for addresses after the last sequence.
+    ///
+    /// With [merged](DebugInfo::merge) binaries, the closest row of all binaries is used.
+    pub fn closest_line(&self, address: u64) -> Option<(SourceLocation, i64)> {
+        self.binaries()
+            .filter_map(|(debug_info, offset)| {
+                let (location, distance) =
+                    debug_info.local_closest_line(address.checked_sub(offset)?)?;
+                Some((relocate_source_location(location, offset), distance))
+            })
+            .min_by_key(|(_, distance)| *distance)
+    }
+
+    /// Find the closest line row at or before `address` in this binary, see [`DebugInfo::closest_line`].
+    fn local_closest_line(&self, address: u64) -> Option<(SourceLocation, i64)> {
+        // The unit, line program and sequence with the highest start address at or before `address`.
+        let mut closest = None;
+        let mut followed_by_code = false;
+
+        let mut unit_iter = self.dwarf.units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let Some(line_program) = unit_info.unit.line_program.clone() else {
+                continue;
+            };
+            let Ok((program, sequences)) = line_program.sequences() else {
+                continue;
+            };
+
+            followed_by_code |= sequences.iter().any(|sequence| sequence.start > address);
+
+            let Some(sequence) = sequences
+                .into_iter()
+                .filter(|sequence| sequence.start <= address)
+                .max_by_key(|sequence| sequence.start)
+            else {
+                continue;
+            };
+
+            if closest.as_ref().map_or(
+                true,
+                |(_, _, closest_sequence): &(UnitInfo, _, gimli::LineSequence<DwarfReader>)| {
+                    sequence.start > closest_sequence.start
+                },
+            ) {
+                closest = Some((unit_info, program, sequence));
+            }
+        }
+
+        let (unit_info, program, sequence) = closest?;
+        if address >= sequence.end && !followed_by_code {
+            return None;
+        }
+
+        let mut closest_row = None;
+        let mut rows = program.resume_from(&sequence);
+        while let Ok(Some((header, row))) = rows.next_row() {
+            if row.end_sequence() || row.address() > address {
+                break;
+            }
+            if let Some(file_entry) = row.file(header) {
+                if let Some((file, directory)) =
+                    self.find_file_and_directory(&unit_info.unit, header, file_entry)
+                {
+                    closest_row = Some((
+                        SourceLocation {
+                            line: row.line().map(NonZeroU64::get),
+                            column: Some(row.column().into()),
+                            file,
+                            directory,
+                            low_pc: Some(sequence.start as u32),
+                            high_pc: Some(sequence.end as u32),
+                            statement_range: None,
+                        },
+                        (address - row.address()) as i64,
+                    ));
+                }
+            }
+        }
+
+        closest_row
+    }
+
+    pub(crate) fn get_units(&self) -> UnitIter {
+        self.dwarf.units()
+    }
+
+    pub(crate) fn get_next_unit_info(&self, units: &mut UnitIter) -> Option<UnitInfo> {
+        while let Ok(Some(header)) = units.next() {
+            if let Ok(unit) = self.dwarf.unit(header) {
+                return Some(UnitInfo {
+                    debug_info: self,
+                    unit,
+                });
+            };
+        }
+        None
+    }
+
+    /// We do not actually resolve the children of `[VariableName::StaticScope]` automatically, and only create the necessary header in the `VariableCache`.
+    /// This allows us to resolve the `[VariableName::StaticScope]` on demand/lazily, when a user requests it from the debug client.
+    /// This saves a lot of overhead when a user only wants to see the `[VariableName::LocalScope]` or `[VariableName::Registers]` while stepping through code (the most common use cases)
+    pub(crate) fn create_static_scope_cache(
+        &self,
+        core: &mut dyn MemoryInterface,
+        unit_info: &UnitInfo,
+    ) -> Result<VariableCache, DebugError> {
+        let mut static_variable_cache = VariableCache::new();
+
+        // Only process statics for this unit header.
+        let abbrevs = &unit_info.unit.abbreviations;
+        // Navigate the current unit from the header down.
+        if let Ok(mut header_tree) = unit_info.unit.header.entries_tree(abbrevs, None) {
+            let unit_node = header_tree.root()?;
+            let mut static_root_variable = Variable::new(
+                unit_info.unit.header.offset().as_debug_info_offset(),
+                Some(unit_node.entry().offset()),
+            );
+            static_root_variable.variable_node_type = VariableNodeType::DirectLookup;
+            static_root_variable.name = VariableName::StaticScopeRoot;
+            static_variable_cache.cache_variable(None, static_root_variable, core)?;
+        }
+        Ok(static_variable_cache)
+    }
+
+    /// Creates the unpopulated cache for `function` variables
+    pub(crate) fn create_function_scope_cache(
+        &self,
+        memory: &mut dyn MemoryInterface,
+        die_cursor_state: &FunctionDie,
+        unit_info: &UnitInfo,
+    ) -> Result<VariableCache, DebugError> {
         let mut function_variable_cache = VariableCache::new();
 
         let abbrevs = &unit_info.unit.abbreviations;
@@ -330,1721 +1312,5815 @@ impl DebugInfo {
         Ok(function_variable_cache)
     }
 
-    /// This effects the on-demand expansion of lazy/deferred load of all the 'child' `Variable`s for a given 'parent'.
-    pub fn cache_deferred_variables(
-        &self,
-        cache: &mut VariableCache,
-        memory: &mut Core<'_>,
-        parent_variable: &mut Variable,
-        stack_frame_registers: &DebugRegisters,
-        frame_base: Option<u64>,
-    ) -> Result<(), DebugError> {
-        if !parent_variable.is_valid() {
-            // Do nothing. The parent_variable.get_value() will already report back the debug_error value.
-            return Ok(());
+    /// Returns the names of the variables and parameters that are in scope at `address`.
+    ///
+    /// Variables declared directly in the non-inlined function containing `address` are always included,
+    /// while variables declared in (nested) lexical blocks are only included if the block's address range
+    /// contains `address`. The names are returned in declaration order, outer scopes first.
+    ///
+    /// Returns an empty list if there is no function at `address`.
+    pub fn variables_in_scope(&self, address: u64) -> Result<Vec<VariableName>, DebugError> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info.variables_in_scope(address - offset);
         }
 
-        match parent_variable.variable_node_type {
-            VariableNodeType::ReferenceOffset(reference_offset) => {
-                // Only attempt this part if we have not yet resolved the referenced children.
-                if !cache.has_children(parent_variable)? {
-                    if let Some(header_offset) = parent_variable.unit_header_offset {
-                        let unit_header =
-                            self.dwarf.debug_info.header_from_offset(header_offset)?;
-                        let unit_info = UnitInfo {
-                            debug_info: self,
-                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
-                        };
-                        // Reference to a type, or an node.entry() to another type or a type modifier which will point to another type.
-                        let mut type_tree = unit_info
-                            .unit
-                            .header
-                            .entries_tree(&unit_info.unit.abbreviations, Some(reference_offset))?;
-                        let referenced_node = type_tree.root()?;
-                        let mut referenced_variable = cache.cache_variable(
-                            Some(parent_variable.variable_key),
-                            Variable::new(
-                                unit_info.unit.header.offset().as_debug_info_offset(),
-                                Some(referenced_node.entry().offset()),
-                            ),
-                            memory,
-                        )?;
+        let mut units = self.get_units();
 
-                        match &parent_variable.name {
-                                VariableName::Named(name) => {
-                                    if name.starts_with("Some") {
-                                        referenced_variable.name =
-                                            VariableName::Named(name.replacen('&', "*", 1));
-                                    } else {
-                                        referenced_variable.name =
-                                            VariableName::Named(format!("*{name}"));
-                                    }
-                                }
-                                other => referenced_variable.name = VariableName::Named(format!("Error: Unable to generate name, parent variable does not have a name but is special variable {other:?}")),
-                            }
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let functions = unit_info.get_function_dies(address, None, false)?;
 
-                        referenced_variable = unit_info.extract_type(
-                            referenced_node,
-                            parent_variable,
-                            referenced_variable,
-                            memory,
-                            stack_frame_registers,
-                            frame_base,
-                            cache,
-                        )?;
+            if let Some(function_die) = functions.first() {
+                return self.function_variables_in_scope(function_die, address);
+            }
+        }
 
-                        if referenced_variable.type_name == VariableType::Base("()".to_owned()) {
-                            // Only use this, if it is NOT a unit datatype.
-                            cache.remove_cache_entry(referenced_variable.variable_key)?;
+        Ok(Vec::new())
+    }
+
+    /// Returns the names of the variables and parameters of `function_die` that are in scope at `program_counter`,
+    /// see [`DebugInfo::variables_in_scope`].
+    fn function_variables_in_scope(
+        &self,
+        function_die: &FunctionDie,
+        program_counter: u64,
+    ) -> Result<Vec<VariableName>, DebugError> {
+        let unit_info = function_die.unit_info;
+        let mut tree = unit_info.unit.header.entries_tree(
+            &unit_info.unit.abbreviations,
+            Some(function_die.function_die.offset()),
+        )?;
+
+        let mut variables = Vec::new();
+        self.collect_variables_in_scope(unit_info, tree.root()?, program_counter, &mut variables)?;
+        Ok(variables)
+    }
+
+    /// Recursive helper for [`DebugInfo::variables_in_scope`].
+    fn collect_variables_in_scope(
+        &self,
+        unit_info: &UnitInfo,
+        node: gimli::EntriesTreeNode<GimliReader>,
+        program_counter: u64,
+        variables: &mut Vec<VariableName>,
+    ) -> Result<(), DebugError> {
+        let mut children = node.children();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            match entry.tag() {
+                gimli::DW_TAG_variable | gimli::DW_TAG_formal_parameter => {
+                    if let Some(name) = self.die_name(unit_info, entry)? {
+                        variables.push(VariableName::Named(name));
+                    }
+                }
+                gimli::DW_TAG_lexical_block => {
+                    let mut ranges = self.dwarf.die_ranges(&unit_info.unit, entry)?;
+                    let mut in_scope = false;
+                    while let Some(range) = ranges.next()? {
+                        if range.begin <= program_counter && program_counter < range.end {
+                            in_scope = true;
+                            break;
                         }
                     }
+                    if in_scope {
+                        self.collect_variables_in_scope(
+                            unit_info,
+                            child,
+                            program_counter,
+                            variables,
+                        )?;
+                    }
                 }
+                // Nested functions and inlined subroutines have their own scope.
+                _ => {}
             }
-            VariableNodeType::TypeOffset(type_offset) => {
-                // Only attempt this if the children are not already resolved.
-                if !cache.has_children(parent_variable)? {
-                    if let Some(header_offset) = parent_variable.unit_header_offset {
-                        let unit_header =
-                            self.dwarf.debug_info.header_from_offset(header_offset)?;
-                        let unit_info = UnitInfo {
-                            debug_info: self,
-                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
-                        };
-                        // Find the parent node
-                        let mut type_tree = unit_info
-                            .unit
-                            .header
-                            .entries_tree(&unit_info.unit.abbreviations, Some(type_offset))?;
-                        let parent_node = type_tree.root()?;
+        }
+        Ok(())
+    }
 
-                        // For process_tree we need to create a temporary parent that will later be eliminated with VariableCache::adopt_grand_children
-                        // TODO: Investigate if UnitInfo::process_tree can be modified to use `&mut parent_variable`, then we would not need this temporary variable.
-                        let mut temporary_variable = parent_variable.clone();
-                        temporary_variable.variable_key = 0;
-                        temporary_variable.parent_key = Some(parent_variable.variable_key);
-                        temporary_variable = cache.cache_variable(
-                            Some(parent_variable.variable_key),
-                            temporary_variable,
-                            memory,
-                        )?;
+    /// Returns the name and type name of each formal parameter of the non-inlined function containing `address`,
+    /// in declaration order.
+    ///
+    /// Artificial parameters, like the implicit `this` of a C++ method, are only included if `include_artificial` is set.
+    /// Parameters without a name are reported as `<unnamed>`.
+    ///
+    /// Returns an empty list if there is no function at `address`.
+    pub fn function_parameters(
+        &self,
+        address: u64,
+        include_artificial: bool,
+    ) -> Result<Vec<(String, String)>, DebugError> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info.function_parameters(address - offset, include_artificial);
+        }
 
-                        temporary_variable = unit_info.process_tree(
-                            parent_node,
-                            temporary_variable,
-                            memory,
-                            stack_frame_registers,
-                            frame_base,
-                            cache,
-                        )?;
+        let mut units = self.get_units();
 
-                        cache.adopt_grand_children(parent_variable, &temporary_variable)?;
-                    }
-                }
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let functions = unit_info.get_function_dies(address, None, false)?;
+
+            if let Some(function_die) = functions.first() {
+                return self.function_die_parameters(function_die, include_artificial);
             }
-            VariableNodeType::DirectLookup => {
-                // Only attempt this if the children are not already resolved.
-                if !cache.has_children(parent_variable)? {
-                    if let Some(header_offset) = parent_variable.unit_header_offset {
-                        let unit_header =
-                            self.dwarf.debug_info.header_from_offset(header_offset)?;
-                        let unit_info = UnitInfo {
-                            debug_info: self,
-                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
-                        };
-                        // Find the parent node
-                        let mut type_tree = unit_info.unit.header.entries_tree(
-                            &unit_info.unit.abbreviations,
-                            parent_variable.variable_unit_offset,
-                        )?;
+        }
 
-                        // For process_tree we need to create a temporary parent that will later be eliminated with VariableCache::adopt_grand_children
-                        // TODO: Investigate if UnitInfo::process_tree can be modified to use `&mut parent_variable`, then we would not need this temporary variable.
-                        let mut temporary_variable = parent_variable.clone();
-                        temporary_variable.variable_key = 0;
-                        temporary_variable.parent_key = Some(parent_variable.variable_key);
-                        temporary_variable = cache.cache_variable(
-                            Some(parent_variable.variable_key),
-                            temporary_variable,
-                            memory,
-                        )?;
-
-                        let parent_node = type_tree.root()?;
+        Ok(Vec::new())
+    }
 
-                        temporary_variable = unit_info.process_tree(
-                            parent_node,
-                            temporary_variable,
-                            memory,
-                            stack_frame_registers,
-                            frame_base,
-                            cache,
-                        )?;
+    /// Returns the formal parameters of `function_die`, see [`DebugInfo::function_parameters`].
+    fn function_die_parameters(
+        &self,
+        function_die: &FunctionDie,
+        include_artificial: bool,
+    ) -> Result<Vec<(String, String)>, DebugError> {
+        let unit_info = function_die.unit_info;
+        let mut tree = unit_info.unit.header.entries_tree(
+            &unit_info.unit.abbreviations,
+            Some(function_die.function_die.offset()),
+        )?;
+
+        let mut parameters = Vec::new();
+        let root = tree.root()?;
+        let mut children = root.children();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            if entry.tag() != gimli::DW_TAG_formal_parameter {
+                continue;
+            }
 
-                        cache.adopt_grand_children(parent_variable, &temporary_variable)?;
-                    }
+            // Inlined and out-of-line instances keep the attributes on their abstract origin.
+            let origin = match entry.attr_value(gimli::DW_AT_abstract_origin)? {
+                Some(gimli::AttributeValue::UnitRef(origin)) => Some(unit_info.unit.entry(origin)?),
+                _ => None,
+            };
+            let attr_value = |attribute| -> Result<_, DebugError> {
+                match entry.attr_value(attribute)? {
+                    Some(value) => Ok(Some(value)),
+                    None => match &origin {
+                        Some(origin) => Ok(origin.attr_value(attribute)?),
+                        None => Ok(None),
+                    },
                 }
+            };
+
+            if !include_artificial
+                && matches!(
+                    attr_value(gimli::DW_AT_artificial)?,
+                    Some(gimli::AttributeValue::Flag(true))
+                )
+            {
+                continue;
             }
-            _ => {
-                // Do nothing. These have already been recursed to their maximum.
+
+            let name = self
+                .die_name(unit_info, entry)?
+                .unwrap_or_else(|| "<unnamed>".to_string());
+            let type_name = match attr_value(gimli::DW_AT_type)? {
+                Some(gimli::AttributeValue::UnitRef(type_offset)) => {
+                    unit_info.modified_type_name(type_offset)
+                }
+                _ => None,
             }
+            .unwrap_or_else(|| "<unknown>".to_string());
+
+            parameters.push((name, type_name));
         }
-        Ok(())
+        Ok(parameters)
     }
 
-    /// Returns a populated (resolved) [`StackFrame`] struct.
-    /// This function will also populate the `DebugInfo::VariableCache` with in scope `Variable`s for each `StackFrame`, while taking into account the appropriate strategy for lazy-loading of variables.
-    pub(crate) fn get_stackframe_info(
+    /// Recover the values of the arguments which the inlined function `function_die` passes to the function it calls at `address`.
+    ///
+    /// Even if the variables of an inlined function are optimized out, the compiler often still describes the arguments of
+    /// its calls with `DW_TAG_call_site_parameter` entries. The call site is the first one of `function_die` (outside of further
+    /// inlined functions) which returns at, or after, `address`, in the same range of the function. The `DW_AT_call_value`
+    /// expressions are evaluated against `registers`, which should be the registers of the frame. Evaluating the parameters is
+    /// expensive, so this is only done on request. Parameters whose value can not be determined without reading memory, or
+    /// which use unsupported operations (e.g. `DW_OP_entry_value`), are skipped.
+    fn inlined_call_arguments(
         &self,
-        memory: &mut dyn MemoryInterface,
+        function_die: &FunctionDie,
         address: u64,
-        unwind_registers: &registers::DebugRegisters,
-    ) -> Result<Vec<StackFrame>, DebugError> {
-        let mut units = self.get_units();
+        registers: &DebugRegisters,
+    ) -> Result<Vec<(String, u64)>, DebugError> {
+        if !function_die.is_inline() {
+            return Ok(Vec::new());
+        }
 
-        // When reporting the address, we format it as a hex string, with the width matching
-        // the configured size of the datatype used in the `RegisterValue` address.
-        let unknown_function = format!(
-            "<unknown function @ {:#0width$x}>",
-            address,
-            width = (unwind_registers.get_address_size_bytes() * 2 + 2)
-        );
+        let Some(range_end) = function_die
+            .ranges()
+            .into_iter()
+            .find(|range| range.contains(&address))
+            .map(|range| range.end)
+        else {
+            return Ok(Vec::new());
+        };
 
-        let mut frames = Vec::new();
+        let unit_info = function_die.unit_info;
+        let mut tree = unit_info.unit.header.entries_tree(
+            &unit_info.unit.abbreviations,
+            Some(function_die.function_die.offset()),
+        )?;
 
-        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
-            let functions = unit_info.get_function_dies(address, Some(unwind_registers), true)?;
+        let mut call_site = None;
+        self.find_call_site(unit_info, tree.root()?, address..=range_end, &mut call_site)?;
+        let Some((_, call_site_offset)) = call_site else {
+            return Ok(Vec::new());
+        };
 
-            if functions.is_empty() {
+        let mut tree = unit_info
+            .unit
+            .header
+            .entries_tree(&unit_info.unit.abbreviations, Some(call_site_offset))?;
+        let call_site_node = tree.root()?;
+        let callee_parameters = self.callee_parameters(unit_info, call_site_node.entry())?;
+
+        let mut arguments = Vec::new();
+        let mut children = call_site_node.children();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            if !matches!(
+                entry.tag(),
+                gimli::DW_TAG_call_site_parameter | gimli::DW_TAG_GNU_call_site_parameter
+            ) {
                 continue;
             }
 
-            // Handle all functions which contain further inlined functions. For
-            // these functions, the location is the call site of the inlined function.
-            for (index, function_die) in functions[0..functions.len() - 1].iter().enumerate() {
-                let mut inlined_call_site: Option<RegisterValue> = None;
-                let mut inlined_caller_source_location: Option<SourceLocation> = None;
-
-                let function_name = function_die
-                    .function_name()
-                    .unwrap_or_else(|| unknown_function.clone());
-
-                tracing::debug!("UNWIND: Function name: {}", function_name);
+            let value_attribute = match entry.attr_value(gimli::DW_AT_call_value)? {
+                Some(value) => Some(value),
+                None => entry.attr_value(gimli::DW_AT_GNU_call_site_value)?,
+            };
+            let Some(gimli::AttributeValue::Exprloc(expression)) = value_attribute else {
+                continue;
+            };
+            let Some(value) = self.call_value(unit_info, expression, registers) else {
+                continue;
+            };
 
-                let next_function = &functions[index + 1];
+            // The parameter of the callee is either referenced, or is in the same location at the entry of the callee.
+            let name = match entry.attr_value(gimli::DW_AT_call_parameter)? {
+                Some(gimli::AttributeValue::UnitRef(parameter)) => {
+                    self.die_name(unit_info, &unit_info.unit.entry(parameter)?)?
+                }
+                _ => {
+                    let location = match entry.attr_value(gimli::DW_AT_location)? {
+                        Some(gimli::AttributeValue::Exprloc(location)) => Some(location),
+                        _ => None,
+                    };
+                    callee_parameters
+                        .iter()
+                        .find(|(parameter_location, _)| {
+                            location.is_some()
+                                && parameter_location
+                                    .as_ref()
+                                    .map(|location| location.0.bytes())
+                                    == location.as_ref().map(|location| location.0.bytes())
+                        })
+                        .and_then(|(_, name)| name.clone())
+                }
+            }
+            .unwrap_or_else(|| "<unnamed>".to_string());
+            arguments.push((name, value));
+        }
+        Ok(arguments)
+    }
 
-                assert!(next_function.is_inline());
+    /// Recursive helper for [`DebugInfo::inlined_call_arguments`], which finds the call site with the lowest
+    /// return address in `return_addresses`. Lexical blocks are searched, but not nested functions.
+    fn find_call_site(
+        &self,
+        unit_info: &UnitInfo,
+        node: gimli::EntriesTreeNode<GimliReader>,
+        return_addresses: std::ops::RangeInclusive<u64>,
+        call_site: &mut Option<(u64, gimli::UnitOffset)>,
+    ) -> Result<(), DebugError> {
+        let mut children = node.children();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            match entry.tag() {
+                gimli::DW_TAG_call_site | gimli::DW_TAG_GNU_call_site => {
+                    let return_address = match entry.attr_value(gimli::DW_AT_call_return_pc)? {
+                        Some(value) => Some(value),
+                        None => entry.attr_value(gimli::DW_AT_low_pc)?,
+                    };
+                    let Some(return_address) = return_address
+                        .map(|value| self.dwarf.attr_address(&unit_info.unit, value))
+                        .transpose()?
+                        .flatten()
+                    else {
+                        continue;
+                    };
+
+                    if return_addresses.contains(&return_address)
+                        && call_site.map_or(true, |(best, _)| return_address < best)
+                    {
+                        *call_site = Some((return_address, entry.offset()));
+                    }
+                }
+                gimli::DW_TAG_lexical_block => {
+                    self.find_call_site(unit_info, child, return_addresses.clone(), call_site)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 
-                // Calculate the call site for this function, so that we can use it later to create an additional 'callee' `StackFrame` from that PC.
-                let address_size = unit_info.unit.header.address_size() as u64;
+    /// The location and name of each formal parameter of the function called at `call_site`, in declaration order.
+    /// The location is the one at the entry of the function, where the arguments are passed.
+    fn callee_parameters(
+        &self,
+        unit_info: &UnitInfo,
+        call_site: &gimli::DebuggingInformationEntry<GimliReader>,
+    ) -> Result<Vec<(Option<gimli::Expression<GimliReader>>, Option<String>)>, DebugError> {
+        let origin = match call_site.attr_value(gimli::DW_AT_call_origin)? {
+            Some(origin) => Some(origin),
+            None => call_site.attr_value(gimli::DW_AT_abstract_origin)?,
+        };
+        let Some(gimli::AttributeValue::UnitRef(callee)) = origin else {
+            return Ok(Vec::new());
+        };
 
-                if next_function.low_pc > address_size && next_function.low_pc < u32::MAX.into() {
-                    // The first instruction of the inlined function is used as the call site
-                    inlined_call_site = Some(RegisterValue::from(next_function.low_pc));
+        let mut tree = unit_info
+            .unit
+            .header
+            .entries_tree(&unit_info.unit.abbreviations, Some(callee))?;
+        let root = tree.root()?;
+        let entry_pc = root
+            .entry()
+            .attr_value(gimli::DW_AT_low_pc)?
+            .map(|value| self.dwarf.attr_address(&unit_info.unit, value))
+            .transpose()?
+            .flatten();
+
+        let mut children = root.children();
+        let mut parameters = Vec::new();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            if entry.tag() != gimli::DW_TAG_formal_parameter {
+                continue;
+            }
 
-                    tracing::debug!(
-                        "UNWIND: Callsite for inlined function {:?}",
-                        next_function.function_name()
-                    );
+            let location = match entry.attr_value(gimli::DW_AT_location)? {
+                Some(gimli::AttributeValue::Exprloc(location)) => Some(location),
+                Some(gimli::AttributeValue::LocationListsRef(offset)) => match entry_pc {
+                    Some(entry_pc) => {
+                        let mut locations = self.locations_section.locations(
+                            offset,
+                            unit_info.unit.header.encoding(),
+                            unit_info.unit.low_pc,
+                            &self.address_section,
+                            unit_info.unit.addr_base,
+                        )?;
+                        let mut entry_location = None;
+                        while let Some(location) = locations.next()? {
+                            if location.range.begin <= entry_pc && entry_pc < location.range.end {
+                                entry_location = Some(location.data);
+                                break;
+                            }
+                        }
+                        entry_location
+                    }
+                    None => None,
+                },
+                _ => None,
+            };
+            parameters.push((location, self.die_name(unit_info, entry)?));
+        }
+        Ok(parameters)
+    }
 
-                    inlined_caller_source_location = next_function.inline_call_location();
-                }
+    /// Evaluate the `DW_AT_call_value` expression of a call site parameter against `registers`.
+    /// Returns `None` if the value can not be determined without reading memory.
+    fn call_value(
+        &self,
+        unit_info: &UnitInfo,
+        expression: gimli::Expression<GimliReader>,
+        registers: &DebugRegisters,
+    ) -> Option<u64> {
+        let pieces = unit_info
+            .expression_to_piece(None, expression, registers, None)
+            .ok()?;
+        let [piece] = pieces.as_slice() else {
+            return None;
+        };
+        // The expression computes the value, so the resulting "address" is the value itself.
+        match &piece.location {
+            gimli::Location::Address { address } => Some(*address),
+            gimli::Location::Value { value } => value.to_u64(u64::MAX).ok(),
+            gimli::Location::Register { register } => registers
+                .get_register_by_dwarf_id(register.0)?
+                .value?
+                .try_into()
+                .ok(),
+            _ => None,
+        }
+    }
 
-                if let Some(inlined_call_site) = inlined_call_site {
-                    tracing::debug!("UNWIND: Call site: {:?}", inlined_caller_source_location);
+    /// Determine where the return value of the function containing `address` is stored when the function returns.
+    ///
+    /// The location follows the calling convention of the target, which passes small values in the return
+    /// registers (e.g. `r0` and `r1` on ARM, or `a0` and `a1` on RISC-V). Like the AAPCS, structures, unions, classes
+    /// and arrays are only returned in a register if they fit into a single one, larger values are stored in memory
+    /// instead. The address of that memory is passed by the caller in the first argument register, and is read from
+    /// `registers`, so it is only reliable at the entry of the function, or if the function kept it in that register.
+    ///
+    /// Inlined functions don't follow the calling convention, so the location is always that of the
+    /// non-inlined function containing `address`.
+    ///
+    /// Returns `None` for functions without a return value, if the size of the return type is unknown,
+    /// or if there is no function at `address`.
+    pub fn return_value_location(
+        &self,
+        address: u64,
+        registers: &DebugRegisters,
+    ) -> Result<Option<VariableStorage>, DebugError> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info.return_value_location(address - offset, registers);
+        }
 
-                    // Now that we have the function_name and function_source_location, we can create the appropriate variable caches for this stack frame.
-                    // Resolve the statics that belong to the compilation unit that this function is in.
-                    let static_variables = self
-                        .create_static_scope_cache(memory, &unit_info)
-                        .map_or_else(
-                            |error| {
-                                tracing::error!(
-                                    "Could not resolve static variables. {}. Continuing...",
-                                    error
-                                );
-                                None
-                            },
-                            Some,
-                        );
+        let mut units = self.get_units();
 
-                    // Next, resolve and cache the function variables.
-                    let local_variables = self
-                        .create_function_scope_cache(memory, function_die, &unit_info)
-                        .map_or_else(
-                            |error| {
-                                tracing::error!(
-                                    "Could not resolve function variables. {}. Continuing...",
-                                    error
-                                );
-                                None
-                            },
-                            Some,
-                        );
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let functions = unit_info.get_function_dies(address, None, false)?;
 
-                    frames.push(StackFrame {
-                        // MS DAP Specification requires the id to be unique accross all threads, so using  so using unique `Variable::variable_key` of the `stackframe_root_variable` as the id.
-                        id: get_sequential_key(),
-                        function_name,
-                        source_location: inlined_caller_source_location,
-                        registers: unwind_registers.clone(),
-                        pc: inlined_call_site,
-                        frame_base: function_die.frame_base,
-                        is_inlined: function_die.is_inline(),
-                        static_variables,
-                        local_variables,
-                    });
-                } else {
-                    tracing::warn!(
-                        "UNWIND: Unknown call site for inlined function {}.",
-                        function_name
-                    );
-                }
+            if let Some(function_die) = functions.first() {
+                return Ok(self.function_return_value_location(function_die, registers));
             }
+        }
 
-            // Handle last function, which contains no further inlined functions
-            //UNWRAP: Checked at beginning of loop, functions must contain at least one value
-            #[allow(clippy::unwrap_used)]
-            let last_function = functions.last().unwrap();
-
-            let function_name = last_function
-                .function_name()
-                .unwrap_or_else(|| unknown_function.clone());
-
-            let function_location = self.get_source_location(address);
-
-            // Now that we have the function_name and function_source_location, we can create the appropriate variable caches for this stack frame.
-            // Resolve the statics that belong to the compilation unit that this function is in.
-            let static_variables = self
-                .create_static_scope_cache(memory, &unit_info)
-                .map_or_else(
-                    |error| {
-                        tracing::error!(
-                            "Could not resolve static variables. {}. Continuing...",
-                            error
-                        );
-                        None
-                    },
-                    Some,
-                );
+        Ok(None)
+    }
 
-            // Next, resolve and cache the function variables.
-            let local_variables = self
-                .create_function_scope_cache(memory, last_function, &unit_info)
-                .map_or_else(
-                    |error| {
-                        tracing::error!(
-                            "Could not resolve function variables. {}. Continuing...",
-                            error
-                        );
-                        None
-                    },
-                    Some,
-                );
+    /// Determine where the return value of `function_die` is stored, see [`DebugInfo::return_value_location`].
+    fn function_return_value_location(
+        &self,
+        function_die: &FunctionDie,
+        registers: &DebugRegisters,
+    ) -> Option<VariableStorage> {
+        let unit_info = function_die.unit_info;
+
+        // The return type of a member function can also be on its declaration.
+        let type_attribute = function_die.get_attribute(gimli::DW_AT_type).or_else(|| {
+            match function_die
+                .function_die
+                .attr_value(gimli::DW_AT_specification)
+                .ok()??
+            {
+                gimli::AttributeValue::UnitRef(specification) => unit_info
+                    .unit
+                    .entry(specification)
+                    .ok()?
+                    .attr(gimli::DW_AT_type)
+                    .ok()?,
+                _ => None,
+            }
+        })?;
+        let gimli::AttributeValue::UnitRef(mut type_offset) = type_attribute.value() else {
+            return None;
+        };
 
-            frames.push(StackFrame {
-                // MS DAP Specification requires the id to be unique accross all threads, so using  so using unique `Variable::variable_key` of the `stackframe_root_variable` as the id.
-                id: get_sequential_key(),
-                function_name,
-                source_location: function_location,
-                registers: unwind_registers.clone(),
-                pc: match unwind_registers.get_address_size_bytes() {
-                    4 => RegisterValue::U32(address as u32),
-                    8 => RegisterValue::U64(address),
-                    _ => RegisterValue::from(address),
+        // Skip typedefs and qualifiers, which don't have a size of their own.
+        let (byte_size, is_composite) = loop {
+            let entry = unit_info.unit.entry(type_offset).ok()?;
+            match entry.tag() {
+                gimli::DW_TAG_typedef
+                | gimli::DW_TAG_const_type
+                | gimli::DW_TAG_volatile_type
+                | gimli::DW_TAG_restrict_type
+                | gimli::DW_TAG_atomic_type => match entry.attr_value(gimli::DW_AT_type).ok()?? {
+                    gimli::AttributeValue::UnitRef(referenced_type) => {
+                        type_offset = referenced_type;
+                    }
+                    _ => return None,
                 },
-                frame_base: last_function.frame_base,
-                is_inlined: last_function.is_inline(),
-                static_variables,
-                local_variables,
-            });
+                // Pointers usually have no byte size in the debug information.
+                gimli::DW_TAG_pointer_type
+                | gimli::DW_TAG_reference_type
+                | gimli::DW_TAG_rvalue_reference_type => {
+                    break (
+                        super::extract_byte_size(&entry)
+                            .unwrap_or(registers.get_address_size_bytes() as u64),
+                        false,
+                    )
+                }
+                gimli::DW_TAG_structure_type
+                | gimli::DW_TAG_union_type
+                | gimli::DW_TAG_class_type
+                | gimli::DW_TAG_array_type => break (super::extract_byte_size(&entry)?, true),
+                _ => break (super::extract_byte_size(&entry)?, false),
+            }
+        };
 
-            break;
+        let return_registers: Vec<_> = registers
+            .0
+            .iter()
+            .filter(|register| {
+                register
+                    .core_register
+                    .roles
+                    .iter()
+                    .any(|role| matches!(role, RegisterRole::Return(_)))
+            })
+            .collect();
+        let register_size = registers.get_address_size_bytes() as u64;
+        if register_size == 0 {
+            return None;
         }
-        Ok(frames)
-    }
+        let register_count = if is_composite && byte_size > register_size {
+            usize::MAX
+        } else {
+            ((byte_size + register_size - 1) / register_size).max(1) as usize
+        };
 
-    /// Performs the logical unwind of the stack and returns a `Vec<StackFrame>`
-    /// - The first 'StackFrame' represents the frame at the current PC (program counter), and ...
-    /// - Each subsequent `StackFrame` represents the **previous or calling** `StackFrame` in the call stack.
-    /// - The majority of the work happens in the `'unwind: while` loop, where each iteration will create a `StackFrame` where possible, and update the `unwind_registers` to prepare for the next iteration.
-    ///
-    /// The unwind loop will continue until we meet one of the following conditions:
-    /// - We can no longer unwind a valid PC value to be used for the next frame.
-    /// - We encounter a LR register value of 0x0 or 0xFFFFFFFF(Arm 'Reset' value for that register).
-    /// - We can not intelligently calculate a valid LR register value from the other registers, or the gimli::RegisterRule result is a value of 0x0. Note: [DWARF](https://dwarfstd.org) 6.4.4 - CIE defines the return register address used in the `gimli::RegisterRule` tables for unwind operations. Theoretically, if we encounter a function that has `Undefined` `gimli::RegisterRule` for the return register address, it means we have reached the bottom of the stack OR the function is a 'no return' type of function. I have found actual examples (e.g. local functions) where we get `Undefined` for register rule when we cannot apply this logic. Example 1: local functions in main.rs will have LR rule as `Undefined`. Example 2: main()-> ! that is called from a trampoline will have a valid LR rule.
-    /// - Similarly, certain error conditions encountered in `StackFrameIterator` will also break out of the unwind loop.
-    /// Note: In addition to populating the `StackFrame`s, this function will also populate the `DebugInfo::VariableCache` with `Variable`s for available Registers as well as static and function variables.
-    /// TODO: Separate logic for stackframe creation and cache population
-    pub fn unwind(&self, core: &mut Core<'_>) -> Result<Vec<StackFrame>, crate::Error> {
-        let initial_registers = DebugRegisters::from_core(core);
-        let exception_handler = exception_handler_for_core(core.core_type());
-        let instruction_set = core.instruction_set().ok();
+        if register_count <= return_registers.len() {
+            Some(VariableStorage::Registers(
+                return_registers[..register_count]
+                    .iter()
+                    .map(|register| register.core_register.id)
+                    .collect(),
+            ))
+        } else {
+            let address = return_registers.first()?.value?.try_into().ok()?;
+            Some(VariableStorage::Memory(address))
+        }
+    }
 
-        self.unwind_impl(initial_registers, core, exception_handler, instruction_set)
+    /// Resolves the name of a DIE, following `DW_AT_abstract_origin` for inlined or out-of-line instances.
+    fn die_name(
+        &self,
+        unit_info: &UnitInfo,
+        entry: &gimli::DebuggingInformationEntry<GimliReader>,
+    ) -> Result<Option<String>, DebugError> {
+        if let Some(name) = entry.attr_value(gimli::DW_AT_name)? {
+            return Ok(Some(super::extract_name(self, name)));
+        }
+        if let Some(gimli::AttributeValue::UnitRef(origin)) =
+            entry.attr_value(gimli::DW_AT_abstract_origin)?
+        {
+            let origin_entry = unit_info.unit.entry(origin)?;
+            if let Some(name) = origin_entry.attr_value(gimli::DW_AT_name)? {
+                return Ok(Some(super::extract_name(self, name)));
+            }
+        }
+        Ok(None)
     }
 
-    pub(crate) fn unwind_impl(
+    /// Creates the static and function variable caches for a single [`StackFrame`].
+    /// Errors are logged, and result in a `None` value for the affected cache, so that the unwind can continue.
+    fn create_frame_variable_caches(
         &self,
-        initial_registers: registers::DebugRegisters,
         memory: &mut dyn MemoryInterface,
-        exception_handler: Box<dyn ExceptionInterface>,
-        instruction_set: Option<InstructionSet>,
-    ) -> Result<Vec<StackFrame>, crate::Error> {
-        let mut stack_frames = Vec::<StackFrame>::new();
+        function_die: &FunctionDie,
+        unit_info: &UnitInfo,
+    ) -> (Option<VariableCache>, Option<VariableCache>) {
+        // Resolve the statics that belong to the compilation unit that this function is in.
+        let static_variables = self
+            .create_static_scope_cache(memory, unit_info)
+            .map_or_else(
+                |error| {
+                    tracing::error!(
+                        "Could not resolve static variables. {}. Continuing...",
+                        error
+                    );
+                    None
+                },
+                Some,
+            );
 
-        let mut unwind_context: Box<UnwindContext<DwarfReader>> =
-            Box::new(gimli::UnwindContext::new());
+        // Next, resolve and cache the function variables.
+        let local_variables = self
+            .create_function_scope_cache(memory, function_die, unit_info)
+            .map_or_else(
+                |error| {
+                    tracing::error!(
+                        "Could not resolve function variables. {}. Continuing...",
+                        error
+                    );
+                    None
+                },
+                Some,
+            );
 
-        let mut unwind_registers = initial_registers;
+        (static_variables, local_variables)
+    }
 
-        // Unwind [StackFrame]'s for as long as we can unwind a valid PC value.
-        'unwind: while let Some(frame_pc_register_value) = unwind_registers
-            .get_program_counter()
-            .and_then(|pc| pc.value)
-        {
-            // PART 0: The first step is to determine the exception context for the current PC.
-            // - If we are at an exception hanlder frame, we need to overwrite the unwind registers with the exception context.
-            // - If for some reason we cannot determine the exception context, we silently continue with the rest of the unwind.
-            // At worst, the unwind will be able to unwind the stack to the frame of the most recent exception handler.
-            let exception_info = match exception_handler
-                .exception_details(memory, &unwind_registers)
-            {
-                Ok(Some(exception_info)) => {
-                    tracing::trace!(
-                        "UNWIND: Found exception context: {}",
-                        exception_info.description
-                    );
-                    Some(exception_info)
-                }
-                Ok(None) => {
-                    tracing::trace!(
-                        "UNWIND: No exception context found. Stack unwind will continue."
-                    );
-                    None
-                }
-                Err(e) => {
-                    tracing::warn!("UNWIND: Error while checking for exception context. The stack trace will not include the calling frames. : {}", e);
-                    None
-                }
-            };
+    /// This effects the on-demand expansion of lazy/deferred load of all the 'child' `Variable`s for a given 'parent'.
+    pub fn cache_deferred_variables(
+        &self,
+        cache: &mut VariableCache,
+        memory: &mut Core<'_>,
+        parent_variable: &mut Variable,
+        stack_frame_registers: &DebugRegisters,
+        frame_base: Option<u64>,
+    ) -> Result<(), DebugError> {
+        if !parent_variable.is_valid() {
+            // Do nothing. The parent_variable.get_value() will already report back the debug_error value.
+            return Ok(());
+        }
 
-            // PART 1: Construct the `StackFrame` for the current pc.
-            let frame_pc = frame_pc_register_value
-                .try_into()
-                .map_err(|error| crate::Error::Register(format!("Cannot convert register value for program counter to a 64-bit integer value: {:?}", error)))?;
-            tracing::trace!(
-                "UNWIND: Will generate `StackFrame` for function at address (PC) {:#}",
-                frame_pc_register_value
-            );
+        match parent_variable.variable_node_type {
+            VariableNodeType::ReferenceOffset(reference_offset) => {
+                // Only attempt this part if we have not yet resolved the referenced children.
+                if !cache.has_children(parent_variable)? {
+                    if let Some(header_offset) = parent_variable.unit_header_offset {
+                        let unit_header =
+                            self.dwarf.debug_info.header_from_offset(header_offset)?;
+                        let unit_info = UnitInfo {
+                            debug_info: self,
+                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
+                        };
+                        // Reference to a type, or an node.entry() to another type or a type modifier which will point to another type.
+                        let mut type_tree = unit_info
+                            .unit
+                            .header
+                            .entries_tree(&unit_info.unit.abbreviations, Some(reference_offset))?;
+                        let referenced_node = type_tree.root()?;
+                        let mut referenced_variable = cache.cache_variable(
+                            Some(parent_variable.variable_key),
+                            Variable::new(
+                                unit_info.unit.header.offset().as_debug_info_offset(),
+                                Some(referenced_node.entry().offset()),
+                            ),
+                            memory,
+                        )?;
 
-            // PART 1-a: Prepare the `StackFrame` that holds the current frame information.
+                        match &parent_variable.name {
+                                VariableName::Named(name) => {
+                                    if name.starts_with("Some") {
+                                        referenced_variable.name =
+                                            VariableName::Named(name.replacen('&', "*", 1));
+                                    } else {
+                                        referenced_variable.name =
+                                            VariableName::Named(format!("*{name}"));
+                                    }
+                                }
+                                other => referenced_variable.name = VariableName::Named(format!("Error: Unable to generate name, parent variable does not have a name but is special variable {other:?}")),
+                            }
 
-            let mut cached_stack_frames =
-                match self.get_stackframe_info(memory, frame_pc, &unwind_registers) {
-                    Ok(cached_stack_frames) => cached_stack_frames,
-                    Err(e) => {
-                        tracing::error!(
-                            "UNWIND: Unable to complete `StackFrame` information: {}",
-                            e
-                        );
-                        // There is no point in continuing with the unwind, so let's get out of here.
-                        break;
-                    }
-                };
+                        referenced_variable = unit_info.extract_type(
+                            referenced_node,
+                            parent_variable,
+                            referenced_variable,
+                            memory,
+                            stack_frame_registers,
+                            frame_base,
+                            cache,
+                        )?;
 
-            while cached_stack_frames.len() > 1 {
-                // If we encountered INLINED functions (all `StackFrames`s in this Vec, except for the last one, which is the containing NON-INLINED function), these are simply added to the list of stack_frames we return.
-                #[allow(clippy::unwrap_used)]
-                let inlined_frame = cached_stack_frames.pop().unwrap(); // unwrap is safe while .len() > 1
-                tracing::trace!(
-                    "UNWIND: Found inlined function - name={}, pc={}",
-                    inlined_frame.function_name,
-                    inlined_frame.pc
-                );
-                stack_frames.push(inlined_frame);
+                        if referenced_variable.type_name == VariableType::Base("()".to_owned()) {
+                            // Only use this, if it is NOT a unit datatype.
+                            cache.remove_cache_entry(referenced_variable.variable_key)?;
+                        }
+                    }
+                }
             }
+            VariableNodeType::TypeOffset(type_offset) => {
+                // Only attempt this if the children are not already resolved.
+                if !cache.has_children(parent_variable)? {
+                    if let Some(header_offset) = parent_variable.unit_header_offset {
+                        let unit_header =
+                            self.dwarf.debug_info.header_from_offset(header_offset)?;
+                        let unit_info = UnitInfo {
+                            debug_info: self,
+                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
+                        };
+                        // Find the parent node
+                        let mut type_tree = unit_info
+                            .unit
+                            .header
+                            .entries_tree(&unit_info.unit.abbreviations, Some(type_offset))?;
+                        let parent_node = type_tree.root()?;
 
-            let mut only_exception = false;
+                        // For process_tree we need to create a temporary parent that will later be eliminated with VariableCache::adopt_grand_children
+                        // TODO: Investigate if UnitInfo::process_tree can be modified to use `&mut parent_variable`, then we would not need this temporary variable.
+                        let mut temporary_variable = parent_variable.clone();
+                        temporary_variable.variable_key = 0;
+                        temporary_variable.parent_key = Some(parent_variable.variable_key);
+                        temporary_variable = cache.cache_variable(
+                            Some(parent_variable.variable_key),
+                            temporary_variable,
+                            memory,
+                        )?;
 
-            let return_frame = match cached_stack_frames.pop() {
-                Some(frame) => frame,
-                None => {
-                    if let Some(exception_info) = &exception_info {
-                        only_exception = true;
-                        let address = frame_pc;
+                        temporary_variable = unit_info.process_tree(
+                            parent_node,
+                            temporary_variable,
+                            memory,
+                            stack_frame_registers,
+                            frame_base,
+                            cache,
+                        )?;
 
-                        let previous_regs = unwind_registers.clone();
+                        cache.adopt_grand_children(parent_variable, &temporary_variable)?;
+                    }
+                }
+            }
+            VariableNodeType::DirectLookup => {
+                // Only attempt this if the children are not already resolved.
+                if !cache.has_children(parent_variable)? {
+                    if let Some(header_offset) = parent_variable.unit_header_offset {
+                        let unit_header =
+                            self.dwarf.debug_info.header_from_offset(header_offset)?;
+                        let unit_info = UnitInfo {
+                            debug_info: self,
+                            unit: gimli::Unit::new(&self.dwarf, unit_header)?,
+                        };
+                        // Find the parent node
+                        let mut type_tree = unit_info.unit.header.entries_tree(
+                            &unit_info.unit.abbreviations,
+                            parent_variable.variable_unit_offset,
+                        )?;
 
-                        StackFrame {
-                            id: get_sequential_key(),
-                            function_name: exception_info.description.clone(),
-                            source_location: None,
-                            registers: previous_regs,
-                            pc: match unwind_registers.get_address_size_bytes() {
-                                4 => RegisterValue::U32(address as u32),
-                                8 => RegisterValue::U64(address),
-                                _ => RegisterValue::from(address),
-                            },
-                            frame_base: None,
-                            is_inlined: false,
-                            static_variables: None,
-                            local_variables: None,
-                        }
-                    } else {
-                        let address = frame_pc;
+                        // For process_tree we need to create a temporary parent that will later be eliminated with VariableCache::adopt_grand_children
+                        // TODO: Investigate if UnitInfo::process_tree can be modified to use `&mut parent_variable`, then we would not need this temporary variable.
+                        let mut temporary_variable = parent_variable.clone();
+                        temporary_variable.variable_key = 0;
+                        temporary_variable.parent_key = Some(parent_variable.variable_key);
+                        temporary_variable = cache.cache_variable(
+                            Some(parent_variable.variable_key),
+                            temporary_variable,
+                            memory,
+                        )?;
 
-                        // When reporting the address, we format it as a hex string, with the width matching
-                        // the configured size of the datatype used in the `RegisterValue` address.
-                        let unknown_function = format!(
-                            "<unknown function @ {:#0width$x}>",
-                            address,
-                            width = (unwind_registers.get_address_size_bytes() * 2 + 2)
-                        );
+                        let parent_node = type_tree.root()?;
 
-                        StackFrame {
-                            id: get_sequential_key(),
-                            function_name: unknown_function,
-                            source_location: self.get_source_location(address),
-                            registers: unwind_registers.clone(),
-                            pc: match unwind_registers.get_address_size_bytes() {
-                                4 => RegisterValue::U32(address as u32),
-                                8 => RegisterValue::U64(address),
-                                _ => RegisterValue::from(address),
-                            },
-                            frame_base: None,
-                            is_inlined: false,
-                            static_variables: None,
-                            local_variables: None,
-                        }
+                        temporary_variable = unit_info.process_tree(
+                            parent_node,
+                            temporary_variable,
+                            memory,
+                            stack_frame_registers,
+                            frame_base,
+                            cache,
+                        )?;
+
+                        cache.adopt_grand_children(parent_variable, &temporary_variable)?;
                     }
                 }
-            };
+            }
+            _ => {
+                // Do nothing. These have already been recursed to their maximum.
+            }
+        }
+        Ok(())
+    }
 
-            // Part 1-b: Check LR values to determine if we can continue unwinding.
-            let Some(check_return_address) = unwind_registers.get_return_address() else {
-                // If the debug info rules result in a None return address, we cannot continue unwinding.
-                stack_frames.push(return_frame);
-                tracing::trace!("UNWIND: Stack unwind complete - LR register value is 'None.");
-                break;
-            };
+    /// The `call_site_arguments` of the `StackFrame` for `function_die`, if `find_call_site_arguments` is set.
+    /// Errors are logged, and result in `None`, so that the unwind can continue.
+    fn frame_call_site_arguments(
+        &self,
+        function_die: &FunctionDie,
+        address: u64,
+        registers: &DebugRegisters,
+        find_call_site_arguments: bool,
+    ) -> Option<Vec<(String, u64)>> {
+        if !find_call_site_arguments || !function_die.is_inline() {
+            return None;
+        }
 
-            if check_return_address.is_max_value() || check_return_address.is_zero() {
-                // When we encounter the starting (after reset) return address, we've reached the bottom of the stack, so no more unwinding after this.
-                stack_frames.push(return_frame);
-                tracing::trace!(
-                    "UNWIND: Stack unwind complete - Reached the 'Reset' value of the LR register."
-                );
-                break;
+        match self.inlined_call_arguments(function_die, address, registers) {
+            Ok(arguments) => Some(arguments),
+            Err(error) => {
+                tracing::warn!("UNWIND: Failed to recover call site arguments: {error}");
+                None
             }
+        }
+    }
 
-            // Part 1-c: If the target current frame is an exception handler, we need to update the `unwind_registers` to match the frame that invoked the exception handler.
-            if let Some(exception_info) = exception_info {
-                tracing::trace!(
-                    "UNWIND: Stack unwind reached an exception handler {}",
-                    exception_info.description
-                );
-
-                tracing::trace!(
-                    "UNWIND: Stack unwind will attempt to unwind the frame that invoked {}.",
-                    exception_info.description
-                );
+    /// Returns a populated (resolved) [`StackFrame`] struct.
+    /// This function will also populate the `DebugInfo::VariableCache` with in scope `Variable`s for each `StackFrame`, while taking into account the appropriate strategy for lazy-loading of variables.
+    /// If `populate_variable_caches` is `false`, the variable caches are not created, and the `static_variables` and `local_variables` of the returned `StackFrame`s will be `None`.
+    /// If `find_inlined` is `false`, inlined functions are not resolved, and only a single `StackFrame` is returned for the outermost function,
+    /// which is much cheaper for functions with a large tree of inlined functions.
+    /// If `find_call_site_arguments` is `true`, the `call_site_arguments` of the inlined `StackFrame`s are recovered.
+    pub(crate) fn get_stackframe_info(
+        &self,
+        memory: &mut dyn MemoryInterface,
+        address: u64,
+        unwind_registers: &registers::DebugRegisters,
+        populate_variable_caches: bool,
+        find_inlined: bool,
+        find_call_site_arguments: bool,
+    ) -> Result<Vec<StackFrame>, DebugError> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            let mut frames = debug_info.get_stackframe_info(
+                memory,
+                address - offset,
+                unwind_registers,
+                populate_variable_caches,
+                find_inlined,
+                find_call_site_arguments,
+            )?;
+
+            let binary_index = self.binary_index(debug_info);
+            for frame in frames.iter_mut() {
+                frame.binary_index = binary_index;
+                frame.pc = match frame.pc {
+                    RegisterValue::U32(pc) => RegisterValue::U32((u64::from(pc) + offset) as u32),
+                    RegisterValue::U64(pc) => RegisterValue::U64(pc + offset),
+                    other => other,
+                };
+                frame.source_location = frame
+                    .source_location
+                    .take()
+                    .map(|location| relocate_source_location(location, offset));
+            }
 
-                // Now that we've optionally updated the `unwind_registers` to match the exception handler, we can continue.
-                if only_exception {
-                    // If we are at an exception handler frame, we need to overwrite the unwind registers.
-                    // This will allow us to continue unwinding from the exception handler frame.
-                    unwind_registers = exception_info.calling_frame_registers;
+            return Ok(frames);
+        }
 
-                    stack_frames.push(return_frame);
-                    continue;
-                }
-            }
+        let mut units = self.get_units();
 
-            // PART 2: Setup the registers for the next iteration (a.k.a. unwind previous frame, a.k.a. "callee", in the call stack).
-            tracing::trace!(
-                "UNWIND - Preparing `StackFrameIterator` to unwind NON-INLINED function {:?} at {:?}",
-                return_frame.function_name,
-                return_frame.source_location
-            );
-            // PART 2-a: get the `gimli::FrameDescriptorEntry` for this address and then the unwind info associated with this row.
-            let unwind_info = match get_unwind_info(
-                &mut unwind_context,
-                &self.frame_section,
-                frame_pc,
-            ) {
-                Ok(unwind_info) => unwind_info,
-                Err(error) => {
-                    // We cannot do stack unwinding if we do not have debug info. However, there is one case where we can continue. When the following conditions are met:
-                    // 1. The current frame is the first frame in the stack, AND ...
-                    // 2. The frame registers have a valid return address/LR value.
-                    // If both these conditions are met, we can push the 'unknown function' to the list of stack frames, and use the LR value to calculate the PC for the calling frame.
-                    // The current logic will then use that PC to get the next frame's unwind info, and if that exists, will be able to continue unwinding.
-                    // If the calling frame has no debug info, then the unwindindg will end with that frame.
-                    if stack_frames.is_empty() {
-                        let callee_frame_registers = unwind_registers.clone();
-                        let mut unwound_return_address: Option<RegisterValue> =
-                            callee_frame_registers
-                                .get_return_address()
-                                .and_then(|lr| lr.value);
+        let unknown_function =
+            self.unknown_function_name(address, unwind_registers.get_address_size_bytes());
 
-                        if let Some(calling_pc) = unwind_registers.get_program_counter_mut() {
-                            if unwind_register(
-                                calling_pc,
-                                &callee_frame_registers,
-                                None,
-                                None,
-                                &mut unwound_return_address,
-                                memory,
-                                instruction_set,
-                            )
-                            .is_break()
-                            {
-                                // We were not able to get a PC for the calling frame, so we cannot continue unwinding.
-                                stack_frames.push(return_frame);
-                                break 'unwind;
-                            } else {
-                                // The unwind registers were updated with the calling frame's PC, so we can continue unwinding.
-                                stack_frames.push(return_frame);
-                                continue 'unwind;
-                            };
-                        } else {
-                            stack_frames.push(return_frame);
-                            continue 'unwind;
-                        }
-                    } else {
-                        stack_frames.push(return_frame);
-                        tracing::trace!("UNWIND: Stack unwind complete. No available debug info for program counter {}: {}", frame_pc, error);
-                        break;
-                    }
-                }
-            };
+        let mut frames = Vec::new();
 
-            // Because we will be updating the `unwind_registers` with previous frame unwind info, we need to keep a copy of the current frame's registers that can be used to resolve [DWARF](https://dwarfstd.org) expressions.
-            let callee_frame_registers = unwind_registers.clone();
-            // PART 2-b: Determine the CFA (canonical frame address) to use for this unwind row.
-            let unwind_cfa = match unwind_info.cfa() {
-                gimli::CfaRule::RegisterAndOffset { register, offset } => {
-                    let reg_val = unwind_registers
-                        .get_register_by_dwarf_id(register.0)
-                        .and_then(|register| register.value);
-                    match reg_val {
-                        Some(reg_val) => {
-                            if reg_val.is_zero() {
-                                // If we encounter this rule for CFA, it implies the scenario depends on a FP/frame pointer to continue successfully.
-                                // Therefore, if reg_val is zero (i.e. FP is zero), then we do not have enough information to determine the CFA by rule.
-                                stack_frames.push(return_frame);
-                                tracing::trace!("UNWIND: Stack unwind complete - The FP register value unwound to a value of zero.");
-                                break;
-                            }
-                            let unwind_cfa = add_to_address(
-                                reg_val.try_into()?,
-                                *offset,
-                                unwind_registers.get_address_size_bytes(),
-                            );
-                            tracing::trace!(
-                                "UNWIND - CFA : {:#010x}\tRule: {:?}",
-                                unwind_cfa,
-                                unwind_info.cfa()
-                            );
-                            Some(unwind_cfa)
-                        }
-                        None => {
-                            tracing::error!("UNWIND: `StackFrameIterator` unable to determine the unwind CFA: Missing value of register {}",register.0);
-                            stack_frames.push(return_frame);
-                            break;
-                        }
-                    }
-                }
-                gimli::CfaRule::Expression(_) => unimplemented!(),
-            };
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let mut functions =
+                unit_info.get_function_dies(address, Some(unwind_registers), find_inlined)?;
 
-            // PART 2-c: Unwind registers for the "previous/calling" frame.
-            // We sometimes need to keep a copy of the LR value to calculate the PC. For both ARM, and RISCV, The LR will be unwound before the PC, so we can reference it safely.
-            let mut unwound_return_address: Option<RegisterValue> = None;
-            for debug_register in unwind_registers.0.iter_mut() {
-                if unwind_register(
-                    debug_register,
-                    &callee_frame_registers,
-                    Some(unwind_info),
-                    unwind_cfa,
-                    &mut unwound_return_address,
-                    memory,
-                    instruction_set,
-                )
-                .is_break()
-                {
-                    stack_frames.push(return_frame);
-                    break 'unwind;
-                };
+            if functions.is_empty() {
+                continue;
             }
 
-            stack_frames.push(return_frame);
+            discard_overlapping_functions(&mut functions, address);
 
-            // Check if we unwound over an exception handler
-            if let Some(value) = unwind_registers.get_program_counter().and_then(|s| s.value) {
-                let value: u32 = value.try_into().unwrap();
+            // Handle all functions which contain further inlined functions. For
+            // these functions, the location is the call site of the inlined function.
+            for (index, function_die) in functions[0..functions.len() - 1].iter().enumerate() {
+                let mut inlined_call_site: Option<RegisterValue> = None;
+                let mut inlined_caller_source_location: Option<SourceLocation> = None;
 
-                if (value >> 28) & 0xf == 0xf {
-                    let ra = unwind_registers
-                        .get_register_mut_by_role(&RegisterRole::ReturnAddress)
-                        .unwrap();
-                    ra.value = Some(RegisterValue::U32(value));
+                let function_name = function_die
+                    .function_name()
+                    .unwrap_or_else(|| unknown_function.clone());
 
-                    // Now, how do we handle this.
-                    if let Some(details) =
-                        exception_handler.exception_details(memory, &unwind_registers)?
-                    {
-                        unwind_registers = details.calling_frame_registers;
-                        let address = frame_pc;
+                tracing::debug!("UNWIND: Function name: {}", function_name);
 
-                        let exception_frame = StackFrame {
-                            id: get_sequential_key(),
-                            function_name: details.description.clone(),
-                            source_location: None,
-                            registers: unwind_registers.clone(),
-                            pc: match unwind_registers.get_address_size_bytes() {
-                                4 => RegisterValue::U32(address as u32),
-                                8 => RegisterValue::U64(address),
-                                _ => RegisterValue::from(address),
-                            },
-                            frame_base: None,
-                            is_inlined: false,
-                            static_variables: None,
-                            local_variables: None,
-                        };
+                let next_function = &functions[index + 1];
 
-                        stack_frames.push(exception_frame);
-                    }
-                }
-            }
-        }
+                // Calculate the call site for this function, so that we can use it later to create an additional 'callee' `StackFrame` from that PC.
+                let address_size = unit_info.unit.header.address_size() as u64;
 
-        Ok(stack_frames)
-    }
+                // A `DW_AT_entry_pc` outside of the function ranges, e.g. one which points into an empty range,
+                // is not a valid call site. The start of the range containing the address is used instead.
+                let next_function_entry = Some(next_function.entry_pc())
+                    .filter(|entry_pc| next_function.contains_address(*entry_pc))
+                    .unwrap_or(next_function.low_pc);
 
-    /// Find the program counter where a breakpoint should be set,
-    /// given a source file, a line and optionally a column.
-    pub fn get_breakpoint_location(
-        &self,
-        path: &TypedPathBuf,
-        line: u64,
-        column: Option<u64>,
-    ) -> Result<VerifiedBreakpoint, DebugError> {
-        tracing::debug!(
-            "Looking for breakpoint location for {}:{}:{}",
-            path.to_path().display(),
-            line,
-            column
-                .map(|c| c.to_string())
-                .unwrap_or_else(|| "-".to_owned())
-        );
+                if next_function_entry > address_size && next_function_entry < u32::MAX.into() {
+                    // The first instruction of the inlined function is used as the call site.
+                    // This is not necessarily `low_pc`, if the inlined function is split into multiple ranges.
+                    inlined_call_site = Some(RegisterValue::from(next_function_entry));
 
-        let mut unit_iter = self.dwarf.units();
+                    tracing::debug!(
+                        "UNWIND: Callsite for inlined function {:?}",
+                        next_function.function_name()
+                    );
 
-        while let Some(unit_header) = self.get_next_unit_info(&mut unit_iter) {
-            let unit = &unit_header.unit;
+                    inlined_caller_source_location = next_function.inline_call_location();
+                }
 
-            if let Some(ref line_program) = unit.line_program {
-                let header = line_program.header();
+                if let Some(inlined_call_site) = inlined_call_site {
+                    tracing::debug!("UNWIND: Call site: {:?}", inlined_caller_source_location);
 
-                for file_name in header.file_names() {
-                    let combined_path = self.get_path(unit, header, file_name);
-                    if combined_path
-                        .map(|p| canonical_path_eq(path, &p))
-                        .unwrap_or(false)
-                    {
-                        let mut rows = line_program.clone().rows();
+                    // Now that we have the function_name and function_source_location, we can create the appropriate variable caches for this stack frame.
+                    let (static_variables, local_variables) = if populate_variable_caches {
+                        self.create_frame_variable_caches(memory, function_die, &unit_info)
+                    } else {
+                        (None, None)
+                    };
+                    let call_site_arguments = self.frame_call_site_arguments(
+                        function_die,
+                        address,
+                        unwind_registers,
+                        find_call_site_arguments,
+                    );
 
-                        while let Some((header, row)) = rows.next_row()? {
-                            let row_path = row
-                                .file(header)
-                                .and_then(|file_entry| self.get_path(unit, header, file_entry));
+                    frames.push(StackFrame {
+                        // MS DAP Specification requires the id to be unique accross all threads, so using  so using unique `Variable::variable_key` of the `stackframe_root_variable` as the id.
+                        id: get_sequential_key(),
+                        function_name,
+                        source_location: inlined_caller_source_location,
+                        registers: unwind_registers.clone(),
+                        pc: inlined_call_site,
+                        frame_base: function_die.frame_base,
+                        is_inlined: function_die.is_inline(),
+                        is_exception_entry: false,
+                        binary_index: 0,
+                        call_site_arguments,
+                        static_variables,
+                        local_variables,
+                    });
+                } else {
+                    tracing::warn!(
+                        "UNWIND: Unknown call site for inlined function {}.",
+                        function_name
+                    );
+                }
+            }
 
-                            if row_path
-                                .map(|p| !canonical_path_eq(path, &p))
-                                .unwrap_or(true)
-                            {
-                                continue;
-                            }
+            // Handle last function, which contains no further inlined functions
+            //UNWRAP: Checked at beginning of loop, functions must contain at least one value
+            #[allow(clippy::unwrap_used)]
+            let last_function = functions.last().unwrap();
 
-                            if let Some(cur_line) = row.line() {
-                                if cur_line.get() == line {
-                                    // The first match of the file and row will be used to build the SourceStatements, and then:
-                                    // 1. If there is an exact column match, we will use the low_pc of the statement at that column and line.
-                                    // 2. If there is no exact column match, we use the first available statement in the line.
-                                    let source_statements =
-                                        SourceStatements::new(self, &unit_header, row.address())?
-                                            .statements;
-                                    if let Some((halt_address, Some(halt_location))) =
-                                        source_statements
-                                            .iter()
-                                            .find(|statement| {
-                                                statement.line == Some(cur_line)
-                                                    && column
-                                                        .and_then(NonZeroU64::new)
-                                                        .map(ColumnType::Column)
-                                                        .map_or(false, |col| {
-                                                            col == statement.column
-                                                        })
-                                            })
-                                            .map(|source_statement| {
-                                                (
-                                                    source_statement.low_pc(),
-                                                    line_program
-                                                        .header()
-                                                        .file(source_statement.file_index)
-                                                        .and_then(|file_entry| {
-                                                            self.find_file_and_directory(
-                                                                &unit_header.unit,
-                                                                line_program.header(),
-                                                                file_entry,
-                                                            )
-                                                            .map(|(file, directory)| {
-                                                                SourceLocation {
-                                                                    line: source_statement
-                                                                        .line
-                                                                        .map(
-                                                                        std::num::NonZeroU64::get,
-                                                                    ),
-                                                                    column: Some(
-                                                                        source_statement
-                                                                            .column
-                                                                            .into(),
-                                                                    ),
-                                                                    file,
-                                                                    directory,
-                                                                    low_pc: Some(
-                                                                        source_statement.low_pc()
-                                                                            as u32,
-                                                                    ),
-                                                                    high_pc: Some(
-                                                                        source_statement
-                                                                            .instruction_range
-                                                                            .end
-                                                                            as u32,
-                                                                    ),
-                                                                }
-                                                            })
-                                                        }),
-                                                )
-                                            })
-                                    {
-                                        return Ok(VerifiedBreakpoint {
-                                            address: halt_address,
-                                            source_location: halt_location,
-                                        });
-                                    } else if let Some((halt_address, Some(halt_location))) =
-                                        source_statements
-                                            .iter()
-                                            .find(|statement| statement.line == Some(cur_line))
-                                            .map(|source_statement| {
-                                                (
-                                                    source_statement.low_pc(),
-                                                    line_program
-                                                        .header()
-                                                        .file(source_statement.file_index)
-                                                        .and_then(|file_entry| {
-                                                            self.find_file_and_directory(
-                                                                &unit_header.unit,
-                                                                line_program.header(),
-                                                                file_entry,
-                                                            )
-                                                            .map(|(file, directory)| {
-                                                                SourceLocation {
-                                                                    line: source_statement
-                                                                        .line
-                                                                        .map(
-                                                                        std::num::NonZeroU64::get,
-                                                                    ),
-                                                                    column: Some(
-                                                                        source_statement
-                                                                            .column
-                                                                            .into(),
-                                                                    ),
-                                                                    file,
-                                                                    directory,
-                                                                    low_pc: Some(
-                                                                        source_statement.low_pc()
-                                                                            as u32,
-                                                                    ),
-                                                                    high_pc: Some(
-                                                                        source_statement
-                                                                            .instruction_range
-                                                                            .end
-                                                                            as u32,
-                                                                    ),
-                                                                }
-                                                            })
-                                                        }),
-                                                )
-                                            })
-                                    {
-                                        return Ok(VerifiedBreakpoint {
-                                            address: halt_address,
-                                            source_location: halt_location,
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            let function_name = last_function
+                .function_name()
+                .unwrap_or_else(|| unknown_function.clone());
+
+            let function_location = self.get_source_location(address);
+
+            // Now that we have the function_name and function_source_location, we can create the appropriate variable caches for this stack frame.
+            let (static_variables, local_variables) = if populate_variable_caches {
+                self.create_frame_variable_caches(memory, last_function, &unit_info)
+            } else {
+                (None, None)
+            };
+            let call_site_arguments = self.frame_call_site_arguments(
+                last_function,
+                address,
+                unwind_registers,
+                find_call_site_arguments,
+            );
+
+            frames.push(StackFrame {
+                // MS DAP Specification requires the id to be unique accross all threads, so using  so using unique `Variable::variable_key` of the `stackframe_root_variable` as the id.
+                id: get_sequential_key(),
+                function_name,
+                source_location: function_location,
+                registers: unwind_registers.clone(),
+                pc: match unwind_registers.get_address_size_bytes() {
+                    4 => RegisterValue::U32(address as u32),
+                    8 => RegisterValue::U64(address),
+                    _ => RegisterValue::from(address),
+                },
+                frame_base: last_function.frame_base,
+                is_inlined: last_function.is_inline(),
+                is_exception_entry: false,
+                binary_index: 0,
+                call_site_arguments,
+                static_variables,
+                local_variables,
+            });
+
+            break;
         }
+        Ok(frames)
+    }
 
-        let p = path.to_path();
+    /// Performs the logical unwind of the stack and returns a `Vec<StackFrame>`
+    /// - The first 'StackFrame' represents the frame at the current PC (program counter), and ...
+    /// - Each subsequent `StackFrame` represents the **previous or calling** `StackFrame` in the call stack.
+    /// - The majority of the work happens in the `'unwind: while` loop, where each iteration will create a `StackFrame` where possible, and update the `unwind_registers` to prepare for the next iteration.
+    ///
+    /// The unwind loop will continue until we meet one of the following conditions:
+    /// - We can no longer unwind a valid PC value to be used for the next frame.
+    /// - We encounter a LR register value of 0x0 or 0xFFFFFFFF(Arm 'Reset' value for that register).
+    /// - We can not intelligently calculate a valid LR register value from the other registers, or the gimli::RegisterRule result is a value of 0x0. Note: [DWARF](https://dwarfstd.org) 6.4.4 - CIE defines the return register address used in the `gimli::RegisterRule` tables for unwind operations. Theoretically, if we encounter a function that has `Undefined` `gimli::RegisterRule` for the return register address, it means we have reached the bottom of the stack OR the function is a 'no return' type of function. I have found actual examples (e.g. local functions) where we get `Undefined` for register rule when we cannot apply this logic. Example 1: local functions in main.rs will have LR rule as `Undefined`. Example 2: main()-> ! that is called from a trampoline will have a valid LR rule.
+    /// - Similarly, certain error conditions encountered in `StackFrameIterator` will also break out of the unwind loop.
+    /// Note: In addition to populating the `StackFrame`s, this function will also populate the `DebugInfo::VariableCache` with `Variable`s for available Registers as well as static and function variables.
+    /// Use [`DebugInfo::unwind_with_options`] to control the work done for each frame, e.g. if only a symbolic backtrace is required.
+    pub fn unwind(&self, core: &mut Core<'_>) -> Result<Vec<StackFrame>, crate::Error> {
+        Ok(self.unwind_with_options(core, UnwindOptions::new())?.frames)
+    }
 
-        Err(DebugError::Other(anyhow::anyhow!(
-            "No valid breakpoint information found for file: {}, line: {:?}, column: {:?}",
-            p.display(),
-            line,
-            column
-        )))
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], with the given `options`.
+    ///
+    /// Returns the unwound `StackFrame`s, together with the reason why the unwind ended.
+    pub fn unwind_with_options(
+        &self,
+        core: &mut Core<'_>,
+        options: UnwindOptions<'_>,
+    ) -> Result<StackUnwind, crate::Error> {
+        let initial_registers = DebugRegisters::from_core(core);
+        let exception_handler = exception_handler_for_core(core.core_type());
+        let instruction_set = current_instruction_set(core, &initial_registers);
+
+        self.unwind_impl(
+            initial_registers,
+            core,
+            exception_handler,
+            instruction_set,
+            options,
+        )
     }
 
-    /// Get the path for an entry in a line program header, using the compilation unit's directory and file entries.
-    // TODO: Determine if it is necessary to navigate the include directories to find the file absolute path for C files.
-    pub(crate) fn get_path(
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], but passes each `StackFrame`
+    /// to `on_frame` as soon as it has been unwound, see [`UnwindOptions::set_on_frame`].
+    pub fn unwind_streaming(
         &self,
-        unit: &gimli::read::Unit<DwarfReader>,
-        header: &LineProgramHeader<DwarfReader>,
-        file_entry: &FileEntry<DwarfReader>,
-    ) -> Option<TypedPathBuf> {
-        let file_name_attr_string = self.dwarf.attr_string(unit, file_entry.path_name()).ok()?;
-        let name_path = from_utf8(&file_name_attr_string).ok()?;
+        core: &mut Core<'_>,
+        mut on_frame: impl FnMut(&StackFrame) -> ControlFlow<()>,
+    ) -> Result<(), crate::Error> {
+        self.unwind_with_options(core, UnwindOptions::new().set_on_frame(&mut on_frame))?;
 
-        let dir_name_attr_string = file_entry
-            .directory(header)
-            .and_then(|dir| self.dwarf.attr_string(unit, dir).ok());
+        Ok(())
+    }
 
-        let dir_path = dir_name_attr_string.and_then(|dir_name| {
-            from_utf8(&dir_name)
-                .ok()
-                .map(|p| TypedPath::derive(p).to_path_buf())
-        });
+    /// Unwind the stack of each of the given `cores`, returning the backtrace for every core, identified by its [`Core::id`].
+    ///
+    /// The cores are unwound one after the other, sharing this [`DebugInfo`].
+    /// The DWARF sections are read through reference counted ([`Rc`]) buffers, so [`DebugInfo`] is neither
+    /// [`Send`] nor [`Sync`], and cannot be used to unwind the cores from multiple threads.
+    /// All cores should be halted before calling this, to get a coherent view of the system.
+    pub fn unwind_all(
+        &self,
+        cores: &mut [Core<'_>],
+    ) -> Result<Vec<(usize, Vec<StackFrame>)>, crate::Error> {
+        cores
+            .iter_mut()
+            .map(|core| Ok((core.id(), self.unwind(core)?)))
+            .collect()
+    }
 
-        let mut combined_path = match dir_path {
-            Some(dir_path) => dir_path.join(name_path),
-            None => TypedPath::derive(name_path).to_path_buf(),
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], but terminates the unwind
+    /// when the stack unwinds to an address outside of the stack memory of the core, see [`UnwindOptions::set_stack_validity`].
+    ///
+    /// The stack memory is taken from [`Target::stack_region_for_core`](crate::config::Target::stack_region_for_core).
+    /// If the target does not describe any RAM for the core, the CFA is not checked.
+    pub fn unwind_with_stack_bounds(
+        &self,
+        core: &mut Core<'_>,
+        target: &crate::config::Target,
+    ) -> Result<StackUnwind, crate::Error> {
+        let stack_region = target.stack_region_for_core(core.id());
+        let stack_validity = |address: u64| {
+            stack_region
+                .as_ref()
+                .map_or(true, |stack_region| stack_region.contains(&address))
         };
 
-        if combined_path.is_relative() {
-            let comp_dir = unit
-                .comp_dir
-                .as_ref()
-                .map(|dir| from_utf8(dir))
-                .transpose()
-                .ok()?
-                .map(TypedPath::derive);
-            if let Some(comp_dir) = comp_dir {
-                combined_path = comp_dir.join(&combined_path);
-            }
-        }
+        self.unwind_with_options(
+            core,
+            UnwindOptions::new().set_stack_validity(&stack_validity),
+        )
+    }
 
-        Some(combined_path)
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], and additionally recovers the
+    /// [`StackFrame::call_site_arguments`] of the frames of inlined functions, see [`UnwindOptions::set_call_site_arguments`].
+    pub fn unwind_with_call_site_arguments(
+        &self,
+        core: &mut Core<'_>,
+    ) -> Result<Vec<StackFrame>, crate::Error> {
+        Ok(self
+            .unwind_with_options(core, UnwindOptions::new().set_call_site_arguments(true))?
+            .frames)
     }
 
-    pub(crate) fn find_file_and_directory(
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], and additionally returns
+    /// a [`RegisterUnwindStep`] for every register of every frame that was unwound, see [`UnwindOptions::set_trace`].
+    pub fn unwind_with_trace(
         &self,
-        unit: &gimli::read::Unit<DwarfReader>,
-        header: &LineProgramHeader<DwarfReader>,
-        file_entry: &FileEntry<DwarfReader>,
-    ) -> Option<(Option<String>, Option<TypedPathBuf>)> {
-        let combined_path = self.get_path(unit, header, file_entry)?;
+        core: &mut Core<'_>,
+    ) -> Result<(Vec<StackFrame>, Vec<RegisterUnwindStep>), crate::Error> {
+        let mut unwind_trace = Vec::new();
 
-        let file_name = combined_path
-            .file_name()
-            .map(|name| String::from_utf8_lossy(name).into_owned());
+        let unwind =
+            self.unwind_with_options(core, UnwindOptions::new().set_trace(&mut unwind_trace))?;
 
-        let directory = combined_path.parent().map(|p| p.to_path_buf());
+        Ok((unwind.frames, unwind_trace))
+    }
 
-        Some((file_name, directory))
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind`], but skips the creation of the variable caches,
+    /// see [`UnwindOptions::set_variable_caches`]. At most `max_frames` `StackFrame`s will be returned.
+    pub fn unwind_frames_only(
+        &self,
+        core: &mut Core<'_>,
+        max_frames: usize,
+    ) -> Result<Vec<StackFrame>, crate::Error> {
+        Ok(self
+            .unwind_with_options(
+                core,
+                UnwindOptions::new()
+                    .set_variable_caches(false)
+                    .set_max_frames(max_frames),
+            )?
+            .frames)
     }
-}
 
-/// Uses the [std::fs::canonicalize] function to canonicalize both paths before applying the [std::path::PathBuf::eq]
-/// to test if the secondary path is equal or a suffix of the primary path.
-/// If for some reason (e.g., the paths don't exist) the canonicalization fails, the original equality check is used.
-/// We do this to maximize the chances of finding a match where the secondary path can be given as
-/// an absolute, relative, or partial path.
-pub(crate) fn canonical_path_eq(
-    primary_path: &TypedPathBuf,
-    secondary_path: &TypedPathBuf,
-) -> bool {
-    primary_path.normalize() == secondary_path.normalize()
-}
+    /// Performs the same logical unwind of the stack as [`DebugInfo::unwind_with_options`], but from a saved register set
+    /// and memory image instead of a live [`Core`], e.g. to analyze a core dump after a crash.
+    ///
+    /// The `registers` have to include the program counter, stack pointer, frame pointer and return address,
+    /// identified by their [`RegisterRole`]. The exception handler used to unwind through exceptions is selected based on `core_type`.
+    /// If `instruction_set` is `None`, it is determined from the processor status register, if one is included in `registers`.
+    pub fn unwind_from_snapshot(
+        &self,
+        registers: DebugRegisters,
+        memory: &mut dyn MemoryInterface,
+        core_type: CoreType,
+        instruction_set: Option<InstructionSet>,
+        options: UnwindOptions<'_>,
+    ) -> Result<StackUnwind, crate::Error> {
+        let instruction_set = instruction_set.or_else(|| registers.instruction_set(core_type));
+
+        self.unwind_impl(
+            registers,
+            memory,
+            exception_handler_for_core(core_type),
+            instruction_set,
+            options,
+        )
+    }
 
-/// Get a handle to the [`gimli::UnwindTableRow`] for this call frame, so that we can reference it to unwind register values.
-fn get_unwind_info<'a>(
-    unwind_context: &'a mut Box<UnwindContext<DwarfReader>>,
-    frame_section: &'a DebugFrame<DwarfReader>,
-    frame_program_counter: u64,
-) -> Result<&'a gimli::UnwindTableRow<DwarfReader, gimli::StoreOnHeap>, DebugError> {
-    let unwind_bases = BaseAddresses::default();
-    let frame_descriptor_entry = match frame_section.fde_for_address(
-        &unwind_bases,
-        frame_program_counter,
-        gimli::DebugFrame::cie_from_offset,
-    ) {
-        Ok(frame_descriptor_entry) => frame_descriptor_entry,
-        Err(error) => {
-            return Err(DebugError::Other(anyhow::anyhow!(
-                "UNWIND: Error reading FrameDescriptorEntry at PC={} : {}",
-                frame_program_counter,
-                error
-            )));
-        }
-    };
+    /// The implementation of the stack unwind, shared by all the public unwind functions.
+    /// The [`UnwindOptions`] control how much work is done for each `StackFrame`.
+    pub(crate) fn unwind_impl(
+        &self,
+        initial_registers: registers::DebugRegisters,
+        memory: &mut dyn MemoryInterface,
+        exception_handler: Box<dyn ExceptionInterface>,
+        instruction_set: Option<InstructionSet>,
+        options: UnwindOptions<'_>,
+    ) -> Result<StackUnwind, crate::Error> {
+        let UnwindOptions {
+            populate_variable_caches,
+            max_frames,
+            find_inlined,
+            find_call_site_arguments,
+            mut unwind_trace,
+            stack_validity,
+            mut on_frame,
+            virtual_address_bits,
+        } = options;
 
-    frame_descriptor_entry
-        .unwind_info_for_address(
-            frame_section,
-            &unwind_bases,
-            unwind_context,
-            frame_program_counter,
-        )
-        .map_err(|error| {
-            DebugError::Other(anyhow::anyhow!(
-                "UNWIND: Error reading FrameDescriptorEntry at PC={} : {}",
-                frame_program_counter,
-                error
-            ))
-        })
-}
+        let mut stack_frames = Vec::<StackFrame>::new();
+        let mut outcome = UnwindOutcome::Complete;
+        let mut pointer_authentication =
+            self.pointer_authentication(instruction_set, virtual_address_bits);
 
-/// A per_register unwind, applying register rules and updating the [`registers::DebugRegister`] value as appropriate, before returning control to the calling function.
-fn unwind_register(
-    debug_register: &mut super::DebugRegister,
-    // The callee_frame_registers are used to lookup values and never updated.
-    callee_frame_registers: &DebugRegisters,
-    unwind_info: Option<&gimli::UnwindTableRow<DwarfReader, gimli::StoreOnHeap>>,
-    unwind_cfa: Option<u64>,
-    unwound_return_address: &mut Option<RegisterValue>,
-    memory: &mut dyn MemoryInterface,
-    instruction_set: Option<InstructionSet>,
-) -> ControlFlow<(), ()> {
-    use gimli::read::RegisterRule::*;
-    // If we do not have unwind info, or there is no register rule, then use UnwindRule::Undefined.
-    let register_rule = debug_register
-        .dwarf_id
-        .and_then(|register_position| {
-            unwind_info.map(|unwind_info| unwind_info.register(gimli::Register(register_position)))
-        })
-        .unwrap_or(gimli::RegisterRule::Undefined);
-    let mut register_rule_string = format!("{register_rule:?}");
-    let new_value = match register_rule {
-        Undefined => {
-            // In many cases, the DWARF has `Undefined` rules for variables like frame pointer, program counter, etc., so we hard-code some rules here to make sure unwinding can continue. If there is a valid rule, it will bypass these hardcoded ones.
-            match &debug_register {
-                fp if fp
-                    .core_register
-                    .register_has_role(RegisterRole::FramePointer) =>
-                {
-                    register_rule_string = "FP=CFA (dwarf Undefined)".to_string();
-                    callee_frame_registers
-                        .get_frame_pointer()
-                        .and_then(|fp| fp.value)
+        let mut unwind_context: Box<UnwindContext<DwarfReader>> =
+            Box::new(gimli::UnwindContext::new());
+
+        let mut unwind_registers = initial_registers;
+        let mut reported_frames = 0;
+
+        // Unwind [StackFrame]'s for as long as we can unwind a valid PC value.
+        'unwind: while let Some(frame_pc_register_value) = unwind_registers
+            .get_program_counter()
+            .and_then(|pc| pc.value)
+        {
+            if max_frames.map_or(false, |max_frames| stack_frames.len() >= max_frames) {
+                tracing::trace!(
+                    "UNWIND: Stack unwind complete - Reached the maximum number of frames."
+                );
+                break;
+            }
+
+            // Report the frames of the previous iteration, before doing any work for the next one.
+            if report_frames(&mut stack_frames, &mut reported_frames, &mut on_frame).is_break() {
+                tracing::trace!("UNWIND: Stack unwind complete - Stopped by the caller.");
+                break;
+            }
+
+            // PART 0: The first step is to determine the exception context for the current PC.
+            // - If we are at an exception hanlder frame, we need to overwrite the unwind registers with the exception context.
+            // - If for some reason we cannot determine the exception context, we silently continue with the rest of the unwind.
+            // At worst, the unwind will be able to unwind the stack to the frame of the most recent exception handler.
+            let exception_info = match exception_handler
+                .exception_details(memory, &unwind_registers)
+            {
+                Ok(Some(exception_info)) => {
+                    tracing::trace!(
+                        "UNWIND: Found exception context: {}",
+                        exception_info.description
+                    );
+                    Some(exception_info)
                 }
-                sp if sp
-                    .core_register
-                    .register_has_role(RegisterRole::StackPointer) =>
-                {
-                    // NOTE: [ARMv7-M Architecture Reference Manual](https://developer.arm.com/documentation/ddi0403/ee), Section B.1.4.1: Treat bits [1:0] as `Should be Zero or Preserved`
-                    // - Applying this logic to RISCV has no adverse effects, since all incoming addresses are already 32-bit aligned.
-                    register_rule_string = "SP=CFA (dwarf Undefined)".to_string();
-                    unwind_cfa.map(|unwind_cfa| {
-                        if sp.is_u32() {
-                            RegisterValue::U32(unwind_cfa as u32 & !0b11)
-                        } else {
-                            RegisterValue::U64(unwind_cfa & !0b11)
-                        }
-                    })
+                Ok(None) => {
+                    tracing::trace!(
+                        "UNWIND: No exception context found. Stack unwind will continue."
+                    );
+                    None
                 }
-                lr if lr
-                    .core_register
-                    .register_has_role(RegisterRole::ReturnAddress) =>
-                {
-                    // This value is can only be used to determine the Undefined PC value. We have no way of inferring the previous frames LR until we have the PC.
-                    register_rule_string = "LR=Unknown (dwarf Undefined)".to_string();
-                    *unwound_return_address = lr.value;
+                Err(e) => {
+                    tracing::warn!("UNWIND: Error while checking for exception context. The stack trace will not include the calling frames. : {}", e);
                     None
                 }
-                pc if pc
-                    .core_register
-                    .register_has_role(RegisterRole::ProgramCounter) =>
-                {
-                    // NOTE: PC = Value of the unwound LR, i.e. the first instruction after the one that called this function.
-                    register_rule_string = "PC=(unwound LR) (dwarf Undefined)".to_string();
-                    unwound_return_address.and_then(|return_address| {
-                        unwind_program_counter_register(
-                            return_address,
-                            instruction_set,
-                            &mut register_rule_string,
-                        )
-                    })
+            };
+
+            // PART 1: Construct the `StackFrame` for the current pc.
+            let frame_pc: u64 = frame_pc_register_value
+                .try_into()
+                .map_err(|error| crate::Error::Register(format!("Cannot convert register value for program counter to a 64-bit integer value: {:?}", error)))?;
+            let frame_pc = self.lookup_address(frame_pc);
+            tracing::trace!(
+                "UNWIND: Will generate `StackFrame` for function at address (PC) {:#}",
+                frame_pc_register_value
+            );
+
+            // PART 1-a: Prepare the `StackFrame` that holds the current frame information.
+
+            let mut cached_stack_frames = match self.get_stackframe_info(
+                memory,
+                frame_pc,
+                &unwind_registers,
+                populate_variable_caches,
+                find_inlined,
+                find_call_site_arguments,
+            ) {
+                Ok(cached_stack_frames) => cached_stack_frames,
+                Err(e) => {
+                    tracing::error!("UNWIND: Unable to complete `StackFrame` information: {}", e);
+                    // There is no point in continuing with the unwind, so let's get out of here.
+                    break;
                 }
-                other_register => {
-                    // If the the register rule was not specified, then we either carry the previous value forward,
-                    // or we clear the register value, depending on the architecture and register type.
-                    match other_register.core_register.unwind_rule {
-                        UnwindRule::Preserve => {
-                            register_rule_string = "Preserve".to_string();
-                            callee_frame_registers
-                                .get_register(other_register.core_register.id)
-                                .and_then(|reg| reg.value)
-                        }
-                        UnwindRule::Clear => {
-                            register_rule_string = "Clear".to_string();
-                            None
-                        }
-                        UnwindRule::SpecialRule => {
-                            // When no DWARF rules are available, and it is not a special register like PC, SP, FP, etc.,
-                            // we will preserve the value. It is possible it might have its value set later if
-                            // exception frame information is available.
-                            register_rule_string = "Clear (no unwind rules specified)".to_string();
-                            None
-                        }
+            };
+
+            // The PC of the topmost frame is the instruction which will be executed next, so the statement
+            // containing it is used as its location. For all other frames, the PC is a return address, so the
+            // row before it gives the location of the call.
+            if stack_frames.is_empty() {
+                if let Some(location) = self.statement_location(frame_pc) {
+                    if let Some(innermost_frame) = cached_stack_frames.last_mut() {
+                        innermost_frame.source_location = Some(location);
                     }
                 }
             }
-        }
-        SameValue => callee_frame_registers
-            .get_register(debug_register.core_register.id)
-            .and_then(|reg| reg.value),
-        Offset(address_offset) => {
-            // "The previous value of this register is saved at the address CFA+N where CFA is the current CFA value and N is a signed offset"
-            if let Some(unwind_cfa) = unwind_cfa {
-                let address_size = callee_frame_registers.get_address_size_bytes();
-                let previous_frame_register_address =
-                    add_to_address(unwind_cfa, address_offset, address_size);
 
-                register_rule_string = format!("CFA {register_rule:?}");
-                let result = match address_size {
-                    4 => {
-                        let mut buff = [0u8; 4];
-                        memory
-                            .read(previous_frame_register_address, &mut buff)
-                            .map(|_| RegisterValue::U32(u32::from_le_bytes(buff)))
-                    }
-                    8 => {
-                        let mut buff = [0u8; 8];
-                        memory
-                            .read(previous_frame_register_address, &mut buff)
-                            .map(|_| RegisterValue::U64(u64::from_le_bytes(buff)))
-                    }
-                    _ => {
-                        tracing::error!(
-                            "UNWIND: Address size {} not supported.  Please report this as a bug.",
-                            address_size
-                        );
-                        return ControlFlow::Break(());
-                    }
-                };
+            while cached_stack_frames.len() > 1 {
+                // If we encountered INLINED functions (all `StackFrames`s in this Vec, except for the last one, which is the containing NON-INLINED function), these are simply added to the list of stack_frames we return.
+                #[allow(clippy::unwrap_used)]
+                let inlined_frame = cached_stack_frames.pop().unwrap(); // unwrap is safe while .len() > 1
+                tracing::trace!(
+                    "UNWIND: Found inlined function - name={}, pc={}",
+                    inlined_frame.function_name,
+                    inlined_frame.pc
+                );
+                stack_frames.push(inlined_frame);
+            }
 
-                match result {
-                    Ok(register_value) => {
-                        if debug_register
-                            .core_register
-                            .register_has_role(RegisterRole::ReturnAddress)
-                        {
-                            // We need to store this value to be used by the calculation of the PC.
-                            *unwound_return_address = Some(register_value);
+            let mut only_exception = false;
+
+            let return_frame = match cached_stack_frames.pop() {
+                Some(frame) => frame,
+                None => {
+                    if let Some(exception_info) = &exception_info {
+                        only_exception = true;
+                        let address = frame_pc;
+
+                        let previous_regs = unwind_registers.clone();
+
+                        StackFrame {
+                            id: get_sequential_key(),
+                            function_name: exception_info.description.clone(),
+                            source_location: None,
+                            registers: previous_regs,
+                            pc: match unwind_registers.get_address_size_bytes() {
+                                4 => RegisterValue::U32(address as u32),
+                                8 => RegisterValue::U64(address),
+                                _ => RegisterValue::from(address),
+                            },
+                            frame_base: None,
+                            is_inlined: false,
+                            is_exception_entry: true,
+                            binary_index: 0,
+                            call_site_arguments: None,
+                            static_variables: None,
+                            local_variables: None,
+                        }
+                    } else {
+                        let address = frame_pc;
+
+                        StackFrame {
+                            id: get_sequential_key(),
+                            function_name: self.unknown_function_name(
+                                address,
+                                unwind_registers.get_address_size_bytes(),
+                            ),
+                            source_location: self.get_source_location(address),
+                            registers: unwind_registers.clone(),
+                            pc: match unwind_registers.get_address_size_bytes() {
+                                4 => RegisterValue::U32(address as u32),
+                                8 => RegisterValue::U64(address),
+                                _ => RegisterValue::from(address),
+                            },
+                            frame_base: None,
+                            is_inlined: false,
+                            is_exception_entry: false,
+                            binary_index: 0,
+                            call_site_arguments: None,
+                            static_variables: None,
+                            local_variables: None,
                         }
-                        Some(register_value)
-                    }
-                    Err(error) => {
-                        tracing::error!(
-                            "UNWIND: Failed to read value for register {} from address {} ({} bytes): {}",
-                            debug_register.get_register_name(),
-                            RegisterValue::from(previous_frame_register_address),
-                            4,
-                            error
-                        );
-                        tracing::error!(
-                            "UNWIND: Rule: Offset {} from address {:#010x}",
-                            address_offset,
-                            unwind_cfa
-                        );
-                        return ControlFlow::Break(());
                     }
                 }
-            } else {
-                tracing::error!("UNWIND: Tried to unwind `RegisterRule` at CFA = None. Please report this as a bug.");
-                return ControlFlow::Break(());
-            }
-        }
-        //TODO: Implement the remainder of these `RegisterRule`s
-        _ => unimplemented!(),
-    };
-    debug_register.value = new_value;
+            };
 
-    tracing::trace!(
-        "UNWIND - {:>10}: Caller: {}\tCallee: {}\tRule: {}",
-        debug_register.get_register_name(),
-        debug_register.value.unwrap_or_default(),
-        callee_frame_registers
-            .get_register(debug_register.core_register.id)
-            .and_then(|reg| reg.value)
-            .unwrap_or_default(),
-        register_rule_string,
-    );
-    ControlFlow::Continue(())
-}
+            // Part 1-b: Check LR values to determine if we can continue unwinding.
+            let Some(check_return_address) = unwind_registers.get_return_address() else {
+                // If the debug info rules result in a None return address, we cannot continue unwinding.
+                stack_frames.push(return_frame);
+                tracing::trace!("UNWIND: Stack unwind complete - LR register value is 'None.");
+                break;
+            };
 
-/// Helper function to determine the program counter value for the previous frame.
-fn unwind_program_counter_register(
-    return_address: RegisterValue,
-    instruction_set: Option<InstructionSet>,
-    register_rule_string: &mut String,
-) -> Option<RegisterValue> {
-    if return_address.is_max_value() || return_address.is_zero() {
-        tracing::warn!("No reliable return address is available, so we cannot determine the program counter to unwind the previous frame.");
-        None
-    } else {
-        match return_address {
-            RegisterValue::U32(return_address) => {
-                if instruction_set == Some(InstructionSet::Thumb2) {
-                    // NOTE: [ARMv7-M Architecture Reference Manual](https://developer.arm.com/documentation/ddi0403/ee), Section A5.1.2: We have to clear the last bit to ensure the PC is half-word aligned. (on ARM architecture, when in Thumb state for certain instruction types will set the LSB to 1)
-                    *register_rule_string = "PC=(unwound LR & !0b1) (dwarf Undefined)".to_string();
-                    Some(RegisterValue::U32(return_address & !0b1))
-                } else {
-                    Some(RegisterValue::U32(return_address))
-                }
+            if check_return_address.is_max_value() || check_return_address.is_zero() {
+                // When we encounter the starting (after reset) return address, we've reached the bottom of the stack, so no more unwinding after this.
+                stack_frames.push(return_frame);
+                tracing::trace!(
+                    "UNWIND: Stack unwind complete - Reached the 'Reset' value of the LR register."
+                );
+                break;
             }
-            RegisterValue::U64(return_address) => Some(RegisterValue::U64(return_address)),
-            RegisterValue::U128(_) => {
-                tracing::warn!("128 bit address space not supported");
-                None
+
+            // Part 1-c: If the target current frame is an exception handler, we need to update the `unwind_registers` to match the frame that invoked the exception handler.
+            if let Some(exception_info) = exception_info {
+                tracing::trace!(
+                    "UNWIND: Stack unwind reached an exception handler {}",
+                    exception_info.description
+                );
+
+                tracing::trace!(
+                    "UNWIND: Stack unwind will attempt to unwind the frame that invoked {}.",
+                    exception_info.description
+                );
+
+                // Now that we've optionally updated the `unwind_registers` to match the exception handler, we can continue.
+                if only_exception {
+                    // If we are at an exception handler frame, we need to overwrite the unwind registers.
+                    // This will allow us to continue unwinding from the exception handler frame.
+                    // If the exception interrupted another exception handler (nested exceptions), the next
+                    // iterations will unwind that handler, and find its exception frame in the same way.
+                    unwind_registers = exception_info.calling_frame_registers;
+
+                    stack_frames.push(return_frame);
+                    continue;
+                }
             }
-        }
-    }
-}
 
-/// Helper function to handle adding a signed offset to a [`RegisterValue`] address.
-/// The numerical overflow is handled based on the byte size (`address_size_in_bytes` parameter  )
-/// of the [`RegisterValue`], as opposed to just the datatype of the `address` parameter.
-/// In the case of unwinding stack frame register values, it makes no sense to wrap,
-/// because it will result in invalid register address reads.
-/// Instead, when we detect over/underflow, we return an address value of 0x0,
-/// which will trigger a graceful (and logged) end of a stack unwind.
-fn add_to_address(address: u64, offset: i64, address_size_in_bytes: usize) -> u64 {
-    match address_size_in_bytes {
-        4 => {
-            if offset >= 0 {
-                (address as u32)
-                    .checked_add(offset as u32)
-                    .map(u64::from)
-                    .unwrap_or(0x0)
-            } else {
-                (address as u32).saturating_sub(offset.unsigned_abs() as u32) as u64
+            // PART 2: Setup the registers for the next iteration (a.k.a. unwind previous frame, a.k.a. "callee", in the call stack).
+            tracing::trace!(
+                "UNWIND - Preparing `StackFrameIterator` to unwind NON-INLINED function {:?} at {:?}",
+                return_frame.function_name,
+                return_frame.source_location
+            );
+            // PART 2-a: get the `gimli::FrameDescriptorEntry` for this address and then the unwind info associated with this row.
+            let (unwind_debug_info, unwind_offset) = self.debug_info_for_address(frame_pc);
+            let unwind_info = match get_unwind_info(
+                &mut unwind_context,
+                &unwind_debug_info.frame_section,
+                frame_pc - unwind_offset,
+            ) {
+                Ok(unwind_info) => unwind_info,
+                Err(error) => {
+                    // We cannot do stack unwinding if we do not have debug info. However, there is one case where we can continue. When the following conditions are met:
+                    // 1. The current frame is the first frame in the stack, AND ...
+                    // 2. The frame registers have a valid return address/LR value.
+                    // If both these conditions are met, we can push the 'unknown function' to the list of stack frames, and use the LR value to calculate the PC for the calling frame.
+                    // The current logic will then use that PC to get the next frame's unwind info, and if that exists, will be able to continue unwinding.
+                    // If the calling frame has no debug info, then the unwindindg will end with that frame.
+                    if stack_frames.is_empty() {
+                        let callee_frame_registers = unwind_registers.clone();
+                        let mut unwound_return_address: Option<RegisterValue> =
+                            callee_frame_registers
+                                .get_return_address()
+                                .and_then(|lr| lr.value);
+
+                        if let Some(calling_pc) = unwind_registers.get_program_counter_mut() {
+                            if unwind_register(
+                                calling_pc,
+                                &callee_frame_registers,
+                                None,
+                                None,
+                                &mut unwound_return_address,
+                                memory,
+                                instruction_set,
+                                &mut pointer_authentication,
+                                stack_frames.len(),
+                                unwind_trace.as_deref_mut(),
+                            )
+                            .is_break()
+                            {
+                                // We were not able to get a PC for the calling frame, so we cannot continue unwinding.
+                                stack_frames.push(return_frame);
+                                break 'unwind;
+                            } else {
+                                // The unwind registers were updated with the calling frame's PC, so we can continue unwinding.
+                                stack_frames.push(return_frame);
+                                continue 'unwind;
+                            };
+                        } else {
+                            stack_frames.push(return_frame);
+                            continue 'unwind;
+                        }
+                    } else {
+                        stack_frames.push(return_frame);
+                        tracing::trace!("UNWIND: Stack unwind complete. No available debug info for program counter {}: {}", frame_pc, error);
+                        break;
+                    }
+                }
+            };
+
+            // Because we will be updating the `unwind_registers` with previous frame unwind info, we need to keep a copy of the current frame's registers that can be used to resolve [DWARF](https://dwarfstd.org) expressions.
+            let callee_frame_registers = unwind_registers.clone();
+            // PART 2-b: Determine the CFA (canonical frame address) to use for this unwind row.
+            let unwind_cfa = match unwind_info.cfa() {
+                gimli::CfaRule::RegisterAndOffset { register, offset } => {
+                    let reg_val = unwind_registers
+                        .get_register_by_dwarf_id(register.0)
+                        .and_then(|register| register.value);
+                    let unwind_cfa = match reg_val {
+                        Some(reg_val) => {
+                            if reg_val.is_zero() {
+                                // If we encounter this rule for CFA, it implies the scenario depends on a FP/frame pointer to continue successfully.
+                                // Therefore, if reg_val is zero (i.e. FP is zero), then we do not have enough information to determine the CFA by rule.
+                                stack_frames.push(return_frame);
+                                tracing::trace!("UNWIND: Stack unwind complete - The FP register value unwound to a value of zero.");
+                                break;
+                            }
+                            add_to_address(
+                                reg_val.try_into()?,
+                                *offset,
+                                unwind_registers.get_address_size_bytes(),
+                            )
+                        }
+                        None => {
+                            // The register could have been dropped from the available registers, but if the frame pointer is still known,
+                            // the CFA of a function which follows the frame pointer convention can be reconstructed from it.
+                            match frame_pointer_cfa(&unwind_registers, instruction_set) {
+                                Some(unwind_cfa) => {
+                                    tracing::warn!("UNWIND: Missing value of register {} for the unwind CFA, using the frame pointer convention instead.", register.0);
+                                    unwind_cfa
+                                }
+                                None => {
+                                    tracing::error!("UNWIND: `StackFrameIterator` unable to determine the unwind CFA: Missing value of register {}",register.0);
+                                    stack_frames.push(return_frame);
+                                    break;
+                                }
+                            }
+                        }
+                    };
+                    tracing::trace!(
+                        "UNWIND - CFA : {:#010x}\tRule: {:?}",
+                        unwind_cfa,
+                        unwind_info.cfa()
+                    );
+                    if stack_validity.map_or(false, |is_valid| !is_valid(unwind_cfa)) {
+                        // Reading the stacked registers from an invalid address would only produce garbage values.
+                        stack_frames.push(return_frame);
+                        tracing::warn!("UNWIND: Stack unwind complete - The CFA {:#010x} is outside of the valid stack memory. The stack is likely corrupt.", unwind_cfa);
+                        outcome = UnwindOutcome::StackCorrupt { cfa: unwind_cfa };
+                        break;
+                    }
+                    Some(unwind_cfa)
+                }
+                gimli::CfaRule::Expression(_) => unimplemented!(),
+            };
+
+            // PART 2-c: Unwind registers for the "previous/calling" frame.
+            // We sometimes need to keep a copy of the LR value to calculate the PC. For both ARM, and RISCV, The LR will be unwound before the PC, so we can reference it safely.
+            let mut unwound_return_address: Option<RegisterValue> = None;
+            for debug_register in unwind_registers.0.iter_mut() {
+                if unwind_register(
+                    debug_register,
+                    &callee_frame_registers,
+                    Some(unwind_info),
+                    unwind_cfa,
+                    &mut unwound_return_address,
+                    memory,
+                    instruction_set,
+                    &mut pointer_authentication,
+                    stack_frames.len(),
+                    unwind_trace.as_deref_mut(),
+                )
+                .is_break()
+                {
+                    stack_frames.push(return_frame);
+                    break 'unwind;
+                };
             }
-        }
-        8 => {
-            if offset >= 0 {
-                address.checked_add(offset as u64).unwrap_or(0x0)
-            } else {
-                address.saturating_sub(offset.unsigned_abs())
+
+            stack_frames.push(return_frame);
+
+            // Check if we unwound over an exception handler
+            if let Some(value) = unwind_registers.get_program_counter().and_then(|s| s.value) {
+                let value: u32 = value.try_into().unwrap();
+
+                if (value >> 28) & 0xf == 0xf {
+                    let ra = unwind_registers
+                        .get_register_mut_by_role(&RegisterRole::ReturnAddress)
+                        .unwrap();
+                    ra.value = Some(RegisterValue::U32(value));
+
+                    // Now, how do we handle this.
+                    let exception_details = match exception_handler
+                        .exception_details(memory, &unwind_registers)
+                    {
+                        Ok(exception_details) => exception_details,
+                        Err(error) => {
+                            // This happens if the registers needed to decode the exception frame are unknown,
+                            // e.g. when unwinding from a partial set of registers.
+                            tracing::warn!("UNWIND: Stack unwind complete - Unable to unwind the exception frame: {}", error);
+                            break;
+                        }
+                    };
+                    if let Some(details) = exception_details {
+                        unwind_registers = details.calling_frame_registers;
+                        let address = frame_pc;
+
+                        let exception_frame = StackFrame {
+                            id: get_sequential_key(),
+                            function_name: details.description.clone(),
+                            source_location: None,
+                            registers: unwind_registers.clone(),
+                            pc: match unwind_registers.get_address_size_bytes() {
+                                4 => RegisterValue::U32(address as u32),
+                                8 => RegisterValue::U64(address),
+                                _ => RegisterValue::from(address),
+                            },
+                            frame_base: None,
+                            is_inlined: false,
+                            is_exception_entry: true,
+                            binary_index: 0,
+                            call_site_arguments: None,
+                            static_variables: None,
+                            local_variables: None,
+                        };
+
+                        stack_frames.push(exception_frame);
+                    }
+                }
             }
         }
-        _ => {
-            panic!(
-                "UNWIND: Address size {} not supported.  Please report this as a bug.",
-                address_size_in_bytes
-            );
+
+        if let Some(max_frames) = max_frames {
+            // A single iteration can add more than one frame (inlined functions and exception handlers).
+            stack_frames.truncate(max_frames);
         }
+        // The unwind is complete at this point, so it makes no difference if the caller wants to stop.
+        let _ = report_frames(&mut stack_frames, &mut reported_frames, &mut on_frame);
+
+        Ok(StackUnwind {
+            frames: stack_frames,
+            outcome,
+            pointer_authentication_stripped: pointer_authentication.stripped,
+        })
     }
-}
 
-#[cfg(test)]
-mod test {
-    use std::path::{Path, PathBuf};
+    /// Compute the program counter of the calling frame, by applying a single unwind step to `registers`.
+    ///
+    /// This is a much cheaper alternative to [`DebugInfo::unwind`] when only the caller of the current frame is of interest.
+    /// The CFA (canonical frame address) and the return address are determined from the unwind information for the
+    /// current program counter, and the Thumb bit is cleared from the return address for [`InstructionSet::Thumb2`].
+    ///
+    /// Returns `None` at the bottom of the stack, i.e. if the return address has its reset value, or if the calling frame
+    /// can not be unwound. Frames entered by an exception are not handled, in which case the `EXC_RETURN` value is returned.
+    ///
+    /// If [load address translation](DebugInfo::set_translate_load_addresses) is enabled, both the program counter
+    /// and the returned address are translated, in the same way as the `pc` of the `StackFrame`s of an unwind.
+    pub fn caller_pc(
+        &self,
+        registers: &DebugRegisters,
+        memory: &mut dyn MemoryInterface,
+        instruction_set: Option<InstructionSet>,
+    ) -> Result<Option<u64>, DebugError> {
+        let Some(pc) = registers.get_program_counter().and_then(|pc| pc.value) else {
+            return Ok(None);
+        };
+        let pc = self.lookup_address(pc.try_into()?);
+
+        if registers
+            .get_return_address()
+            .and_then(|lr| lr.value)
+            .map_or(true, |lr| lr.is_max_value() || lr.is_zero())
+        {
+            return Ok(None);
+        }
+
+        let mut unwind_context: Box<UnwindContext<DwarfReader>> =
+            Box::new(gimli::UnwindContext::new());
+        let (unwind_debug_info, unwind_offset) = self.debug_info_for_address(pc);
+        let unwind_info = get_unwind_info(
+            &mut unwind_context,
+            &unwind_debug_info.frame_section,
+            pc - unwind_offset,
+        )?;
+
+        let unwind_cfa = match unwind_info.cfa() {
+            gimli::CfaRule::RegisterAndOffset { register, offset } => {
+                match registers
+                    .get_register_by_dwarf_id(register.0)
+                    .and_then(|register| register.value)
+                {
+                    Some(value) if value.is_zero() => return Ok(None),
+                    Some(value) => add_to_address(
+                        value.try_into()?,
+                        *offset,
+                        registers.get_address_size_bytes(),
+                    ),
+                    None => match frame_pointer_cfa(registers, instruction_set) {
+                        Some(unwind_cfa) => unwind_cfa,
+                        None => return Ok(None),
+                    },
+                }
+            }
+            gimli::CfaRule::Expression(_) => {
+                return Err(DebugError::Other(anyhow::anyhow!(
+                    "UNWIND: CFA expressions are not supported, at PC={:#010x}",
+                    pc
+                )))
+            }
+        };
+
+        // The return address is unwound before the program counter, which is derived from it.
+        let mut caller_registers = registers.clone();
+        let mut unwound_return_address: Option<RegisterValue> = None;
+        let mut pointer_authentication = self.pointer_authentication(instruction_set, None);
+        for debug_register in caller_registers.0.iter_mut() {
+            if unwind_register(
+                debug_register,
+                registers,
+                Some(unwind_info),
+                Some(unwind_cfa),
+                &mut unwound_return_address,
+                memory,
+                instruction_set,
+                &mut pointer_authentication,
+                0,
+                None,
+            )
+            .is_break()
+            {
+                return Ok(None);
+            }
+        }
+
+        match caller_registers
+            .get_program_counter()
+            .and_then(|pc| pc.value)
+        {
+            Some(caller_pc) => Ok(Some(self.lookup_address(caller_pc.try_into()?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Determine how pointer authentication codes are removed from the return addresses of an unwind.
+    ///
+    /// Only A64 return addresses can be signed. Without a `virtual_address_bits` from the caller, the lowest plausible
+    /// size is derived from the highest address of the functions and segments of this binary and the merged ones.
+    fn pointer_authentication(
+        &self,
+        instruction_set: Option<InstructionSet>,
+        virtual_address_bits: Option<u8>,
+    ) -> PointerAuthentication {
+        if instruction_set != Some(InstructionSet::A64) {
+            return PointerAuthentication::default();
+        }
+
+        let virtual_address_bits = virtual_address_bits.unwrap_or_else(|| {
+            self.binaries()
+                .flat_map(|(debug_info, offset)| {
+                    let functions = debug_info.function_symbols.iter().map(move |function| {
+                        offset.wrapping_add(
+                            function
+                                .address
+                                .saturating_add(function.size.saturating_sub(1)),
+                        )
+                    });
+                    let segments = debug_info.load_segments.iter().flat_map(move |segment| {
+                        let last_byte = segment.size.saturating_sub(1);
+                        [
+                            offset.wrapping_add(segment.virtual_address.saturating_add(last_byte)),
+                            offset.wrapping_add(segment.physical_address.saturating_add(last_byte)),
+                        ]
+                    });
+                    functions.chain(segments)
+                })
+                .map(significant_address_bits)
+                .max()
+                // Without any addresses, only the bits which are never part of an address are stripped.
+                .unwrap_or(MAX_VIRTUAL_ADDRESS_BITS)
+        });
+
+        PointerAuthentication {
+            virtual_address_bits: Some(
+                virtual_address_bits.clamp(MIN_VIRTUAL_ADDRESS_BITS, MAX_VIRTUAL_ADDRESS_BITS),
+            ),
+            stripped: false,
+        }
+    }
+
+    /// Find the address of the first instruction of the next source line after `current_pc`, within the same function.
+    /// This can be used to implement a source level "step over".
+    ///
+    /// - Only rows from the same source file as the current row are considered, so that the lines of inlined functions are skipped.
+    /// - Returns `None` if `current_pc` is on the last line of the function, in which case the caller should fall back to "step out".
+    pub fn next_line_address(&self, current_pc: u64) -> Option<u64> {
+        let (debug_info, offset) = self.debug_info_for_address(current_pc);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info
+                .next_line_address(current_pc - offset)
+                .map(|address| address + offset);
+        }
+
+        let mut units = self.get_units();
+
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let Ok(functions) = unit_info.get_function_dies(current_pc, None, false) else {
+                continue;
+            };
+            let Some(function) = functions.first() else {
+                continue;
+            };
+            let line_program = unit_info.unit.line_program.as_ref()?;
+
+            // Collect the statement rows of the function, ordered by address.
+            // The function can be split into multiple ranges, so all of them have to be considered.
+            let function_ranges = function.ranges();
+            let mut function_rows = Vec::new();
+            let mut rows = line_program.clone().rows();
+            while let Ok(Some((_, row))) = rows.next_row() {
+                if !row.end_sequence()
+                    && row.is_stmt()
+                    && function_ranges
+                        .iter()
+                        .any(|range| range.contains(&row.address()))
+                {
+                    function_rows.push((row.address(), row.file_index(), row.line()));
+                }
+            }
+            function_rows.sort_by_key(|(address, _, _)| *address);
+
+            let (_, current_file, current_line) = function_rows
+                .iter()
+                .rev()
+                .find(|(address, _, _)| *address <= current_pc)?;
+
+            return function_rows
+                .iter()
+                .find(|(address, file, line)| {
+                    *address > current_pc
+                        && file == current_file
+                        && line.is_some()
+                        && line != current_line
+                })
+                .map(|(address, _, _)| *address);
+        }
+
+        None
+    }
+
+    /// Returns the addresses of the return instructions of the (non-inlined) function which contains `address`,
+    /// e.g. to run to the end of the function.
+    ///
+    /// The addresses are taken from the rows of the line program which are marked with `epilogue_begin`.
+    /// Not all compilers emit this marker, in which case the address of the last row of the function is returned,
+    /// as a best effort. The variant of [`ReturnAddresses`] tells which of the two was used.
+    /// Returns `None` if there is no function with line information at `address`.
+    pub fn function_return_addresses(&self, address: u64) -> Option<ReturnAddresses> {
+        let (debug_info, offset) = self.debug_info_for_address(address);
+        if !std::ptr::eq(debug_info, self) {
+            return debug_info.function_return_addresses(address - offset).map(
+                |return_addresses| match return_addresses {
+                    ReturnAddresses::Epilogue(addresses) => ReturnAddresses::Epilogue(
+                        addresses
+                            .into_iter()
+                            .map(|address| address + offset)
+                            .collect(),
+                    ),
+                    ReturnAddresses::LastRow(address) => ReturnAddresses::LastRow(address + offset),
+                },
+            );
+        }
+
+        let mut units = self.get_units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut units) {
+            let Ok(functions) = unit_info.get_function_dies(address, None, false) else {
+                continue;
+            };
+            if let Some(function) = functions.first() {
+                return self.return_addresses(function);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the addresses of the return instructions of `function_die`, see [`DebugInfo::function_return_addresses`].
+    pub(crate) fn return_addresses(&self, function_die: &FunctionDie) -> Option<ReturnAddresses> {
+        let line_program = function_die.unit_info.unit.line_program.as_ref()?;
+
+        let function_ranges = function_die.ranges();
+        let mut epilogue_addresses = Vec::new();
+        let mut last_address = None;
+
+        let mut rows = line_program.clone().rows();
+        while let Ok(Some((_, row))) = rows.next_row() {
+            if row.end_sequence()
+                || !function_ranges
+                    .iter()
+                    .any(|range| range.contains(&row.address()))
+            {
+                continue;
+            }
+
+            if row.epilogue_begin() {
+                epilogue_addresses.push(row.address());
+            }
+
+            if (function_die.low_pc..function_die.high_pc).contains(&row.address()) {
+                last_address = last_address.max(Some(row.address()));
+            }
+        }
+
+        if epilogue_addresses.is_empty() {
+            return last_address.map(ReturnAddresses::LastRow);
+        }
+
+        epilogue_addresses.sort_unstable();
+        epilogue_addresses.dedup();
+        Some(ReturnAddresses::Epilogue(epilogue_addresses))
+    }
+
+    /// Get the unwind table row from the `.debug_frame` section which applies to `address`.
+    ///
+    /// The frame sections of all merged binaries are searched, starting with the binary whose
+    /// debug information covers `address`.
+    pub fn unwind_table_row(&self, address: u64) -> Result<UnwindRowDump, DebugError> {
+        let preferred = self.debug_info_for_address(address);
+        let candidates = std::iter::once(preferred).chain(
+            self.binaries()
+                .filter(|(debug_info, _)| !std::ptr::eq(*debug_info, preferred.0)),
+        );
+
+        let mut first_error = None;
+        for (debug_info, offset) in candidates {
+            let Some(local_address) = address.checked_sub(offset) else {
+                continue;
+            };
+
+            match debug_info.local_unwind_table_row(local_address) {
+                Ok(mut row) => {
+                    row.fde_range = row.fde_range.start + offset..row.fde_range.end + offset;
+                    row.row_range = row.row_range.start + offset..row.row_range.end + offset;
+                    return Ok(row);
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            DebugError::Other(anyhow::anyhow!(
+                "No unwind information for address {address:#010x}"
+            ))
+        }))
+    }
+
+    /// Get the unwind table row from the `.debug_frame` section of this binary only.
+    fn local_unwind_table_row(&self, address: u64) -> Result<UnwindRowDump, DebugError> {
+        let unwind_bases = BaseAddresses::default();
+        let frame_descriptor_entry = self.frame_section.fde_for_address(
+            &unwind_bases,
+            address,
+            gimli::DebugFrame::cie_from_offset,
+        )?;
+
+        let mut unwind_context: Box<UnwindContext<DwarfReader>> = Box::new(UnwindContext::new());
+        let unwind_info = get_unwind_info(&mut unwind_context, &self.frame_section, address)?;
+
+        let cfa_rule = match unwind_info.cfa() {
+            gimli::CfaRule::RegisterAndOffset { register, offset } => {
+                format!("r{}{:+}", register.0, offset)
+            }
+            gimli::CfaRule::Expression(_) => "exp".to_string(),
+        };
+
+        let mut register_rules: Vec<(u16, String)> = unwind_info
+            .registers()
+            .map(|(register, rule)| {
+                let rule = match rule {
+                    gimli::RegisterRule::Undefined => "u".to_string(),
+                    gimli::RegisterRule::SameValue => "s".to_string(),
+                    gimli::RegisterRule::Offset(offset) => format!("c{offset:+}"),
+                    gimli::RegisterRule::ValOffset(offset) => format!("v{offset:+}"),
+                    gimli::RegisterRule::Register(register) => format!("r{}", register.0),
+                    gimli::RegisterRule::Expression(_) => "exp".to_string(),
+                    gimli::RegisterRule::ValExpression(_) => "vexp".to_string(),
+                    other => format!("{other:?}"),
+                };
+                (register.0, rule)
+            })
+            .collect();
+        register_rules.sort_by_key(|(register, _)| *register);
+
+        Ok(UnwindRowDump {
+            cie_offset: frame_descriptor_entry.cie().offset(),
+            fde_range: frame_descriptor_entry.initial_address()
+                ..frame_descriptor_entry.initial_address() + frame_descriptor_entry.len(),
+            row_range: unwind_info.start_address()..unwind_info.end_address(),
+            return_address_register: frame_descriptor_entry.cie().return_address_register().0,
+            cfa_rule,
+            register_rules,
+        })
+    }
+
+    /// Find the program counter where a breakpoint should be set,
+    /// given a source file, a line and optionally a column.
+    ///
+    /// With [merged](DebugInfo::merge) binaries, the location in the first binary which contains the line is used.
+    pub fn get_breakpoint_location(
+        &self,
+        path: &TypedPathBuf,
+        line: u64,
+        column: Option<u64>,
+    ) -> Result<VerifiedBreakpoint, DebugError> {
+        let mut first_error = None;
+        for (debug_info, offset) in self.binaries() {
+            match debug_info.local_breakpoint_location(path, line, column) {
+                Ok(breakpoint) => return Ok(relocate_breakpoint(breakpoint, offset)),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        // There is always at least one binary, `self`.
+        #[allow(clippy::unwrap_used)]
+        Err(first_error.unwrap())
+    }
+
+    /// Find the breakpoint location for a source line in this binary, see [`DebugInfo::get_breakpoint_location`].
+    fn local_breakpoint_location(
+        &self,
+        path: &TypedPathBuf,
+        line: u64,
+        column: Option<u64>,
+    ) -> Result<VerifiedBreakpoint, DebugError> {
+        tracing::debug!(
+            "Looking for breakpoint location for {}:{}:{}",
+            path.to_path().display(),
+            line,
+            column
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "-".to_owned())
+        );
+
+        let mut unit_iter = self.dwarf.units();
+
+        while let Some(unit_header) = self.get_next_unit_info(&mut unit_iter) {
+            let unit = &unit_header.unit;
+
+            if let Some(ref line_program) = unit.line_program {
+                let header = line_program.header();
+
+                for file_name in header.file_names() {
+                    let combined_path = self.get_path(unit, header, file_name);
+                    if combined_path
+                        .map(|p| canonical_path_eq(path, &p))
+                        .unwrap_or(false)
+                    {
+                        let mut rows = line_program.clone().rows();
+
+                        while let Some((header, row)) = rows.next_row()? {
+                            let row_path = row
+                                .file(header)
+                                .and_then(|file_entry| self.get_path(unit, header, file_entry));
+
+                            if row_path
+                                .map(|p| !canonical_path_eq(path, &p))
+                                .unwrap_or(true)
+                            {
+                                continue;
+                            }
+
+                            if let Some(cur_line) = row.line() {
+                                if cur_line.get() == line {
+                                    // The first match of the file and row will be used to build the SourceStatements, and then:
+                                    // 1. If there is an exact column match, we will use the low_pc of the statement at that column and line.
+                                    // 2. If there is no exact column match, we use the first available statement in the line.
+                                    let source_statements =
+                                        SourceStatements::new(self, &unit_header, row.address())?
+                                            .statements;
+                                    if let Some((halt_address, Some(halt_location))) =
+                                        source_statements
+                                            .iter()
+                                            .find(|statement| {
+                                                statement.line == Some(cur_line)
+                                                    && column
+                                                        .and_then(NonZeroU64::new)
+                                                        .map(ColumnType::Column)
+                                                        .map_or(false, |col| {
+                                                            col == statement.column
+                                                        })
+                                            })
+                                            .map(|source_statement| {
+                                                (
+                                                    source_statement.low_pc(),
+                                                    line_program
+                                                        .header()
+                                                        .file(source_statement.file_index)
+                                                        .and_then(|file_entry| {
+                                                            self.find_file_and_directory(
+                                                                &unit_header.unit,
+                                                                line_program.header(),
+                                                                file_entry,
+                                                            )
+                                                            .map(|(file, directory)| {
+                                                                SourceLocation {
+                                                                    line: source_statement
+                                                                        .line
+                                                                        .map(
+                                                                        std::num::NonZeroU64::get,
+                                                                    ),
+                                                                    column: Some(
+                                                                        source_statement
+                                                                            .column
+                                                                            .into(),
+                                                                    ),
+                                                                    file,
+                                                                    directory,
+                                                                    low_pc: Some(
+                                                                        source_statement.low_pc()
+                                                                            as u32,
+                                                                    ),
+                                                                    high_pc: Some(
+                                                                        source_statement
+                                                                            .instruction_range
+                                                                            .end
+                                                                            as u32,
+                                                                    ),
+                                                                    statement_range: Some(
+                                                                        source_statement
+                                                                            .instruction_range
+                                                                            .clone(),
+                                                                    ),
+                                                                }
+                                                            })
+                                                        }),
+                                                )
+                                            })
+                                    {
+                                        return Ok(VerifiedBreakpoint {
+                                            address: halt_address,
+                                            source_location: halt_location,
+                                        });
+                                    } else if let Some((halt_address, Some(halt_location))) =
+                                        source_statements
+                                            .iter()
+                                            .find(|statement| statement.line == Some(cur_line))
+                                            .map(|source_statement| {
+                                                (
+                                                    source_statement.low_pc(),
+                                                    line_program
+                                                        .header()
+                                                        .file(source_statement.file_index)
+                                                        .and_then(|file_entry| {
+                                                            self.find_file_and_directory(
+                                                                &unit_header.unit,
+                                                                line_program.header(),
+                                                                file_entry,
+                                                            )
+                                                            .map(|(file, directory)| {
+                                                                SourceLocation {
+                                                                    line: source_statement
+                                                                        .line
+                                                                        .map(
+                                                                        std::num::NonZeroU64::get,
+                                                                    ),
+                                                                    column: Some(
+                                                                        source_statement
+                                                                            .column
+                                                                            .into(),
+                                                                    ),
+                                                                    file,
+                                                                    directory,
+                                                                    low_pc: Some(
+                                                                        source_statement.low_pc()
+                                                                            as u32,
+                                                                    ),
+                                                                    high_pc: Some(
+                                                                        source_statement
+                                                                            .instruction_range
+                                                                            .end
+                                                                            as u32,
+                                                                    ),
+                                                                    statement_range: Some(
+                                                                        source_statement
+                                                                            .instruction_range
+                                                                            .clone(),
+                                                                    ),
+                                                                }
+                                                            })
+                                                        }),
+                                                )
+                                            })
+                                    {
+                                        return Ok(VerifiedBreakpoint {
+                                            address: halt_address,
+                                            source_location: halt_location,
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let p = path.to_path();
+
+        Err(DebugError::Other(anyhow::anyhow!(
+            "No valid breakpoint information found for file: {}, line: {:?}, column: {:?}",
+            p.display(),
+            line,
+            column
+        )))
+    }
+
+    /// Find the address where a breakpoint should be set to halt on entry to the function `name`.
+    ///
+    /// The function can be specified by its name, its linkage (mangled) name, or its demangled name.
+    /// The breakpoint is set on the first statement after the prologue of the function.
+    /// The functions of all [merged](DebugInfo::merge) binaries are considered.
+    pub fn breakpoint_for_function(&self, name: &str) -> Result<VerifiedBreakpoint, DebugError> {
+        // The display name, the entry address and the binary of each matching function.
+        let mut candidates: Vec<(String, u64, &DebugInfo, u64)> = Vec::new();
+        for (debug_info, offset) in self.binaries() {
+            for (display_name, entry_address) in debug_info.function_entries(name)? {
+                candidates.push((display_name, entry_address, debug_info, offset));
+            }
+        }
+
+        let (entry_address, debug_info, offset) = match candidates.as_slice() {
+            [] => {
+                return Err(DebugError::Other(anyhow::anyhow!(
+                    "No function with the name `{}` found",
+                    name
+                )))
+            }
+            [(_, entry_address, debug_info, offset)] => (*entry_address, *debug_info, *offset),
+            _ => {
+                let candidate_list = candidates
+                    .iter()
+                    .map(|(name, address, _, offset)| {
+                        format!("{name} @ {:#010x}", address + offset)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(DebugError::Other(anyhow::anyhow!(
+                    "The function name `{}` is ambiguous, candidates are: {}",
+                    name,
+                    candidate_list
+                )));
+            }
+        };
+
+        match SteppingMode::BreakPoint.get_halt_location(None, debug_info, entry_address, None)? {
+            (Some(address), Some(source_location)) => Ok(relocate_breakpoint(
+                VerifiedBreakpoint {
+                    address,
+                    source_location,
+                },
+                offset,
+            )),
+            _ => Err(DebugError::NoValidHaltLocation {
+                message: format!(
+                    "Could not find a valid breakpoint location for the function `{name}`."
+                ),
+                pc_at_error: entry_address + offset,
+            }),
+        }
+    }
+
+    /// The display name and the entry address of each function in this binary which can be referred to by `name`.
+    fn function_entries(&self, name: &str) -> Result<Vec<(String, u64)>, DebugError> {
+        let mut candidates: Vec<(String, u64)> = Vec::new();
+
+        let mut unit_iter = self.dwarf.units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let mut entries = unit_info.unit.entries();
+            while let Ok(Some((_, entry))) = entries.next_dfs() {
+                if entry.tag() != gimli::DW_TAG_subprogram {
+                    continue;
+                }
+
+                // Declarations and abstract instances of inlined functions have no code, and are skipped.
+                // So are functions which only have empty ranges, e.g. after identical code folding.
+                let Some(entry_address) = self
+                    .dwarf
+                    .die_ranges(&unit_info.unit, entry)
+                    .ok()
+                    .and_then(|mut ranges| {
+                        ranges
+                            .find(|range| Ok(range.begin < range.end))
+                            .ok()
+                            .flatten()
+                    })
+                    .map(|range| range.begin)
+                else {
+                    continue;
+                };
+
+                let names = self.function_names(&unit_info, entry)?;
+                if !names.iter().any(|function_name| function_name == name)
+                    || candidates
+                        .iter()
+                        .any(|(_, address)| *address == entry_address)
+                {
+                    continue;
+                }
+
+                // Prefer the most descriptive name, which is the demangled name if there is one.
+                let display_name = names.last().cloned().unwrap_or_else(|| name.to_string());
+                candidates.push((display_name, entry_address));
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    /// All the names by which the function described by `entry` can be referred to.
+    ///
+    /// This is the `DW_AT_name`, the linkage name, and the demangled linkage name, in that order.
+    fn function_names(
+        &self,
+        unit_info: &UnitInfo,
+        entry: &gimli::DebuggingInformationEntry<GimliReader>,
+    ) -> Result<Vec<String>, DebugError> {
+        let mut names = Vec::new();
+
+        if let Some(name) = self.die_name(unit_info, entry)? {
+            names.push(name);
+        }
+
+        // The linkage name is either on the concrete function, or on its declaration.
+        let mut linkage_name = None;
+        let mut current_entry = entry.clone();
+        for _ in 0..2 {
+            linkage_name = current_entry
+                .attr_value(gimli::DW_AT_linkage_name)?
+                .or(current_entry.attr_value(gimli::DW_AT_MIPS_linkage_name)?);
+            if linkage_name.is_some() {
+                break;
+            }
+            let origin = current_entry
+                .attr_value(gimli::DW_AT_abstract_origin)?
+                .or(current_entry.attr_value(gimli::DW_AT_specification)?);
+            let Some(gimli::AttributeValue::UnitRef(origin)) = origin else {
+                break;
+            };
+            current_entry = unit_info.unit.entry(origin)?;
+        }
+
+        if let Some(linkage_name) = linkage_name {
+            let linkage_name = super::extract_name(self, linkage_name);
+            if let Ok(demangled) = rustc_demangle::try_demangle(&linkage_name) {
+                // The alternate format omits the hash of the symbol.
+                names.push(linkage_name.clone());
+                names.push(format!("{demangled:#}"));
+            } else {
+                names.push(linkage_name);
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Get all compilation units which include `path` in the file table of their line program.
+    ///
+    /// A source file, e.g. a header or a generic function, can be used by many compilation units, and all of them are returned.
+    /// This includes the units of all [merged](DebugInfo::merge) binaries.
+    pub fn units_for_file(&self, path: &TypedPathBuf) -> Vec<UnitMetadata> {
+        self.binaries()
+            .flat_map(|(debug_info, _)| debug_info.local_units_for_file(path))
+            .collect()
+    }
+
+    /// Get the compilation units of this binary which include `path`, see [`DebugInfo::units_for_file`].
+    fn local_units_for_file(&self, path: &TypedPathBuf) -> Vec<UnitMetadata> {
+        let mut units = Vec::new();
+        let mut unit_iter = self.dwarf.units();
+
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let unit = &unit_info.unit;
+
+            let Some(line_program) = unit.line_program.as_ref() else {
+                continue;
+            };
+            let header = line_program.header();
+
+            let includes_file = header.file_names().iter().any(|file_entry| {
+                self.get_path(unit, header, file_entry)
+                    .map(|file_path| canonical_path_eq(path, &file_path))
+                    .unwrap_or(false)
+            });
+
+            if !includes_file {
+                continue;
+            }
+
+            let producer = unit
+                .entries()
+                .next_dfs()
+                .ok()
+                .flatten()
+                .and_then(|(_, root)| root.attr_value(gimli::DW_AT_producer).ok().flatten())
+                .and_then(|producer| self.dwarf.attr_string(unit, producer).ok())
+                .map(|producer| String::from_utf8_lossy(&producer).into_owned());
+
+            units.push(UnitMetadata {
+                name: unit
+                    .name
+                    .as_ref()
+                    .map(|name| String::from_utf8_lossy(name).into_owned()),
+                comp_dir: unit
+                    .comp_dir
+                    .as_ref()
+                    .and_then(|dir| from_utf8(dir).ok())
+                    .map(|dir| TypedPath::derive(dir).to_path_buf()),
+                producer,
+            });
+        }
+
+        units
+    }
+
+    /// Map every source line to the instruction address ranges generated for it.
+    ///
+    /// Combined with an execution trace, this can be used to compute the line coverage of a program.
+    /// The ranges are collected from the line programs of all units, and adjacent ranges of the same line are merged.
+    /// The `column` of the returned source locations is not set, and `low_pc` and `high_pc` describe the range.
+    /// The lines of all [merged](DebugInfo::merge) binaries are included, at the addresses they are loaded at.
+    pub fn line_coverage_map(&self) -> Vec<(SourceLocation, Range<u64>)> {
+        self.binaries()
+            .flat_map(|(debug_info, offset)| {
+                debug_info
+                    .local_line_coverage_map()
+                    .into_iter()
+                    .map(move |(location, range)| {
+                        (
+                            relocate_source_location(location, offset),
+                            range.start + offset..range.end + offset,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Map the source lines of this binary to address ranges, see [`DebugInfo::line_coverage_map`].
+    fn local_line_coverage_map(&self) -> Vec<(SourceLocation, Range<u64>)> {
+        let mut coverage: Vec<(SourceLocation, Range<u64>)> = Vec::new();
+
+        let mut unit_iter = self.dwarf.units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let unit = &unit_info.unit;
+            let Some(line_program) = unit.line_program.clone() else {
+                continue;
+            };
+
+            // The location of the previous row, which covers all addresses up to the current row.
+            let mut previous_row: Option<(SourceLocation, u64)> = None;
+
+            let mut rows = line_program.rows();
+            while let Ok(Some((header, row))) = rows.next_row() {
+                if let Some((location, start)) = previous_row.take() {
+                    if start < row.address() {
+                        let range = start..row.address();
+                        match coverage.last_mut() {
+                            Some((last_location, last_range))
+                                if *last_location == location && last_range.end == range.start =>
+                            {
+                                last_range.end = range.end;
+                            }
+                            _ => coverage.push((location, range)),
+                        }
+                    }
+                }
+
+                // The end of a sequence is the first address after the last instruction.
+                if row.end_sequence() {
+                    continue;
+                }
+
+                let Some(line) = row.line() else {
+                    continue;
+                };
+                let Some((file, directory)) = row
+                    .file(header)
+                    .and_then(|file_entry| self.find_file_and_directory(unit, header, file_entry))
+                else {
+                    continue;
+                };
+
+                previous_row = Some((
+                    SourceLocation {
+                        line: Some(line.get()),
+                        column: None,
+                        file,
+                        directory,
+                        low_pc: None,
+                        high_pc: None,
+                        statement_range: None,
+                    },
+                    row.address(),
+                ));
+            }
+        }
+
+        for (location, range) in &mut coverage {
+            location.low_pc = Some(range.start as u32);
+            location.high_pc = Some(range.end as u32);
+        }
+
+        coverage
+    }
+
+    /// Check the debug information for common problems, which would otherwise only show up
+    /// later as missing source locations, variables or stack frames.
+    ///
+    /// This never fails, problems which prevent the check itself are ignored.
+    /// All [merged](DebugInfo::merge) binaries are checked, and their function ranges are reported at the addresses they are loaded at.
+    pub fn verify(&self) -> Vec<DebugWarning> {
+        self.binaries()
+            .flat_map(|(debug_info, offset)| {
+                debug_info
+                    .local_verify()
+                    .into_iter()
+                    .map(move |warning| match warning {
+                        DebugWarning::InvalidFunctionRange {
+                            function,
+                            low_pc,
+                            high_pc,
+                        } => DebugWarning::InvalidFunctionRange {
+                            function,
+                            low_pc: low_pc + offset,
+                            high_pc: high_pc + offset,
+                        },
+                        other => other,
+                    })
+            })
+            .collect()
+    }
+
+    /// Check the debug information of this binary, see [`DebugInfo::verify`].
+    fn local_verify(&self) -> Vec<DebugWarning> {
+        use gimli::{Reader, Section};
+
+        let mut warnings = Vec::new();
+
+        if self.debug_line_section.reader().is_empty() {
+            warnings.push(DebugWarning::MissingLineSection);
+        }
+
+        if self.frame_section.reader().is_empty() {
+            warnings.push(DebugWarning::MissingFrameSection);
+        }
+
+        let mut unit_iter = self.dwarf.units();
+        while let Some(unit_info) = self.get_next_unit_info(&mut unit_iter) {
+            let unit = &unit_info.unit;
+
+            if unit.line_program.is_none() {
+                let name = match &unit.name {
+                    Some(name) => String::from_utf8_lossy(name).into_owned(),
+                    None => format!(
+                        "<unnamed unit at {:#x}>",
+                        unit.header
+                            .offset()
+                            .as_debug_info_offset()
+                            .map_or(0, |offset| offset.0)
+                    ),
+                };
+                warnings.push(DebugWarning::UnitWithoutLineProgram { unit: name });
+            }
+
+            let mut entries = unit.entries();
+            while let Ok(Some((_, entry))) = entries.next_dfs() {
+                if entry.tag() != gimli::DW_TAG_subprogram {
+                    continue;
+                }
+
+                let Ok(Some(low_pc)) = entry.attr_value(gimli::DW_AT_low_pc) else {
+                    continue;
+                };
+                let Ok(Some(low_pc)) = self.dwarf.attr_address(unit, low_pc) else {
+                    continue;
+                };
+
+                // A `DW_AT_high_pc` which is encoded as an offset from `DW_AT_low_pc` is always valid.
+                let Ok(Some(high_pc)) = entry.attr_value(gimli::DW_AT_high_pc) else {
+                    continue;
+                };
+                let Ok(Some(high_pc)) = self.dwarf.attr_address(unit, high_pc) else {
+                    continue;
+                };
+
+                if low_pc > high_pc {
+                    let function = self
+                        .die_name(&unit_info, entry)
+                        .ok()
+                        .flatten()
+                        .unwrap_or_else(|| "<unknown function>".to_string());
+
+                    warnings.push(DebugWarning::InvalidFunctionRange {
+                        function,
+                        low_pc,
+                        high_pc,
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Get the path for an entry in a line program header, using the compilation unit's directory and file entries.
+    ///
+    /// Relative paths are resolved against the [comp_dir override](DebugInfo::set_comp_dir_override), if one is set.
+    /// They are left relative if [resolving them](DebugInfo::set_resolve_relative_paths) is disabled.
+    // TODO: Determine if it is necessary to navigate the include directories to find the file absolute path for C files.
+    pub(crate) fn get_path(
+        &self,
+        unit: &gimli::read::Unit<DwarfReader>,
+        header: &LineProgramHeader<DwarfReader>,
+        file_entry: &FileEntry<DwarfReader>,
+    ) -> Option<TypedPathBuf> {
+        let file_name_attr_string = self.dwarf.attr_string(unit, file_entry.path_name()).ok()?;
+        // Paths which are not valid UTF-8 (e.g. from a Windows codepage) are decoded lossily,
+        // so that the location is still usable.
+        let name_path = String::from_utf8_lossy(&file_name_attr_string);
+
+        let dir_name_attr_string = file_entry
+            .directory(header)
+            .and_then(|dir| self.dwarf.attr_string(unit, dir).ok());
+
+        let dir_path = dir_name_attr_string.map(|dir_name| {
+            TypedPath::derive(String::from_utf8_lossy(&dir_name).as_ref()).to_path_buf()
+        });
+
+        let mut combined_path = match dir_path {
+            Some(dir_path) => dir_path.join(name_path.as_ref()),
+            None => TypedPath::derive(name_path.as_ref()).to_path_buf(),
+        };
+
+        if combined_path.is_relative() && self.resolve_relative_paths {
+            if let Some(comp_dir) = &self.comp_dir_override {
+                return Some(comp_dir.join(&combined_path));
+            }
+
+            if let Some(comp_dir) = unit.comp_dir.as_ref() {
+                let comp_dir = String::from_utf8_lossy(comp_dir);
+                combined_path = TypedPath::derive(comp_dir.as_ref()).join(&combined_path);
+            }
+        }
+
+        Some(combined_path)
+    }
+
+    /// Split the path of a line program file entry, as resolved by [`DebugInfo::get_path`], into its file name and directory.
+    pub(crate) fn find_file_and_directory(
+        &self,
+        unit: &gimli::read::Unit<DwarfReader>,
+        header: &LineProgramHeader<DwarfReader>,
+        file_entry: &FileEntry<DwarfReader>,
+    ) -> Option<(Option<String>, Option<TypedPathBuf>)> {
+        let combined_path = self.get_path(unit, header, file_entry)?;
+
+        let file_name = combined_path
+            .file_name()
+            .map(|name| String::from_utf8_lossy(name).into_owned());
+
+        let directory = combined_path.parent().map(|p| p.to_path_buf());
+
+        Some((file_name, directory))
+    }
+}
+
+/// Check if a runnable file and a separate debug information file were produced by the same build.
+///
+/// The build IDs are compared if both files have one. Otherwise, the sizes of the `.text` sections are compared,
+/// which are kept in the debug file as sections without data.
+fn is_same_build(runnable_object: &object::File, debug_object: &object::File) -> bool {
+    if let (Ok(Some(runnable_id)), Ok(Some(debug_id))) =
+        (runnable_object.build_id(), debug_object.build_id())
+    {
+        return runnable_id == debug_id;
+    }
+
+    let text_size = |object: &object::File| object.section_by_name(".text").map(|text| text.size());
+
+    text_size(runnable_object) == text_size(debug_object)
+}
+
+/// Collect the `PT_LOAD` segments from the program headers of an ELF file.
+fn load_segments<Elf: object::read::elf::FileHeader>(
+    elf: &object::read::elf::ElfFile<'_, Elf>,
+) -> Vec<LoadSegment> {
+    use object::read::elf::ProgramHeader;
+
+    let endian = elf.endian();
+
+    elf.raw_segments()
+        .iter()
+        .filter(|segment| segment.p_type(endian) == object::elf::PT_LOAD)
+        .map(|segment| LoadSegment {
+            virtual_address: segment.p_vaddr(endian).into(),
+            physical_address: segment.p_paddr(endian).into(),
+            size: segment.p_memsz(endian).into(),
+        })
+        .collect()
+}
+
+/// Uses the [std::fs::canonicalize] function to canonicalize both paths before applying the [std::path::PathBuf::eq]
+/// to test if the secondary path is equal or a suffix of the primary path.
+/// If for some reason (e.g., the paths don't exist) the canonicalization fails, the original equality check is used.
+/// We do this to maximize the chances of finding a match where the secondary path can be given as
+/// an absolute, relative, or partial path.
+pub(crate) fn canonical_path_eq(
+    primary_path: &TypedPathBuf,
+    secondary_path: &TypedPathBuf,
+) -> bool {
+    primary_path.normalize() == secondary_path.normalize()
+}
+
+/// Get a handle to the [`gimli::UnwindTableRow`] for this call frame, so that we can reference it to unwind register values.
+fn get_unwind_info<'a>(
+    unwind_context: &'a mut Box<UnwindContext<DwarfReader>>,
+    frame_section: &'a DebugFrame<DwarfReader>,
+    frame_program_counter: u64,
+) -> Result<&'a gimli::UnwindTableRow<DwarfReader, gimli::StoreOnHeap>, DebugError> {
+    let unwind_bases = BaseAddresses::default();
+    let frame_descriptor_entry = match frame_section.fde_for_address(
+        &unwind_bases,
+        frame_program_counter,
+        gimli::DebugFrame::cie_from_offset,
+    ) {
+        Ok(frame_descriptor_entry) => frame_descriptor_entry,
+        Err(error) => {
+            return Err(DebugError::Other(anyhow::anyhow!(
+                "UNWIND: Error reading FrameDescriptorEntry at PC={} : {}",
+                frame_program_counter,
+                error
+            )));
+        }
+    };
+
+    frame_descriptor_entry
+        .unwind_info_for_address(
+            frame_section,
+            &unwind_bases,
+            unwind_context,
+            frame_program_counter,
+        )
+        .map_err(|error| {
+            DebugError::Other(anyhow::anyhow!(
+                "UNWIND: Error reading FrameDescriptorEntry at PC={} : {}",
+                frame_program_counter,
+                error
+            ))
+        })
+}
+
+/// A per_register unwind, applying register rules and updating the [`registers::DebugRegister`] value as appropriate, before returning control to the calling function.
+/// If `unwind_trace` is provided, the applied rule and resulting value are recorded as a [`RegisterUnwindStep`] for the frame at `frame_index`.
+#[allow(clippy::too_many_arguments)]
+fn unwind_register(
+    debug_register: &mut super::DebugRegister,
+    // The callee_frame_registers are used to lookup values and never updated.
+    callee_frame_registers: &DebugRegisters,
+    unwind_info: Option<&gimli::UnwindTableRow<DwarfReader, gimli::StoreOnHeap>>,
+    unwind_cfa: Option<u64>,
+    unwound_return_address: &mut Option<RegisterValue>,
+    memory: &mut dyn MemoryInterface,
+    instruction_set: Option<InstructionSet>,
+    pointer_authentication: &mut PointerAuthentication,
+    frame_index: usize,
+    unwind_trace: Option<&mut Vec<RegisterUnwindStep>>,
+) -> ControlFlow<(), ()> {
+    use gimli::read::RegisterRule::*;
+    // If we do not have unwind info, or there is no register rule, then use UnwindRule::Undefined.
+    let register_rule = debug_register
+        .dwarf_id
+        .and_then(|register_position| {
+            unwind_info.map(|unwind_info| unwind_info.register(gimli::Register(register_position)))
+        })
+        .unwrap_or(gimli::RegisterRule::Undefined);
+    let mut register_rule_string = format!("{register_rule:?}");
+    let new_value = match register_rule {
+        Undefined => {
+            // In many cases, the DWARF has `Undefined` rules for registers like the frame pointer, program counter, etc.,
+            // so the default rule from the register definition is used to make sure unwinding can continue.
+            // If there is a valid rule, it will bypass these defaults.
+            match debug_register.core_register.unwind_rule {
+                UnwindRule::CanonicalFrameAddress => {
+                    // NOTE: [ARMv7-M Architecture Reference Manual](https://developer.arm.com/documentation/ddi0403/ee), Section B.1.4.1: Treat bits [1:0] as `Should be Zero or Preserved`
+                    // - Applying this logic to RISCV has no adverse effects, since all incoming addresses are already 32-bit aligned.
+                    register_rule_string = "SP=CFA (dwarf Undefined)".to_string();
+                    unwind_cfa.map(|unwind_cfa| {
+                        if debug_register.is_u32() {
+                            RegisterValue::U32(unwind_cfa as u32 & !0b11)
+                        } else {
+                            RegisterValue::U64(unwind_cfa & !0b11)
+                        }
+                    })
+                }
+                UnwindRule::ReturnAddress => {
+                    // This value is can only be used to determine the Undefined PC value. We have no way of inferring the previous frames LR until we have the PC.
+                    register_rule_string = "LR=Unknown (dwarf Undefined)".to_string();
+                    *unwound_return_address = debug_register.value;
+                    None
+                }
+                UnwindRule::ProgramCounter => {
+                    // NOTE: PC = Value of the unwound LR, i.e. the first instruction after the one that called this function.
+                    register_rule_string = "PC=(unwound LR) (dwarf Undefined)".to_string();
+                    unwound_return_address.and_then(|return_address| {
+                        unwind_program_counter_register(
+                            return_address,
+                            instruction_set,
+                            pointer_authentication,
+                            &mut register_rule_string,
+                        )
+                    })
+                }
+                // If the the register rule was not specified, then we either carry the previous value forward,
+                // or we clear the register value, depending on the architecture and register type.
+                UnwindRule::Preserve => {
+                    register_rule_string = "Preserve".to_string();
+                    callee_register_value(
+                        callee_frame_registers.get_register(debug_register.core_register.id),
+                        &mut register_rule_string,
+                    )
+                }
+                UnwindRule::Clear => {
+                    register_rule_string = "Clear".to_string();
+                    None
+                }
+                UnwindRule::SpecialRule => {
+                    // When no DWARF rules are available, and it is not a special register like PC, SP, FP, etc.,
+                    // we will preserve the value. It is possible it might have its value set later if
+                    // exception frame information is available.
+                    register_rule_string = "Clear (no unwind rules specified)".to_string();
+                    None
+                }
+            }
+        }
+        SameValue => callee_register_value(
+            callee_frame_registers.get_register(debug_register.core_register.id),
+            &mut register_rule_string,
+        ),
+        Register(register) => callee_register_value(
+            callee_frame_registers.get_register_by_dwarf_id(register.0),
+            &mut register_rule_string,
+        ),
+        Offset(address_offset) => {
+            // "The previous value of this register is saved at the address CFA+N where CFA is the current CFA value and N is a signed offset"
+            if let Some(unwind_cfa) = unwind_cfa {
+                let address_size = callee_frame_registers.get_address_size_bytes();
+                let previous_frame_register_address =
+                    add_to_address(unwind_cfa, address_offset, address_size);
+
+                register_rule_string = format!("CFA {register_rule:?}");
+                let result = match address_size {
+                    4 => {
+                        let mut buff = [0u8; 4];
+                        memory
+                            .read(previous_frame_register_address, &mut buff)
+                            .map(|_| RegisterValue::U32(u32::from_le_bytes(buff)))
+                    }
+                    8 => {
+                        let mut buff = [0u8; 8];
+                        memory
+                            .read(previous_frame_register_address, &mut buff)
+                            .map(|_| RegisterValue::U64(u64::from_le_bytes(buff)))
+                    }
+                    _ => {
+                        tracing::error!(
+                            "UNWIND: Address size {} not supported.  Please report this as a bug.",
+                            address_size
+                        );
+                        return ControlFlow::Break(());
+                    }
+                };
+
+                match result {
+                    Ok(register_value) => {
+                        if debug_register
+                            .core_register
+                            .register_has_role(RegisterRole::ReturnAddress)
+                        {
+                            // We need to store this value to be used by the calculation of the PC.
+                            *unwound_return_address = Some(register_value);
+                        }
+                        Some(register_value)
+                    }
+                    Err(error) => {
+                        tracing::error!(
+                            "UNWIND: Failed to read value for register {} from address {} ({} bytes): {}",
+                            debug_register.get_register_name(),
+                            RegisterValue::from(previous_frame_register_address),
+                            4,
+                            error
+                        );
+                        tracing::error!(
+                            "UNWIND: Rule: Offset {} from address {:#010x}",
+                            address_offset,
+                            unwind_cfa
+                        );
+                        return ControlFlow::Break(());
+                    }
+                }
+            } else {
+                tracing::error!("UNWIND: Tried to unwind `RegisterRule` at CFA = None. Please report this as a bug.");
+                return ControlFlow::Break(());
+            }
+        }
+        //TODO: Implement the remainder of these `RegisterRule`s
+        _ => unimplemented!(),
+    };
+    debug_register.value = new_value;
+
+    tracing::trace!(
+        "UNWIND - {:>10}: Caller: {}\tCallee: {}\tRule: {}",
+        debug_register.get_register_name(),
+        debug_register.value.unwrap_or_default(),
+        callee_frame_registers
+            .get_register(debug_register.core_register.id)
+            .and_then(|reg| reg.value)
+            .unwrap_or_default(),
+        register_rule_string,
+    );
+
+    if let Some(unwind_trace) = unwind_trace {
+        unwind_trace.push(RegisterUnwindStep {
+            frame_index,
+            register_name: debug_register.get_register_name(),
+            rule: register_rule_string,
+            value: debug_register.value,
+        });
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// The value of `register` in the callee frame, for the unwind rules which carry it forward to the calling frame.
+///
+/// If the value is not known, e.g. when unwinding from a partial set of registers, the register stays unknown
+/// in the calling frame. This is noted in `register_rule_string`, to tell it apart from a cleared register.
+fn callee_register_value(
+    register: Option<&super::DebugRegister>,
+    register_rule_string: &mut String,
+) -> Option<RegisterValue> {
+    let value = register.and_then(|register| register.value);
+    if value.is_none() {
+        register_rule_string.push_str(" (unknown in the callee frame)");
+    }
+    value
+}
+
+/// The instruction set which `core` is currently executing.
+///
+/// Cores which can switch between instruction sets report the current one in their processor status register,
+/// so it is taken from `registers` where possible, with [`Core::instruction_set`] as the fallback.
+fn current_instruction_set(
+    core: &mut Core<'_>,
+    registers: &DebugRegisters,
+) -> Option<InstructionSet> {
+    registers
+        .instruction_set(core.core_type())
+        .or_else(|| core.instruction_set().ok())
+}
+
+/// Pass the `stack_frames` after the first `reported_frames` to the `on_frame` callback of an unwind.
+///
+/// If the callback stops the unwind, the frames after the one it stopped at are discarded.
+fn report_frames(
+    stack_frames: &mut Vec<StackFrame>,
+    reported_frames: &mut usize,
+    on_frame: &mut Option<&mut dyn FnMut(&StackFrame) -> ControlFlow<()>>,
+) -> ControlFlow<()> {
+    let Some(on_frame) = on_frame else {
+        return ControlFlow::Continue(());
+    };
+
+    while let Some(frame) = stack_frames.get(*reported_frames) {
+        *reported_frames += 1;
+        if on_frame(frame).is_break() {
+            stack_frames.truncate(*reported_frames);
+            return ControlFlow::Break(());
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// The smallest virtual address size of an A64 target, with the small translation tables of Armv8.4-A (`TxSZ` = 48).
+const MIN_VIRTUAL_ADDRESS_BITS: u8 = 16;
+/// The largest virtual address size of an A64 target, with the large virtual addresses of Armv8.2-A.
+const MAX_VIRTUAL_ADDRESS_BITS: u8 = 52;
+
+/// How pointer authentication codes are removed from the A64 return addresses of an unwind,
+/// see [`strip_pointer_authentication_code`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct PointerAuthentication {
+    /// The size of the virtual addresses, or `None` if the return addresses can not be signed.
+    virtual_address_bits: Option<u8>,
+    /// Set once a pointer authentication code was removed from a return address.
+    stripped: bool,
+}
+
+/// The number of low bits of an A64 address which are not a copy of bit 55, i.e. the smallest virtual address size
+/// which can hold the address in either the lower or the upper address range.
+fn significant_address_bits(address: u64) -> u8 {
+    let copies_of_bit_55 = if address & (1 << 55) == 0 {
+        address.leading_zeros()
+    } else {
+        address.leading_ones()
+    };
+
+    (64 - copies_of_bit_55) as u8
+}
+
+/// Helper function to determine the program counter value for the previous frame.
+fn unwind_program_counter_register(
+    return_address: RegisterValue,
+    instruction_set: Option<InstructionSet>,
+    pointer_authentication: &mut PointerAuthentication,
+    register_rule_string: &mut String,
+) -> Option<RegisterValue> {
+    if return_address.is_max_value() || return_address.is_zero() {
+        tracing::warn!("No reliable return address is available, so we cannot determine the program counter to unwind the previous frame.");
+        None
+    } else {
+        match return_address {
+            RegisterValue::U32(return_address) => {
+                if instruction_set == Some(InstructionSet::Thumb2) {
+                    // NOTE: [ARMv7-M Architecture Reference Manual](https://developer.arm.com/documentation/ddi0403/ee), Section A5.1.2: We have to clear the last bit to ensure the PC is half-word aligned. (on ARM architecture, when in Thumb state for certain instruction types will set the LSB to 1)
+                    *register_rule_string = "PC=(unwound LR & !0b1) (dwarf Undefined)".to_string();
+                    Some(RegisterValue::U32(return_address & !0b1))
+                } else {
+                    Some(RegisterValue::U32(return_address))
+                }
+            }
+            RegisterValue::U64(return_address) => {
+                let Some(virtual_address_bits) = pointer_authentication.virtual_address_bits else {
+                    return Some(RegisterValue::U64(return_address));
+                };
+
+                let stripped_address =
+                    strip_pointer_authentication_code(return_address, virtual_address_bits);
+                if stripped_address != return_address {
+                    if !pointer_authentication.stripped {
+                        tracing::warn!(
+                            "UNWIND: Return address {:#018x} is signed, stripping the pointer authentication code above bit {}.",
+                            return_address,
+                            virtual_address_bits
+                        );
+                        pointer_authentication.stripped = true;
+                    }
+                    *register_rule_string =
+                        "PC=(unwound LR without PAC) (dwarf Undefined)".to_string();
+                }
+                Some(RegisterValue::U64(stripped_address))
+            }
+            RegisterValue::U128(_) => {
+                tracing::warn!("128 bit address space not supported");
+                None
+            }
+        }
+    }
+}
+
+/// Remove the pointer authentication code (PAC) from an A64 return address.
+///
+/// On cores with pointer authentication (Armv8.3-A and later), `paciasp` signs the link register by placing a PAC in the
+/// unused upper bits of the address, which has to be removed before the address can be used as a PC. The PAC starts at
+/// `virtual_address_bits`, and the bits above it are restored from bit 55, which selects between the lower and upper
+/// address range (like `xpaci` does). Addresses without a PAC are not changed by this.
+///
+/// Armv8.1-M keeps the PAC in a separate register, so Thumb return addresses never need this.
+fn strip_pointer_authentication_code(address: u64, virtual_address_bits: u8) -> u64 {
+    let pac_mask = u64::MAX << virtual_address_bits.min(MAX_VIRTUAL_ADDRESS_BITS);
+
+    if address & (1 << 55) == 0 {
+        address & !pac_mask
+    } else {
+        address | pac_mask
+    }
+}
+
+/// Only the first of the function DIEs found for an address can be a non-inlined function, and all the following
+/// ones have to be functions inlined into it. Invalid debug information, or functions that were merged by the linker
+/// (identical code folding), could violate this. Instead of failing the unwind, we keep only the functions before the
+/// offending one, which results in a single frame for the non-inlined function.
+fn discard_overlapping_functions(functions: &mut Vec<FunctionDie>, address: u64) {
+    if let Some(position) = functions
+        .iter()
+        .skip(1)
+        .position(|function| !function.is_inline())
+    {
+        tracing::warn!(
+            "UNWIND: Found overlapping non-inlined functions {:?} and {:?} at address {:#010x}. The debug information may be invalid. Ignoring the overlapping function.",
+            functions[0].function_name(),
+            functions[position + 1].function_name(),
+            address
+        );
+        functions.truncate(position + 1);
+    }
+}
+
+/// Move the address and source location of a `breakpoint` from a [merged](DebugInfo::merge) binary by the `offset` at which it is loaded.
+fn relocate_breakpoint(breakpoint: VerifiedBreakpoint, offset: u64) -> VerifiedBreakpoint {
+    VerifiedBreakpoint {
+        address: breakpoint.address + offset,
+        source_location: relocate_source_location(breakpoint.source_location, offset),
+    }
+}
+
+/// Move all addresses of a `location` from a [merged](DebugInfo::merge) binary by the `offset` at which it is loaded.
+fn relocate_source_location(location: SourceLocation, offset: u64) -> SourceLocation {
+    let relocate = |pc: u32| (u64::from(pc) + offset) as u32;
+
+    SourceLocation {
+        low_pc: location.low_pc.map(relocate),
+        high_pc: location.high_pc.map(relocate),
+        statement_range: location
+            .statement_range
+            .as_ref()
+            .map(|range| range.start + offset..range.end + offset),
+        ..location
+    }
+}
+
+/// Determine the CFA from the frame pointer, for functions which follow the frame pointer convention of the instruction set.
+///
+/// This is only a fallback for when the register used by the CFA rule is not available.
+/// Returns `None` if the frame pointer is unknown or zero, or if the instruction set has no known convention.
+fn frame_pointer_cfa(
+    registers: &DebugRegisters,
+    instruction_set: Option<InstructionSet>,
+) -> Option<u64> {
+    let offset = match instruction_set? {
+        // `push {r7, lr}` followed by `mov r7, sp`.
+        InstructionSet::Thumb2 => 8,
+        // `stp x29, x30, [sp, #-16]!` followed by `mov x29, sp`.
+        InstructionSet::A64 => 16,
+        // The frame pointer `s0` is set to the stack pointer on entry.
+        InstructionSet::RV32 | InstructionSet::RV32C => 0,
+        // The frame pointer register of A32 code depends on the compiler.
+        InstructionSet::A32 => return None,
+    };
+
+    let frame_pointer = registers.get_frame_pointer()?.value?;
+    if frame_pointer.is_zero() {
+        return None;
+    }
+
+    Some(add_to_address(
+        frame_pointer.try_into().ok()?,
+        offset,
+        registers.get_address_size_bytes(),
+    ))
+}
+
+/// Helper function to handle adding a signed offset to a [`RegisterValue`] address.
+/// The numerical overflow is handled based on the byte size (`address_size_in_bytes` parameter  )
+/// of the [`RegisterValue`], as opposed to just the datatype of the `address` parameter.
+/// In the case of unwinding stack frame register values, it makes no sense to wrap,
+/// because it will result in invalid register address reads.
+/// Instead, when we detect over/underflow, we return an address value of 0x0,
+/// which will trigger a graceful (and logged) end of a stack unwind.
+fn add_to_address(address: u64, offset: i64, address_size_in_bytes: usize) -> u64 {
+    match address_size_in_bytes {
+        4 => {
+            if offset >= 0 {
+                (address as u32)
+                    .checked_add(offset as u32)
+                    .map(u64::from)
+                    .unwrap_or(0x0)
+            } else {
+                (address as u32).saturating_sub(offset.unsigned_abs() as u32) as u64
+            }
+        }
+        8 => {
+            if offset >= 0 {
+                address.checked_add(offset as u64).unwrap_or(0x0)
+            } else {
+                address.saturating_sub(offset.unsigned_abs())
+            }
+        }
+        _ => {
+            panic!(
+                "UNWIND: Address size {} not supported.  Please report this as a bug.",
+                address_size_in_bytes
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ops::ControlFlow,
+        path::{Path, PathBuf},
+    };
+    use typed_path::TypedPath;
+
+    use super::{
+        discard_overlapping_functions, is_same_build, relocate_source_location,
+        significant_address_bits, strip_pointer_authentication_code,
+        unwind_program_counter_register, unwind_register, LoadSegment, PointerAuthentication,
+    };
+    use crate::{
+        architecture::arm::core::{
+            exception_handling::{ArmV6MExceptionHandler, ArmV7MExceptionHandler},
+            registers::{aarch32::AARCH32_CORE_REGSISTERS, cortex_m::CORTEX_M_CORE_REGISTERS},
+        },
+        debug::{
+            extract_name, function_die::FunctionDie, BacktraceFormatter, BacktracePathStyle,
+            ColumnType, DebugInfo, DebugRegister, DebugRegisters, DebugWarning, RegisterUnwindStep,
+            ReturnAddresses, StackFrame, StackUnwind, UnknownFunctionPlaceholder, UnwindOptions,
+            UnwindOutcome, UnwindRowDump, VariableCache, VariableLocation, VariableName,
+            VariableStorage,
+        },
+        test::{MockCore, MockMemory},
+        Core, CoreType, InstructionSet, MemoryInterface, RegisterId, RegisterRole, RegisterValue,
+    };
+
+    fn get_path_for_test_files(filename: &str) -> PathBuf {
+        let path = Path::new(filename);
+
+        let mut base_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        base_dir.push("tests");
+
+        base_dir.join(path)
+    }
+
+    fn debug_info(filename: &str) -> DebugInfo {
+        DebugInfo::from_file(get_path_for_test_files(filename)).unwrap()
+    }
+
+    /// The registers at a breakpoint in the `SVCall` exception handler of the `exceptions` fixture.
+    /// These are the same values as used in the `unwinding_in_exception_handler` test.
+    fn exception_handler_registers() -> DebugRegisters {
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ff9f, // R1
+            0x20000047, // R2
+            0x20000047, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001ffc0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffc0, // R13
+            0x0000042f, // R14
+            0x000001a4, // R15
+            0x2001ffc0, // MSP
+            0x00000000, // PSP
+            0x2100000b, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        DebugRegisters(values)
+    }
+
+    /// Cortex-M registers with the given program counter, stack pointer and link register.
+    /// The main stack pointer is the active stack pointer, and all other registers are zero, except for the Thumb bit of the XPSR.
+    fn cortex_m_registers(pc: u32, sp: u32, lr: u32) -> DebugRegisters {
+        let mut values = [0u32; 19];
+        values[13] = sp; // R13
+        values[14] = lr; // R14
+        values[15] = pc; // R15
+        values[16] = sp; // MSP
+        values[18] = 0x0100_0000; // XPSR
+
+        DebugRegisters(
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(id, value)| DebugRegister {
+                    dwarf_id: Some(id as u16),
+                    core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+                    value: Some(RegisterValue::U32(value)),
+                })
+                .collect(),
+        )
+    }
+
+    /// The stack memory matching [`exception_handler_registers`].
+    fn exception_handler_memory() -> MockMemory {
+        let mut memory = MockMemory::new();
+
+        memory.add_word_range(
+            0x2001_ffc0,
+            &[
+                0x2001ffc8, 0x0000018b, 0x2001fff0, 0xfffffff9, 0x00000001, 0x2001ffcf, 0x20000044,
+                0x20000044, 0x00000000, 0x0000017f, 0x00000180, 0x21000000, 0x2001fff8, 0x00000161,
+                0x00000000, 0x0000013d,
+            ],
+        );
+
+        memory
+    }
+
+    #[test]
+    fn unwinding_first_instruction_after_exception() {
+        let debug_info = debug_info("exceptions");
+
+        // Registers:
+        // R0        : 0x00000001
+        // R1        : 0x2001ffcf
+        // R2        : 0x20000044
+        // R3        : 0x20000044
+        // R4        : 0x00000000
+        // R5        : 0x00000000
+        // R6        : 0x00000000
+        // R7        : 0x2001fff0
+        // R8        : 0x00000000
+        // R9        : 0x00000000
+        // R10       : 0x00000000
+        // R11       : 0x00000000
+        // R12       : 0x00000000
+        // R13       : 0x2001ffd0
+        // R14       : 0xfffffff9
+        // R15       : 0x00000182
+        // MSP       : 0x2001ffd0
+        // PSP       : 0x00000000
+        // XPSR      : 0x2100000b
+        // EXTRA     : 0x00000000
+        // FPSCR     : 0x00000000
+
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ffcf, // R1
+            0x20000044, // R2
+            0x20000044, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001fff0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffd0, // R13
+            0xfffffff9, // R14
+            0x00000182, // R15
+            0x2001ffd0, // MSP
+            0x00000000, // PSP
+            0x2100000b, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let regs = DebugRegisters(values);
+
+        let expected_regs = regs.clone();
+
+        let mut mocked_mem = MockMemory::new();
+
+        // Stack:
+        // 0x2001ffd0 = 0x00000001
+        // 0x2001ffd4 = 0x2001ffcf
+        // 0x2001ffd8 = 0x20000044
+        // 0x2001ffdc = 0x20000044
+        // 0x2001ffe0 = 0x00000000
+        // 0x2001ffe4 = 0x0000017f
+        // 0x2001ffe8 = 0x00000180
+        // 0x2001ffec = 0x21000000
+        // 0x2001fff0 = 0x2001fff8
+        // 0x2001fff4 = 0x00000161
+        // 0x2001fff8 = 0x00000000
+        // 0x2001fffc = 0x0000013d
+
+        mocked_mem.add_word_range(
+            0x2001_ffd0,
+            &[
+                0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000, 0x0000017f, 0x00000180,
+                0x21000000, 0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
+            ],
+        );
+
+        let exception_handler = Box::new(ArmV6MExceptionHandler {});
+
+        let frames = debug_info
+            .unwind_impl(
+                regs,
+                &mut mocked_mem,
+                exception_handler,
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        let first_frame = &frames[0];
+
+        assert_eq!(first_frame.pc, RegisterValue::U32(0x00000182));
+
+        assert_eq!(
+            first_frame.function_name,
+            "__cortex_m_rt_SVCall_trampoline".to_string()
+        );
+
+        assert_eq!(first_frame.registers, expected_regs);
+
+        let next_frame = &frames[1];
+        assert_eq!(next_frame.function_name, "SVCall");
+        assert_eq!(next_frame.pc, RegisterValue::U32(0x00000182));
+
+        // Expected stack frame(s):
+        // Frame 0: __cortex_m_rt_SVCall_trampoline @ 0x00000182
+        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:22:1
+        //
+        // <--- A frame seems to be missing here, to indicate the exception entry
+        //
+        // Frame 1: __cortex_m_rt_main @ 0x00000180   (<--- This should be 0x17e)
+        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:19:5
+        // Frame 2: __cortex_m_rt_main_trampoline @ 0x00000160
+        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:11:1
+        // Frame 3: memmove @ 0x0000013c
+        // Frame 4: memmove @ 0x0000013c
+
+        // Registers in frame 1:
+        // R0        : 0x00000001
+        // R1        : 0x2001ffcf
+        // R2        : 0x20000044
+        // R3        : 0x20000044
+        // R4        : 0x00000000
+        // R5        : 0x00000000
+        // R6        : 0x00000000
+        // R7        : 0x2001fff0
+        // R8        : 0x00000000
+        // R9        : 0x00000000
+        // R10       : 0x00000000
+        // R11       : 0x00000000
+        // R12       : 0x00000000
+        // R13       : 0x2001fff0
+        // R14       : 0x0000017f
+        // R15       : 0x0000017e
+        // MSP       : 0x2001fff0
+        // PSP       : 0x00000000
+        // XPSR      : 0x21000000
+        // EXTRA     : 0x00000000
+        // XPSR      : 0x21000000
+    }
+
+    #[test]
+    fn unwinding_in_exception_handler() {
+        let debug_info = debug_info("exceptions");
+
+        // Registers:
+        // R0        : 0x00000001
+        // R1        : 0x2001ff9f
+        // R2        : 0x20000047
+        // R3        : 0x20000047
+        // R4        : 0x00000000
+        // R5        : 0x00000000
+        // R6        : 0x00000000
+        // R7        : 0x2001ffc0
+        // R8        : 0x00000000
+        // R9        : 0x00000000
+        // R10       : 0x00000000
+        // R11       : 0x00000000
+        // R12       : 0x00000000
+        // R13       : 0x2001ffc0
+        // R14       : 0x0000042f
+        // R15       : 0x000001a4
+        // MSP       : 0x2001ffc0
+        // PSP       : 0x00000000
+        // XPSR      : 0x2100000b
+        // EXTRA     : 0x00000000
+
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ff9f, // R1
+            0x20000047, // R2
+            0x20000047, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001ffc0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffc0, // R13
+            0x0000042f, // R14
+            0x000001a4, // R15
+            0x2001ffc0, // MSP
+            0x00000000, // PSP
+            0x2100000b, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let regs = DebugRegisters(values);
+
+        let mut dummy_mem = MockMemory::new();
+
+        // Stack:
+        // 0x2001ffc0 = 0x2001ffc8
+        // 0x2001ffc4 = 0x0000018b
+        // 0x2001ffc8 = 0x2001fff0
+        // 0x2001ffcc = 0xfffffff9
+        // 0x2001ffd0 = 0x00000001
+        // 0x2001ffd4 = 0x2001ffcf
+        // 0x2001ffd8 = 0x20000044
+        // 0x2001ffdc = 0x20000044
+        // 0x2001ffe0 = 0x00000000
+        // 0x2001ffe4 = 0x0000017f
+        // 0x2001ffe8 = 0x00000180
+        // 0x2001ffec = 0x21000000
+        // 0x2001fff0 = 0x2001fff8
+        // 0x2001fff4 = 0x00000161
+        // 0x2001fff8 = 0x00000000
+        // 0x2001fffc = 0x0000013d
+
+        dummy_mem.add_word_range(
+            0x2001_ffc0,
+            &[
+                0x2001ffc8, 0x0000018b, 0x2001fff0, 0xfffffff9, 0x00000001, 0x2001ffcf, 0x20000044,
+                0x20000044, 0x00000000, 0x0000017f, 0x00000180, 0x21000000, 0x2001fff8, 0x00000161,
+                0x00000000, 0x0000013d,
+            ],
+        );
+
+        let exception_handler = Box::new(ArmV6MExceptionHandler {});
+
+        let frames = debug_info
+            .unwind_impl(
+                regs,
+                &mut dummy_mem,
+                exception_handler,
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        assert_eq!(frames[0].pc, RegisterValue::U32(0x000001a4));
+
+        assert_eq!(
+            frames[1].function_name,
+            "__cortex_m_rt_SVCall_trampoline".to_string()
+        );
+
+        // Only the `SVCall` frame marks the entry into the exception handler.
+        assert_eq!(frames[2].function_name, "SVCall".to_string());
+        let exception_entries: Vec<bool> = frames
+            .iter()
+            .map(|frame| frame.is_exception_entry)
+            .collect();
+        assert_eq!(
+            exception_entries,
+            vec![false, false, true, false, false, false, false]
+        );
+
+        assert_eq!(frames[1].pc, RegisterValue::U32(0x0000018A)); // <-- This seems wrong, this is the instruction *after* the jump into the topmost frame
+
+        assert_eq!(
+            frames[1]
+                .registers
+                .get_frame_pointer()
+                .and_then(|r| r.value),
+            Some(RegisterValue::U32(0x2001ffc8))
+        );
+
+        let printed_backtrace = frames
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<String>>()
+            .join("");
+
+        insta::assert_snapshot!(printed_backtrace);
+    }
+
+    #[test]
+    fn unwinding_in_exception_trampoline() {
+        let debug_info = debug_info("exceptions");
+
+        // Registers:
+        // R0        : 0x00000001
+        // R1        : 0x2001ffcf
+        // R2        : 0x20000044
+        // R3        : 0x20000044
+        // R4        : 0x00000000
+        // R5        : 0x00000000
+        // R6        : 0x00000000
+        // R7        : 0x2001fff0
+        // R8        : 0x00000000
+        // R9        : 0x00000000
+        // R10       : 0x00000000
+        // R11       : 0x00000000
+        // R12       : 0x00000000
+        // R13       : 0x2001ffc8
+        // R14       : 0xfffffff9
+        // R15       : 0x00000184
+        // MSP       : 0x2001ffc8
+        // PSP       : 0x00000000
+        // XPSR      : 0x2100000b
+        // EXTRA     : 0x00000000
+        // FPSCR     : 0x00000000
+
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ffcf, // R1
+            0x20000044, // R2
+            0x20000044, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001fff0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffc8, // R13
+            0xfffffff9, // R14
+            0x00000184, // R15
+            0x2001ffc8, // MSP
+            0x00000000, // PSP
+            0x2100000b, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let regs = DebugRegisters(values);
+
+        let mut dummy_mem = MockMemory::new();
+
+        // Stack:
+        // 0x2001ffc8 = 0x2001fff0
+        // 0x2001ffcc = 0xfffffff9
+        // 0x2001ffd0 = 0x00000001
+        // 0x2001ffd4 = 0x2001ffcf
+        // 0x2001ffd8 = 0x20000044
+        // 0x2001ffdc = 0x20000044
+        // 0x2001ffe0 = 0x00000000
+        // 0x2001ffe4 = 0x0000017f
+        // 0x2001ffe8 = 0x00000180
+        // 0x2001ffec = 0x21000000
+        // 0x2001fff0 = 0x2001fff8
+        // 0x2001fff4 = 0x00000161
+        // 0x2001fff8 = 0x00000000
+        // 0x2001fffc = 0x0000013d
+
+        dummy_mem.add_word_range(
+            0x2001_ffc8,
+            &[
+                0x2001fff0, 0xfffffff9, 0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000,
+                0x0000017f, 0x00000180, 0x21000000, 0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
+            ],
+        );
+
+        let exception_handler = Box::new(ArmV6MExceptionHandler {});
+
+        let frames = debug_info
+            .unwind_impl(
+                regs,
+                &mut dummy_mem,
+                exception_handler,
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        assert_eq!(frames[1].function_name, "SVCall".to_string());
+        assert!(frames[1].is_exception_entry);
+        assert!(!frames[0].is_exception_entry);
+
+        let printed_backtrace = frames
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<String>>()
+            .join("");
+
+        insta::assert_snapshot!(printed_backtrace);
+    }
+
+    #[test]
+    fn unwinding_inlined() {
+        let debug_info = debug_info("inlined-functions");
+
+        // Registers:
+        // R0        : 0xfffffecc
+        // R1        : 0x00000001
+        // R2        : 0x00000000
+        // R3        : 0x40008140
+        // R4        : 0x000f4240
+        // R5        : 0xfffffec0
+        // R6        : 0x00000000
+        // R7        : 0x20003ff0
+        // R8        : 0x00000000
+        // R9        : 0x00000000
+        // R10       : 0x00000000
+        // R11       : 0x00000000
+        // R12       : 0x5000050c
+        // R13       : 0x20003ff0
+        // R14       : 0x00200000
+        // R15       : 0x000002e4
+        // MSP       : 0x20003ff0
+        // PSP       : 0x00000000
+        // XPSR      : 0x61000000
+        // EXTRA     : 0x00000000
+        // FPSCR     : 0x00000000
+
+        let values: Vec<_> = [
+            0xfffffecc, // R0
+            0x00000001, // R1
+            0x00000000, // R2
+            0x40008140, // R3
+            0x000f4240, // R4
+            0xfffffec0, // R5
+            0x00000000, // R6
+            0x20003ff0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x5000050c, // R12
+            0x20003ff0, // R13
+            0x00200000, // R14
+            0x000002e4, // R15
+            0x20003ff0, // MSP
+            0x00000000, // PSP
+            0x61000000, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let regs = DebugRegisters(values);
+
+        let mut dummy_mem = MockMemory::new();
+
+        // Stack:
+        // 0x20003ff0 = 0x20003ff8
+        // 0x20003ff4 = 0x00000161
+        // 0x20003ff8 = 0x00000000
+        // 0x20003ffc = 0x0000013d
+
+        dummy_mem.add_word_range(
+            0x2000_3ff0,
+            &[0x20003ff8, 0x00000161, 0x00000000, 0x0000013d],
+        );
+
+        let exception_handler = Box::new(ArmV7MExceptionHandler {});
+
+        let frames = debug_info
+            .unwind_impl(
+                regs,
+                &mut dummy_mem,
+                exception_handler,
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        let printed_backtrace = frames
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<String>>()
+            .join("");
+
+        insta::assert_snapshot!(printed_backtrace);
+    }
+
+    #[test]
+    fn unwinding_frames_only() {
+        let debug_info = debug_info("exceptions");
+
+        let frames = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    populate_variable_caches: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .frames;
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[0].function_name, "__cortex_m_rt_SVCall");
+        assert!(frames
+            .iter()
+            .all(|frame| frame.static_variables.is_none() && frame.local_variables.is_none()));
+
+        // The full unwind populates the variable caches for the same frames.
+        let frames = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        assert!(frames[0].static_variables.is_some());
+        assert!(frames[0].local_variables.is_some());
+    }
+
+    #[test]
+    fn unwinding_frames_only_max_frames() {
+        let debug_info = debug_info("exceptions");
+
+        let frames = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    populate_variable_caches: false,
+                    max_frames: Some(3),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .frames;
+
+        let function_names: Vec<_> = frames.iter().map(|f| f.function_name.as_str()).collect();
+        assert_eq!(
+            function_names,
+            [
+                "__cortex_m_rt_SVCall",
+                "__cortex_m_rt_SVCall_trampoline",
+                "SVCall"
+            ]
+        );
+    }
+
+    #[test]
+    fn unwinding_with_trace() {
+        let debug_info = debug_info("exceptions");
+
+        let mut unwind_trace = Vec::new();
+
+        debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    populate_variable_caches: false,
+                    unwind_trace: Some(&mut unwind_trace),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .frames;
+
+        // The CFA for the first frame is R7+8 = 0x2001ffc8
+        let first_frame_step = |register_name: &str| {
+            unwind_trace
+                .iter()
+                .find(|step| step.frame_index == 0 && step.register_name == register_name)
+                .cloned()
+                .unwrap()
+        };
+
+        assert_eq!(
+            first_frame_step("R13/SP"),
+            RegisterUnwindStep {
+                frame_index: 0,
+                register_name: "R13/SP".to_string(),
+                rule: "SP=CFA (dwarf Undefined)".to_string(),
+                value: Some(RegisterValue::U32(0x2001ffc8)),
+            }
+        );
+
+        assert_eq!(
+            first_frame_step("R15/PC"),
+            RegisterUnwindStep {
+                frame_index: 0,
+                register_name: "R15/PC".to_string(),
+                rule: "PC=(unwound LR & !0b1) (dwarf Undefined)".to_string(),
+                value: Some(RegisterValue::U32(0x0000018a)),
+            }
+        );
+    }
+
+    #[test]
+    fn unwinding_with_invalid_stack() {
+        let debug_info = debug_info("exceptions");
+
+        // The CFA of the first frame (0x2001ffc8) is outside of this range.
+        let stack_validity = |address: u64| (0x2000_0000..0x2001_0000).contains(&address);
+
+        let StackUnwind {
+            frames, outcome, ..
+        } = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    stack_validity: Some(&stack_validity),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].function_name, "__cortex_m_rt_SVCall");
+        assert_eq!(outcome, UnwindOutcome::StackCorrupt { cfa: 0x2001ffc8 });
+
+        // With a valid stack range, the unwind is not affected.
+        let stack_validity = |address: u64| (0x2000_0000..0x2004_0000).contains(&address);
+
+        let StackUnwind {
+            frames, outcome, ..
+        } = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    stack_validity: Some(&stack_validity),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(outcome, UnwindOutcome::Complete);
+    }
+
+    #[test]
+    fn type_names_of_modifier_chains() {
+        let debug_info = debug_info("c-type-modifiers");
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+
+        // Collect the reconstructed type names of all the global variables in the test source.
+        let mut type_names = std::collections::HashMap::new();
+        let mut entries = unit_info.unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() != gimli::DW_TAG_variable {
+                continue;
+            }
+            let Some(name) = entry.attr_value(gimli::DW_AT_name).unwrap() else {
+                continue;
+            };
+            let Some(gimli::AttributeValue::UnitRef(type_offset)) =
+                entry.attr_value(gimli::DW_AT_type).unwrap()
+            else {
+                continue;
+            };
+            type_names.insert(
+                extract_name(&debug_info, name),
+                unit_info.modified_type_name(type_offset),
+            );
+        }
+
+        for (variable_name, expected_type_name) in [
+            ("typedef_variable", "uint32_t"),
+            ("const_variable", "const uint32_t"),
+            ("volatile_variable", "volatile uint32_t"),
+            ("const_volatile_pointer", "const volatile uint32_t *"),
+            ("const_pointer_to_const", "const char *const"),
+            ("typedef_struct_variable", "point_t"),
+            ("typedef_struct_pointer", "const point_t *"),
+            ("pointer_to_pointer", "char **"),
+        ] {
+            assert_eq!(
+                type_names[variable_name].as_deref(),
+                Some(expected_type_name),
+                "Unexpected type name for `{variable_name}`"
+            );
+        }
+    }
+
+    #[test]
+    fn overlapping_non_inlined_functions() {
+        // The two functions in this binary were merged by the linker, so both function DIEs cover the same address.
+        let debug_info = debug_info("overlapping-functions");
+        let address = 0x400144;
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+
+        let mut functions = Vec::new();
+        let mut entries = unit_info.unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() == gimli::DW_TAG_subprogram
+                && entry.attr_value(gimli::DW_AT_low_pc).unwrap()
+                    == Some(gimli::AttributeValue::Addr(address))
+            {
+                functions.push(FunctionDie::new(entry.clone(), &unit_info).unwrap());
+            }
+        }
+        assert_eq!(functions.len(), 2);
+
+        discard_overlapping_functions(&mut functions, address);
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(
+            functions[0].function_name().as_deref(),
+            Some("second_function")
+        );
+
+        // Building the stack frames for this address must not panic, and results in a single frame.
+        let frames = debug_info
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                address,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
+            )
+            .unwrap();
+        assert_eq!(frames.len(), 1);
+    }
+
+    #[test]
+    fn mach_o_function_name() {
+        let debug_info = debug_info("mach-o-function");
+
+        assert_eq!(
+            debug_info.function_name(0x2, false).unwrap().as_deref(),
+            Some("mach_o_function")
+        );
+    }
+
+    #[test]
+    fn next_line_address() {
+        let debug_info = debug_info("exceptions");
+
+        // `__cortex_m_rt_SVCall` covers 0x18c..0x1a6, with lines 23, 24 and 26 of `main.rs`, and two inlined functions at 0x190 and 0x1a0.
+        assert_eq!(debug_info.next_line_address(0x194), Some(0x1a4));
+        assert_eq!(debug_info.next_line_address(0x196), Some(0x1a4));
+        assert_eq!(debug_info.next_line_address(0x18c), Some(0x194));
+
+        // Line 26 is the last line of the function.
+        assert_eq!(debug_info.next_line_address(0x1a4), None);
+    }
+
+    #[test]
+    fn comp_dir_override() {
+        let mut debug_info = debug_info("exceptions");
+
+        // Line 24 of `main.rs`, which is stored relative to the compilation directory.
+        let location = debug_info.get_source_location(0x196).unwrap();
+        assert_eq!(location.file.as_deref(), Some("main.rs"));
+        assert_eq!(
+            location.directory,
+            Some(
+                TypedPath::derive("/home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src")
+                    .to_path_buf()
+            )
+        );
+
+        debug_info.set_comp_dir_override(Some(TypedPath::derive("/work/exceptions").to_path_buf()));
+
+        let location = debug_info.get_source_location(0x196).unwrap();
+        assert_eq!(location.file.as_deref(), Some("main.rs"));
+        assert_eq!(
+            location.directory,
+            Some(TypedPath::derive("/work/exceptions/src").to_path_buf())
+        );
+
+        // The inlined defmt function at 0x190 uses an absolute path, which is left untouched.
+        let location = debug_info.get_source_location(0x192).unwrap();
+        assert_eq!(location.file.as_deref(), Some("mod.rs"));
+        assert_eq!(
+            location.directory,
+            Some(TypedPath::derive(
+                "/home/dominik/.cargo/registry/src/index.crates.io-6f17d22bba15001f/defmt-0.3.2/src/export"
+            ).to_path_buf())
+        );
+    }
+
+    #[test]
+    fn unwind_table_row() {
+        let debug_info = debug_info("exceptions");
+
+        // `__cortex_m_rt_SVCall` pushes r7 and lr, and then uses r7 as the frame pointer.
+        assert_eq!(
+            debug_info.unwind_table_row(0x194).unwrap(),
+            UnwindRowDump {
+                cie_offset: 0,
+                fde_range: 0x18c..0x1a6,
+                row_range: 0x190..0x1a6,
+                return_address_register: 14,
+                cfa_rule: "r7+8".to_string(),
+                register_rules: vec![(7, "c-8".to_string()), (14, "c-4".to_string())],
+            }
+        );
+
+        // No registers are saved at the entry of the function.
+        let entry_row = debug_info.unwind_table_row(0x18c).unwrap();
+        assert_eq!(entry_row.cfa_rule, "r13+0");
+        assert!(entry_row.register_rules.is_empty());
+    }
+
+    #[test]
+    fn unwind_table_row_merged() {
+        let mut debug_info = debug_info("entry-pc");
+        debug_info.merge(self::debug_info("exceptions"), 0x10_0000);
+
+        let row = debug_info.unwind_table_row(0x10_0194).unwrap();
+        assert_eq!(row.fde_range, 0x10_018c..0x10_01a6);
+        assert_eq!(row.row_range, 0x10_0190..0x10_01a6);
+        assert_eq!(row.cfa_rule, "r7+8");
+    }
+
+    #[test]
+    fn unwind_all_cores() {
+        let debug_info = debug_info("exceptions");
+
+        // The first core is halted inside `__cortex_m_rt_SVCall`.
+        let first_core =
+            MockCore::new(0, exception_handler_registers(), exception_handler_memory());
+
+        // The second core is halted at the first instruction of `__cortex_m_rt_SVCall_trampoline`.
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ffcf, // R1
+            0x20000044, // R2
+            0x20000044, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001fff0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffd0, // R13
+            0xfffffff9, // R14
+            0x00000182, // R15
+            0x2001ffd0, // MSP
+            0x00000000, // PSP
+            0x2100000b, // XPSR
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let mut memory = MockMemory::new();
+        memory.add_word_range(
+            0x2001_ffd0,
+            &[
+                0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000, 0x0000017f, 0x00000180,
+                0x21000000, 0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
+            ],
+        );
+        let second_core = MockCore::new(1, DebugRegisters(values), memory);
+
+        let mut cores = [Core::new(first_core), Core::new(second_core)];
+
+        let backtraces = debug_info.unwind_all(&mut cores).unwrap();
+
+        assert_eq!(backtraces.len(), 2);
+
+        let (first_id, first_frames) = &backtraces[0];
+        assert_eq!(*first_id, 0);
+        assert_eq!(first_frames[0].pc, RegisterValue::U32(0x000001a4));
+        assert_eq!(first_frames[2].function_name, "SVCall");
+
+        let (second_id, second_frames) = &backtraces[1];
+        assert_eq!(*second_id, 1);
+        assert_eq!(second_frames[0].pc, RegisterValue::U32(0x00000182));
+        assert_eq!(
+            second_frames[0].function_name,
+            "__cortex_m_rt_SVCall_trampoline"
+        );
+        assert_eq!(second_frames[1].function_name, "SVCall");
+    }
+
+    #[test]
+    fn entry_point_and_symbols() {
+        let debug_info = debug_info("exceptions");
+
+        // The entry point is the `Reset` function, with the Thumb bit set.
+        assert_eq!(debug_info.entry_point(), Some(0x101));
+        assert_eq!(debug_info.symbol_address("Reset"), Some(0x101));
+        assert_eq!(debug_info.symbol_address("main"), Some(0x159));
+
+        // Linker defined symbols don't have any debug information.
+        assert_eq!(debug_info.symbol_address("__reset_vector"), Some(0x8));
+
+        assert_eq!(debug_info.symbol_address("does_not_exist"), None);
+    }
+
+    #[test]
+    fn inlined_call_site_uses_entry_pc() {
+        // `checked_add` is inlined into `accumulate`, and its unlikely branch was moved to `accumulate.cold`,
+        // which is placed before `accumulate`. The `DW_AT_entry_pc` of the inlined function is
+        // 0x401038, while its lowest address is the start of the cold part at 0x401007.
+        let debug_info = debug_info("entry-pc");
+        let address = 0x40100c;
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+        let functions = unit_info.get_function_dies(address, None, true).unwrap();
+
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[1].function_name().as_deref(), Some("checked_add"));
+        assert_eq!(functions[1].low_pc, 0x401007);
+        assert_eq!(functions[1].entry_pc(), 0x401038);
+
+        // Functions without `DW_AT_entry_pc` are entered at `low_pc`.
+        assert_eq!(functions[0].entry_pc(), functions[0].low_pc);
+
+        let frames = debug_info
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                address,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].function_name, "accumulate");
+        assert_eq!(frames[0].pc, RegisterValue::from(0x401038u64));
+        assert_eq!(frames[1].function_name, "checked_add");
+    }
+
+    #[test]
+    fn stackframe_info_without_inlined_functions() {
+        let debug_info = debug_info("entry-pc");
+        let address = 0x40100c;
+
+        let frames_for = |find_inlined| {
+            debug_info
+                .get_stackframe_info(
+                    &mut MockMemory::new(),
+                    address,
+                    &DebugRegisters(vec![]),
+                    false,
+                    find_inlined,
+                    false,
+                )
+                .unwrap()
+        };
+
+        // `checked_add` is inlined into `accumulate`.
+        let with_inlined = frames_for(true);
+        assert_eq!(with_inlined.len(), 2);
+        assert!(with_inlined[1].is_inlined);
+
+        // Only the frame of the outermost function is created.
+        let without_inlined = frames_for(false);
+        assert_eq!(without_inlined.len(), 1);
+        assert_eq!(without_inlined[0].function_name, "accumulate");
+        assert!(!without_inlined[0].is_inlined);
+    }
+
+    #[test]
+    fn units_for_shared_file() {
+        let debug_info = debug_info("exceptions");
+
+        // The interrupt module of `cortex-m` is used by the application, `cortex-m` itself and `defmt-rtt`.
+        let path = TypedPath::derive(
+            "/home/dominik/.cargo/registry/src/index.crates.io-6f17d22bba15001f/cortex-m-0.7.7/src/interrupt.rs",
+        )
+        .to_path_buf();
+
+        let units = debug_info.units_for_file(&path);
+
+        let comp_dirs: Vec<_> = units.iter().map(|unit| unit.comp_dir.clone()).collect();
+        let expected_comp_dirs: Vec<_> = [
+            "/home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions",
+            "/home/dominik/.cargo/registry/src/index.crates.io-6f17d22bba15001f/cortex-m-0.7.7",
+            "/home/dominik/.cargo/registry/src/index.crates.io-6f17d22bba15001f/defmt-rtt-0.4.0",
+        ]
+        .into_iter()
+        .map(|dir| Some(TypedPath::derive(dir).to_path_buf()))
+        .collect();
+        assert_eq!(comp_dirs, expected_comp_dirs);
+        assert_eq!(
+            units[0].name.as_deref(),
+            Some("src/main.rs/@/exceptions.85c061dcf6c47f03-cgu.0")
+        );
+        assert_eq!(
+            units[0].producer.as_deref(),
+            Some("clang LLVM (rustc version 1.73.0 (cc66ad468 2023-10-03))")
+        );
+
+        let unknown_file = TypedPath::derive("/does/not/exist.rs").to_path_buf();
+        assert!(debug_info.units_for_file(&unknown_file).is_empty());
+    }
+
+    #[test]
+    fn registers_table_of_first_frame() {
+        let debug_info = debug_info("exceptions");
+
+        let frames = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        let filter = ["r0", "SP", "lr", "pc"].map(String::from);
+
+        assert_eq!(
+            frames[0].registers_table(&filter).to_string(),
+            "frame base: 0x2001ffc0\n\
+             R0  0x00000001  R13 0x2001ffc0  R14 0x0000042f  R15 0x000001a4\n"
+        );
+    }
+
+    #[test]
+    fn split_debug_information() {
+        let runnable = get_path_for_test_files("entry-pc.stripped");
+        let debug = get_path_for_test_files("entry-pc.debug");
+
+        // The stripped file alone has no debug information.
+        let stripped_debug_info = DebugInfo::from_file(&runnable).unwrap();
+        assert_eq!(
+            stripped_debug_info.function_name(0x401020, false).unwrap(),
+            None
+        );
+
+        let debug_info = DebugInfo::from_files(&runnable, &debug).unwrap();
+        assert_eq!(
+            debug_info
+                .function_name(0x401020, false)
+                .unwrap()
+                .as_deref(),
+            Some("accumulate")
+        );
+        assert_eq!(debug_info.entry_point(), Some(0x401060));
+
+        // The symbols are taken from the debug file, because the runnable file was stripped.
+        assert_eq!(debug_info.symbol_address("accumulate"), Some(0x401020));
+    }
+
+    #[test]
+    fn split_debug_information_build_check() {
+        let runnable = std::fs::read(get_path_for_test_files("entry-pc.stripped")).unwrap();
+        let debug = std::fs::read(get_path_for_test_files("entry-pc.debug")).unwrap();
+        let other = std::fs::read(get_path_for_test_files("c-type-modifiers")).unwrap();
+
+        let runnable = object::File::parse(&*runnable).unwrap();
+        let debug = object::File::parse(&*debug).unwrap();
+        let other = object::File::parse(&*other).unwrap();
+
+        assert!(is_same_build(&runnable, &debug));
+        assert!(!is_same_build(&runnable, &other));
+    }
+
+    #[test]
+    fn variables_in_scope_follow_lexical_blocks() {
+        // `scoped` declares `inner` in a block at 0x401019..0x40103e, and `innermost`
+        // in a nested block at 0x40102a..0x40103e.
+        let debug_info = debug_info("lexical-blocks");
+
+        let names_at = |address: u64| -> Vec<String> {
+            debug_info
+                .variables_in_scope(address)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        };
+
+        assert_eq!(names_at(0x401010), ["argument", "outer"]);
+        assert_eq!(names_at(0x401021), ["argument", "outer", "inner"]);
+        assert_eq!(
+            names_at(0x401035),
+            ["argument", "outer", "inner", "innermost"]
+        );
+        assert_eq!(names_at(0x401048), ["argument", "outer"]);
+        assert!(debug_info.variables_in_scope(0x10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unknown_function_placeholder() {
+        let placeholder = UnknownFunctionPlaceholder::default();
+        assert_eq!(
+            placeholder.format(0x1a4, 4),
+            "<unknown function @ 0x000001a4>"
+        );
+        assert_eq!(
+            placeholder.format(0x1a4, 8),
+            "<unknown function @ 0x00000000000001a4>"
+        );
+
+        assert_eq!(
+            UnknownFunctionPlaceholder::Address.format(0x1a4, 4),
+            "0x000001a4"
+        );
+        assert_eq!(
+            UnknownFunctionPlaceholder::Address.format(0x1a4, 8),
+            "0x00000000000001a4"
+        );
+        assert_eq!(
+            UnknownFunctionPlaceholder::QuestionMarks.format(0x1a4, 4),
+            "??"
+        );
+    }
+
+    #[test]
+    fn verify_valid_debug_info() {
+        assert_eq!(debug_info("exceptions").verify(), vec![]);
+    }
+
+    #[test]
+    fn verify_broken_function_range() {
+        // The `DW_AT_high_pc` of `broken` was patched to be lower than its `DW_AT_low_pc`.
+        // The x86 fixture has no `.debug_frame` section, its unwind information is in `.eh_frame`.
+        assert_eq!(
+            debug_info("broken-function-range").verify(),
+            vec![
+                DebugWarning::MissingFrameSection,
+                DebugWarning::InvalidFunctionRange {
+                    function: "broken".to_string(),
+                    low_pc: 0x40100f,
+                    high_pc: 0x401001,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn instruction_set_from_processor_status() {
+        // The T bit (24) is set in the XPSR of the exception handler registers.
+        let mut registers = exception_handler_registers();
+        let instruction_set = registers.instruction_set(CoreType::Armv7m);
+        assert_eq!(instruction_set, Some(InstructionSet::Thumb2));
+
+        // M-profile cores don't have an ARM state, so a cleared T bit is ignored.
+        registers
+            .get_register_mut_by_role(&RegisterRole::ProcessorStatus)
+            .unwrap()
+            .value = Some(RegisterValue::U32(0x2000_000b));
+        assert_eq!(
+            registers.instruction_set(CoreType::Armv7m),
+            Some(InstructionSet::Thumb2)
+        );
 
-    use crate::{
-        architecture::arm::core::{
-            exception_handling::{ArmV6MExceptionHandler, ArmV7MExceptionHandler},
-            registers::cortex_m::CORTEX_M_CORE_REGISTERS,
-        },
-        debug::{DebugInfo, DebugRegister, DebugRegisters},
-        test::MockMemory,
-        RegisterValue,
-    };
+        let mut rule = String::new();
+        assert_eq!(
+            unwind_program_counter_register(
+                RegisterValue::U32(0x42f),
+                instruction_set,
+                &mut PointerAuthentication::default(),
+                &mut rule
+            ),
+            Some(RegisterValue::U32(0x42e))
+        );
 
-    fn debug_info(filename: &str) -> DebugInfo {
-        let path = Path::new(filename);
+        // An A-profile core in ARM state, with the T bit (5) of the CPSR cleared.
+        let registers = DebugRegisters(
+            AARCH32_CORE_REGSISTERS
+                .all_registers()
+                .enumerate()
+                .map(|(id, core_register)| DebugRegister {
+                    dwarf_id: Some(id as u16),
+                    core_register,
+                    value: Some(RegisterValue::U32(
+                        if core_register.register_has_role(RegisterRole::ProcessorStatus) {
+                            0x600001d3
+                        } else {
+                            0
+                        },
+                    )),
+                })
+                .collect(),
+        );
+        let instruction_set = registers.instruction_set(CoreType::Armv7a);
+        assert_eq!(instruction_set, Some(InstructionSet::A32));
+
+        let mut rule = String::new();
+        assert_eq!(
+            unwind_program_counter_register(
+                RegisterValue::U32(0x8001),
+                instruction_set,
+                &mut PointerAuthentication::default(),
+                &mut rule
+            ),
+            Some(RegisterValue::U32(0x8001))
+        );
+    }
+
+    #[test]
+    fn unwinding_from_snapshot() {
+        let debug_info = debug_info("exceptions");
+
+        // The instruction set is taken from the XPSR of the snapshot.
+        let frames = debug_info
+            .unwind_from_snapshot(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                CoreType::Armv6m,
+                None,
+                UnwindOptions::new(),
+            )
+            .unwrap()
+            .frames;
+
+        let function_names: Vec<&str> = frames
+            .iter()
+            .map(|frame| frame.function_name.as_str())
+            .collect();
+
+        let expected_frames = debug_info
+            .unwind_impl(
+                exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+        let expected_function_names: Vec<&str> = expected_frames
+            .iter()
+            .map(|frame| frame.function_name.as_str())
+            .collect();
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(function_names[0], "__cortex_m_rt_SVCall");
+        assert_eq!(function_names[2], "SVCall");
+        assert_eq!(function_names, expected_function_names);
+    }
+
+    #[test]
+    fn unwinding_from_cold_part_of_split_function() {
+        // `accumulate` consists of the ranges 0x401020..0x401053, and 0x401007..0x401013 for its cold part.
+        let debug_info = debug_info("entry-pc");
+        let address = 0x401010;
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+        let functions = unit_info.get_function_dies(address, None, false).unwrap();
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].function_name().as_deref(), Some("accumulate"));
+        assert_eq!(
+            functions[0].ranges(),
+            vec![0x401020..0x401053, 0x401007..0x401013]
+        );
+        assert!(functions[0].contains_address(address));
+        assert!(functions[0].contains_address(0x401040));
+        assert!(!functions[0].contains_address(0x401013));
+
+        let frames = debug_info
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                address,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(frames[0].function_name, "accumulate");
+    }
+
+    #[test]
+    fn function_name_from_symbol_table() {
+        // `add_one` is written in assembly, and has no debug information.
+        let mut debug_info = debug_info("symbol-fallback");
+
+        assert_eq!(debug_info.function_name(0x40101b, false).unwrap(), None);
+        assert_eq!(debug_info.symbol_name(0x401019).as_deref(), Some("add_one"));
+        assert_eq!(
+            debug_info.symbol_name(0x40101b).as_deref(),
+            Some("add_one+0x2")
+        );
+        // The address after the end of the function.
+        assert_eq!(debug_info.symbol_name(0x40101f), None);
+
+        debug_info.set_symbol_fallback(true);
+        assert_eq!(
+            debug_info
+                .function_name(0x40101b, false)
+                .unwrap()
+                .as_deref(),
+            Some("add_one+0x2")
+        );
+        // Functions with debug information are still named from the DWARF data.
+        assert_eq!(
+            debug_info
+                .function_name(0x401004, false)
+                .unwrap()
+                .as_deref(),
+            Some("_start")
+        );
+    }
+
+    #[test]
+    fn unknown_function_frame_from_symbol_table() {
+        let mut debug_info = debug_info("symbol-fallback");
+
+        // The return address is zero, so the unwind stops after the frame for `add_one`.
+        let registers = cortex_m_registers(0x40101b, 0, 0);
+
+        let function_names = |debug_info: &DebugInfo| -> Vec<String> {
+            debug_info
+                .unwind_from_snapshot(
+                    registers.clone(),
+                    &mut MockMemory::new(),
+                    CoreType::Armv7m,
+                    None,
+                    UnwindOptions::new(),
+                )
+                .unwrap()
+                .frames
+                .into_iter()
+                .map(|frame| frame.function_name)
+                .collect()
+        };
+
+        assert_eq!(
+            function_names(&debug_info),
+            ["<unknown function @ 0x0040101b>"]
+        );
+
+        debug_info.set_symbol_fallback(true);
+        assert_eq!(function_names(&debug_info), ["add_one+0x2"]);
+    }
+
+    #[test]
+    fn unwinding_nested_exceptions() {
+        let debug_info = debug_info("exceptions");
+
+        // A `SysTick` exception interrupted the `SVCall` handler, at the same location as in the
+        // `unwinding_in_exception_trampoline` test, and is halted at the same location in its own trampoline.
+        let values: Vec<_> = [
+            0x00000001, // R0
+            0x2001ffcf, // R1
+            0x20000044, // R2
+            0x20000044, // R3
+            0x00000000, // R4
+            0x00000000, // R5
+            0x00000000, // R6
+            0x2001fff0, // R7
+            0x00000000, // R8
+            0x00000000, // R9
+            0x00000000, // R10
+            0x00000000, // R11
+            0x00000000, // R12
+            0x2001ffa0, // R13
+            0xfffffff1, // R14 (return to handler mode)
+            0x00000184, // R15
+            0x2001ffa0, // MSP
+            0x00000000, // PSP
+            0x2100000f, // XPSR (SysTick)
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, r)| DebugRegister {
+            dwarf_id: Some(id as u16),
+            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
+            value: Some(RegisterValue::U32(r)),
+        })
+        .collect();
+
+        let mut memory = MockMemory::new();
+        memory.add_word_range(
+            0x2001_ffa0,
+            &[
+                // Pushed by the `SysTick` trampoline.
+                0x2001fff0, 0xfffffff1,
+                // The exception frame of `SysTick`, stacked while `SVCall` was executing.
+                0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000, 0xfffffff9, 0x00000184,
+                0x2100000b, // Pushed by the `SVCall` trampoline.
+                0x2001fff0, 0xfffffff9,
+                // The exception frame of `SVCall`, stacked while `main` was executing.
+                0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000, 0x0000017f, 0x00000180,
+                0x21000000, // The stack of `main`.
+                0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
+            ],
+        );
+
+        let frames = debug_info
+            .unwind_impl(
+                DebugRegisters(values),
+                &mut memory,
+                Box::new(ArmV6MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        let exception_entries: Vec<&str> = frames
+            .iter()
+            .filter(|frame| frame.is_exception_entry)
+            .map(|frame| frame.function_name.as_str())
+            .collect();
+        assert_eq!(exception_entries, ["SysTick", "SVCall"]);
+
+        assert_eq!(frames[0].function_name, "__cortex_m_rt_SVCall_trampoline");
+        assert_eq!(frames[1].function_name, "SysTick");
+        assert_eq!(frames[2].function_name, "__cortex_m_rt_SVCall_trampoline");
+        assert_eq!(frames[3].function_name, "SVCall");
+        assert_eq!(frames[4].function_name, "__cortex_m_rt_main");
+
+        // The registers of the interrupted `SVCall` handler are restored from the first exception frame.
+        assert_eq!(frames[2].pc, RegisterValue::U32(0x184));
+        assert_eq!(
+            frames[2]
+                .registers
+                .get_stack_pointer()
+                .and_then(|register| register.value),
+            Some(RegisterValue::U32(0x2001ffc8))
+        );
+        assert_eq!(
+            frames[2]
+                .registers
+                .get_return_address()
+                .and_then(|register| register.value),
+            Some(RegisterValue::U32(0xfffffff9))
+        );
+    }
+
+    #[test]
+    fn merged_debug_info() {
+        let mut debug_info = debug_info("entry-pc");
+        debug_info.merge(self::debug_info("symbol-fallback"), 0x10_0000);
+
+        assert_eq!(
+            debug_info
+                .function_name(0x401040, false)
+                .unwrap()
+                .as_deref(),
+            Some("accumulate")
+        );
+        assert_eq!(
+            debug_info
+                .function_name(0x501004, false)
+                .unwrap()
+                .as_deref(),
+            Some("_start")
+        );
+
+        let location = debug_info.get_source_location(0x501004).unwrap();
+        assert_eq!(location.file.as_deref(), Some("symbol-fallback.c"));
+    }
+
+    #[test]
+    fn merged_debug_info_overlapping() {
+        // Both binaries start at 0x401000. The unit of `entry-pc` has the ranges 0x401000..0x401007,
+        // 0x401007..0x401013 and 0x401020..0x401073, and the unit of `symbol-fallback` the range 0x401000..0x401019.
+        let mut debug_info = debug_info("entry-pc");
+        debug_info.merge(self::debug_info("symbol-fallback"), 0);
+
+        // The range of `entry-pc` is smaller.
+        assert_eq!(
+            debug_info
+                .function_name(0x401004, false)
+                .unwrap()
+                .as_deref(),
+            Some("report")
+        );
+        // Only `symbol-fallback` contains the address.
+        assert_eq!(
+            debug_info
+                .function_name(0x401014, false)
+                .unwrap()
+                .as_deref(),
+            Some("_start")
+        );
+    }
+
+    #[test]
+    fn merged_debug_info_lines() {
+        let mut debug_info = debug_info("entry-pc");
+        debug_info.merge(self::debug_info("function-local-statics"), 0x10_0000);
+        let single = self::debug_info("function-local-statics");
+
+        let breakpoint = debug_info.breakpoint_for_function("next_id").unwrap();
+        let expected = single.breakpoint_for_function("next_id").unwrap();
+        assert_eq!(breakpoint.address, expected.address + 0x10_0000);
+        assert_eq!(
+            breakpoint.source_location,
+            relocate_source_location(expected.source_location, 0x10_0000)
+        );
+
+        // Both binaries have a `_start` function.
+        let error = debug_info
+            .breakpoint_for_function("_start")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("ambiguous"), "{error}");
+
+        assert_eq!(
+            debug_info.closest_line(0x501026),
+            single.closest_line(0x401026).map(|(location, distance)| (
+                relocate_source_location(location, 0x10_0000),
+                distance
+            ))
+        );
+
+        // The lines of the merged binary follow those of `entry-pc`.
+        let coverage = debug_info.line_coverage_map();
+        let expected_coverage: Vec<_> = single
+            .line_coverage_map()
+            .into_iter()
+            .map(|(location, range)| {
+                (
+                    relocate_source_location(location, 0x10_0000),
+                    range.start + 0x10_0000..range.end + 0x10_0000,
+                )
+            })
+            .collect();
+        assert!(coverage.ends_with(&expected_coverage));
+        assert_eq!(
+            coverage.len(),
+            self::debug_info("entry-pc").line_coverage_map().len() + expected_coverage.len()
+        );
+    }
+
+    #[test]
+    fn breakpoint_for_function() {
+        let debug_info = debug_info("inlined-functions");
+
+        for name in [
+            "__cortex_m_rt_main",
+            "_ZN17inlined_functions18__cortex_m_rt_main17h7fa6d4a359a0a98eE",
+            "inlined_functions::__cortex_m_rt_main",
+        ] {
+            let breakpoint = debug_info.breakpoint_for_function(name).unwrap();
+
+            // The function starts at 0x162, and the prologue ends at 0x166.
+            assert_eq!(breakpoint.address, 0x166, "breakpoint for {name}");
+            assert_eq!(breakpoint.source_location.line, Some(29));
+        }
+    }
+
+    #[test]
+    fn breakpoint_for_ambiguous_function() {
+        let debug_info = debug_info("inlined-functions");
+
+        let error = debug_info
+            .breakpoint_for_function("panic")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("rust_begin_unwind @ 0x0000031a"), "{error}");
+        assert!(
+            error.contains("core::panicking::panic @ 0x0000030c"),
+            "{error}"
+        );
+
+        assert!(debug_info
+            .breakpoint_for_function("does_not_exist")
+            .is_err());
+    }
+
+    #[test]
+    fn debug_info_from_mapped_file() {
+        let path = get_path_for_test_files("inlined-functions");
+        let read_debug_info = DebugInfo::from_file(&path).unwrap();
+        let mapped_debug_info = DebugInfo::from_file_mmap(&path).unwrap();
+
+        assert_eq!(
+            mapped_debug_info.symbol_address("main"),
+            read_debug_info.symbol_address("main")
+        );
+        assert_eq!(
+            mapped_debug_info.function_name(0x166, false).unwrap(),
+            read_debug_info.function_name(0x166, false).unwrap()
+        );
+        assert_eq!(
+            mapped_debug_info.get_source_location(0x166),
+            read_debug_info.get_source_location(0x166)
+        );
+
+        // The sections are not copied, but refer to the mapped file.
+        let mapping = mapped_debug_info.mapping.as_ref().unwrap();
+        let section = gimli::Section::reader(&mapped_debug_info.dwarf.debug_info).bytes();
+        assert!(!section.is_empty());
+        assert!(mapping.as_ptr_range().contains(&section.as_ptr()));
+        assert!(read_debug_info.mapping.is_none());
+    }
+
+    #[test]
+    fn active_enum_variant() {
+        let debug_info = debug_info("rust-enums");
+
+        // The `.data` and `.bss` sections, with the statics `MAYBE`, `SHAPE` and `NOTHING`.
+        let mut memory = MockMemory::new();
+        memory.add_word_range(
+            0x0020_2234,
+            &[
+                0x00000001, 0x00000007, 0x00030001, 0x00000004, 0x00000000, 0x00000000,
+            ],
+        );
+        let registers = exception_handler_registers();
+        let mut core = Core::new(MockCore::new(0, registers.clone(), memory));
+
+        let unit_info = debug_info
+            .get_next_unit_info(&mut debug_info.get_units())
+            .unwrap();
+        let mut cache = debug_info
+            .create_static_scope_cache(&mut core, &unit_info)
+            .unwrap();
+        let mut static_root = cache
+            .get_variable_by_name(&VariableName::StaticScopeRoot)
+            .unwrap();
+        debug_info
+            .cache_deferred_variables(&mut cache, &mut core, &mut static_root, &registers, None)
+            .unwrap();
+
+        let variant_name = |cache: &VariableCache, name: &str| {
+            cache
+                .get_variable_by_name(&VariableName::Named(name.to_string()))
+                .unwrap()
+                .variant_name
+        };
+
+        assert_eq!(variant_name(&cache, "MAYBE"), Some("Some".to_string()));
+        assert_eq!(variant_name(&cache, "NOTHING"), Some("None".to_string()));
+
+        // Custom enums are only resolved on demand.
+        let mut shape = cache
+            .get_variable_by_name(&VariableName::Named("SHAPE".to_string()))
+            .unwrap();
+        debug_info
+            .cache_deferred_variables(&mut cache, &mut core, &mut shape, &registers, None)
+            .unwrap();
+
+        assert_eq!(variant_name(&cache, "SHAPE"), Some("Rectangle".to_string()));
+
+        // Only the fields of the active variant are present.
+        let width = cache
+            .get_variable_by_name(&VariableName::Named("width".to_string()))
+            .unwrap();
+        assert_eq!(width.get_value(&cache), "3");
+        assert!(cache
+            .get_variable_by_name(&VariableName::Named("Circle".to_string()))
+            .is_none());
+    }
+
+    #[test]
+    fn line_coverage_map() {
+        let debug_info = debug_info("symbol-fallback");
+
+        let coverage: Vec<_> = debug_info
+            .line_coverage_map()
+            .into_iter()
+            .map(|(location, range)| {
+                assert_eq!(location.file.as_deref(), Some("symbol-fallback.c"));
+                assert_eq!(location.low_pc, Some(range.start as u32));
+                assert_eq!(location.high_pc, Some(range.end as u32));
+                (location.line.unwrap(), range)
+            })
+            .collect();
+
+        // The two rows for the columns of line 6 are merged.
+        assert_eq!(
+            coverage,
+            [
+                (5, 0x401000..0x401004),
+                (6, 0x401004..0x401017),
+                (7, 0x401017..0x401019),
+            ]
+        );
+    }
+
+    #[test]
+    fn unwinding_with_missing_cfa_register() {
+        let debug_info = debug_info("exceptions");
+
+        // Halted in `__cortex_m_rt_SVCall` after `push {r7, lr}`, where the CFA rule is `SP+8`.
+        // The frame pointer has the same value as the stack pointer, as after `mov r7, sp`.
+        let mut registers = exception_handler_registers();
+        registers.get_program_counter_mut().unwrap().value = Some(RegisterValue::U32(0x18e));
+
+        let unwind = |registers: DebugRegisters| {
+            debug_info
+                .unwind_impl(
+                    registers,
+                    &mut exception_handler_memory(),
+                    Box::new(ArmV6MExceptionHandler {}),
+                    Some(InstructionSet::Thumb2),
+                    UnwindOptions {
+                        populate_variable_caches: false,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+                .frames
+                .into_iter()
+                .map(|frame| frame.pc)
+                .collect::<Vec<_>>()
+        };
+
+        let expected_frames = unwind(registers.clone());
+        assert!(expected_frames.len() > 1);
+
+        // The same frames are found using the frame pointer, if the stack pointer is missing.
+        let mut without_sp = registers;
+        without_sp
+            .get_register_mut_by_role(&RegisterRole::StackPointer)
+            .unwrap()
+            .value = None;
+        assert_eq!(unwind(without_sp.clone()), expected_frames);
+
+        // Without the frame pointer, the unwind stops after the first frame.
+        without_sp
+            .get_register_mut_by_role(&RegisterRole::FramePointer)
+            .unwrap()
+            .value = None;
+        assert_eq!(unwind(without_sp), [RegisterValue::U32(0x18e)]);
+    }
+
+    #[test]
+    fn closest_line_in_padding() {
+        let debug_info = debug_info("entry-pc");
+
+        // The padding between the cold part of `accumulate` and `_start` is not covered by a sequence.
+        assert_eq!(debug_info.get_source_location(0x401016), None);
+
+        let (location, delta) = debug_info.closest_line(0x401016).unwrap();
+        assert_eq!(location.file.as_deref(), Some("entry-pc.c"));
+        assert_eq!(location.line, Some(9));
+        assert_eq!(delta, 8);
+
+        // Addresses inside a sequence use the row at or before the address.
+        let (location, delta) = debug_info.closest_line(0x401007).unwrap();
+        assert_eq!(location.line, Some(8));
+        assert_eq!(delta, 0);
+
+        // There is no code after the last sequence.
+        assert!(debug_info.closest_line(0x401080).is_none());
+    }
+
+    #[test]
+    fn source_location_with_non_utf8_file_name() {
+        let debug_info = debug_info("non-utf8-path");
+
+        // The file name contains the Latin-1 byte 0xe9, which is not valid UTF-8.
+        let location = debug_info.get_source_location(0x401004).unwrap();
+        assert_eq!(location.file.as_deref(), Some("caf\u{FFFD}.c"));
+        assert_eq!(location.line, Some(3));
+        assert!(location.directory.is_some());
+
+        // Valid UTF-8 paths are not affected.
+        let utf8_debug_info = debug_info("entry-pc");
+        let location = utf8_debug_info.get_source_location(0x401004).unwrap();
+        assert_eq!(location.file.as_deref(), Some("entry-pc.c"));
+    }
+
+    #[test]
+    fn function_parameters_with_types() {
+        let debug_info = debug_info("function-parameters");
+
+        let parameters_at = |address: u64, include_artificial: bool| {
+            debug_info
+                .function_parameters(address, include_artificial)
+                .unwrap()
+        };
+        let parameter = |name: &str, type_name: &str| (name.to_string(), type_name.to_string());
+
+        // `scale(uint32_t factor, const point *origin)`
+        assert_eq!(
+            parameters_at(0x401000, false),
+            [
+                parameter("factor", "uint32_t"),
+                parameter("origin", "const point *")
+            ]
+        );
+
+        // `ignore_first(int, long flags)`
+        assert_eq!(
+            parameters_at(0x401017, false),
+            [
+                parameter("<unnamed>", "int"),
+                parameter("flags", "long int")
+            ]
+        );
+
+        // `counter::add(int amount)` has an implicit `this` parameter.
+        assert_eq!(parameters_at(0x401086, false), [parameter("amount", "int")]);
+        assert_eq!(
+            parameters_at(0x401086, true),
+            [
+                parameter("this", "counter *const"),
+                parameter("amount", "int")
+            ]
+        );
+    }
+
+    #[test]
+    fn backtrace_formatter_variants() {
+        let debug_info = debug_info("entry-pc");
+
+        // `checked_add` is inlined into `accumulate`.
+        let frames = debug_info
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                0x40100c,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
+            )
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+
+        // The default output matches the `Display` implementation of the frames.
+        let displayed: String = frames.iter().map(ToString::to_string).collect();
+        assert_eq!(BacktraceFormatter::new().format(&frames), displayed);
+
+        let basename = BacktraceFormatter::new()
+            .path_style(BacktracePathStyle::FileName)
+            .format(&frames);
+        let locations: Vec<&str> = basename
+            .lines()
+            .filter_map(|line| line.strip_prefix('\t'))
+            .collect();
+        assert_eq!(locations.len(), 2);
+        assert!(locations
+            .iter()
+            .all(|location| location.starts_with("entry-pc.c:")));
+
+        let without_inlined = BacktraceFormatter::new()
+            .include_inlined(false)
+            .format(&frames);
+        let function_names: Vec<&str> = without_inlined
+            .lines()
+            .filter_map(|line| line.strip_prefix("Frame: "))
+            .collect();
+        assert_eq!(function_names, ["accumulate"]);
+
+        let limited = BacktraceFormatter::new()
+            .max_depth(1)
+            .show_addresses(true)
+            .format(&frames);
+        assert_eq!(
+            limited
+                .lines()
+                .filter(|line| line.starts_with("Frame: "))
+                .count(),
+            1
+        );
+        assert!(limited.contains(" @ 0x"));
+    }
+
+    #[test]
+    fn topmost_frame_uses_statement_location() {
+        let debug_info = debug_info("inlined-functions");
+
+        // 0x1d6 is after a non-statement row with line 0, inside the statement starting at 0x1c4 on line 1618.
+        let pc = 0x1d6;
+        assert_eq!(debug_info.get_source_location(pc).unwrap().line, Some(0));
+
+        // The LR is 0, so the unwind ends after the topmost frame.
+        let registers = cortex_m_registers(pc as u32, 0x2000_3ff0, 0);
+        let frames = debug_info
+            .unwind_impl(
+                registers,
+                &mut MockMemory::new(),
+                Box::new(ArmV7MExceptionHandler {}),
+                Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions::default(),
+            )
+            .unwrap()
+            .frames;
+
+        let location = frames[0].source_location.as_ref().unwrap();
+        assert_eq!(location.file.as_deref(), Some("mod.rs"));
+        assert_eq!(location.line, Some(1618));
+        assert_eq!(location.column, Some(ColumnType::Column(9)));
+    }
+
+    #[test]
+    fn caller_pc_of_first_frame() {
+        let debug_info = debug_info("exceptions");
+
+        // The return address 0x18b is stored on the stack, and the Thumb bit is cleared.
+        let caller_pc = debug_info
+            .caller_pc(
+                &exception_handler_registers(),
+                &mut exception_handler_memory(),
+                Some(InstructionSet::Thumb2),
+            )
+            .unwrap();
+        assert_eq!(caller_pc, Some(0x18a));
+
+        // A return address with the reset value marks the bottom of the stack.
+        let mut registers = exception_handler_registers();
+        registers
+            .get_register_mut_by_role(&RegisterRole::ReturnAddress)
+            .unwrap()
+            .value = Some(RegisterValue::U32(0xffff_ffff));
+        let caller_pc = debug_info
+            .caller_pc(
+                &registers,
+                &mut exception_handler_memory(),
+                Some(InstructionSet::Thumb2),
+            )
+            .unwrap();
+        assert_eq!(caller_pc, None);
+    }
 
-        let mut base_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        base_dir.push("tests");
+    #[test]
+    fn caller_pc_with_load_address() {
+        let mut debug_info = debug_info("exceptions");
+
+        // Execute the code from 0x1000_0100 instead of the address it is linked for.
+        debug_info.load_segments.push(LoadSegment {
+            virtual_address: 0x100,
+            physical_address: 0x1000_0100,
+            size: 0x698,
+        });
+        debug_info.set_translate_load_addresses(true);
 
-        let path = base_dir.join(path);
+        let mut registers = exception_handler_registers();
+        registers.get_program_counter_mut().unwrap().value = Some(RegisterValue::U32(0x1000_01a4));
+        let mut memory = exception_handler_memory();
+        memory.write_word_32(0x2001_ffc4, 0x1000_018b).unwrap();
 
-        DebugInfo::from_file(path).unwrap()
+        let caller_pc = debug_info
+            .caller_pc(&registers, &mut memory, Some(InstructionSet::Thumb2))
+            .unwrap();
+        assert_eq!(caller_pc, Some(0x18a));
     }
 
     #[test]
-    fn unwinding_first_instruction_after_exception() {
-        let debug_info = debug_info("exceptions");
+    fn pointer_authentication_code_is_stripped() {
+        // A return address signed with `paciasp` on a target with 39 bit virtual addresses,
+        // with the PAC in bits 39..=54 and 56..=63.
+        let signed_return_address = 0x002d_0a00_4008_1234;
+        let mut pointer_authentication = PointerAuthentication {
+            virtual_address_bits: Some(39),
+            stripped: false,
+        };
 
-        // Registers:
-        // R0        : 0x00000001
-        // R1        : 0x2001ffcf
-        // R2        : 0x20000044
-        // R3        : 0x20000044
-        // R4        : 0x00000000
-        // R5        : 0x00000000
-        // R6        : 0x00000000
-        // R7        : 0x2001fff0
-        // R8        : 0x00000000
-        // R9        : 0x00000000
-        // R10       : 0x00000000
-        // R11       : 0x00000000
-        // R12       : 0x00000000
-        // R13       : 0x2001ffd0
-        // R14       : 0xfffffff9
-        // R15       : 0x00000182
-        // MSP       : 0x2001ffd0
-        // PSP       : 0x00000000
-        // XPSR      : 0x2100000b
-        // EXTRA     : 0x00000000
-        // FPSCR     : 0x00000000
+        let mut rule = String::new();
+        assert_eq!(
+            unwind_program_counter_register(
+                RegisterValue::U64(signed_return_address),
+                Some(InstructionSet::A64),
+                &mut pointer_authentication,
+                &mut rule
+            ),
+            Some(RegisterValue::U64(0x4008_1234))
+        );
+        assert_eq!(rule, "PC=(unwound LR without PAC) (dwarf Undefined)");
+        assert!(pointer_authentication.stripped);
 
-        let values: Vec<_> = [
-            0x00000001, // R0
-            0x2001ffcf, // R1
-            0x20000044, // R2
-            0x20000044, // R3
-            0x00000000, // R4
-            0x00000000, // R5
-            0x00000000, // R6
-            0x2001fff0, // R7
-            0x00000000, // R8
-            0x00000000, // R9
-            0x00000000, // R10
-            0x00000000, // R11
-            0x00000000, // R12
-            0x2001ffd0, // R13
-            0xfffffff9, // R14
-            0x00000182, // R15
-            0x2001ffd0, // MSP
-            0x00000000, // PSP
-            0x2100000b, // XPSR
-        ]
-        .into_iter()
-        .enumerate()
-        .map(|(id, r)| DebugRegister {
-            dwarf_id: Some(id as u16),
-            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
-            value: Some(RegisterValue::U32(r)),
-        })
-        .collect();
+        // Assuming 48 bit virtual addresses would leave part of the PAC in the address.
+        assert_eq!(
+            strip_pointer_authentication_code(signed_return_address, 48),
+            0x0a00_4008_1234
+        );
 
-        let regs = DebugRegisters(values);
+        // Addresses in the upper range keep their upper bits set.
+        assert_eq!(
+            strip_pointer_authentication_code(0x5a80_ffff_8000_1000, 39),
+            0xffff_ffff_8000_1000
+        );
 
-        let expected_regs = regs.clone();
+        // Unsigned addresses are not changed.
+        assert_eq!(
+            strip_pointer_authentication_code(0x4008_1234, 39),
+            0x4008_1234
+        );
+        assert_eq!(
+            strip_pointer_authentication_code(0xffff_ff80_0000_1000, 39),
+            0xffff_ff80_0000_1000
+        );
 
-        let mut mocked_mem = MockMemory::new();
+        // Without a virtual address size, e.g. for other instruction sets, return addresses are never signed.
+        let mut pointer_authentication = PointerAuthentication::default();
+        let mut rule = String::new();
+        assert_eq!(
+            unwind_program_counter_register(
+                RegisterValue::U64(signed_return_address),
+                None,
+                &mut pointer_authentication,
+                &mut rule
+            ),
+            Some(RegisterValue::U64(signed_return_address))
+        );
+        assert!(rule.is_empty());
+        assert!(!pointer_authentication.stripped);
+    }
 
-        // Stack:
-        // 0x2001ffd0 = 0x00000001
-        // 0x2001ffd4 = 0x2001ffcf
-        // 0x2001ffd8 = 0x20000044
-        // 0x2001ffdc = 0x20000044
-        // 0x2001ffe0 = 0x00000000
-        // 0x2001ffe4 = 0x0000017f
-        // 0x2001ffe8 = 0x00000180
-        // 0x2001ffec = 0x21000000
-        // 0x2001fff0 = 0x2001fff8
-        // 0x2001fff4 = 0x00000161
-        // 0x2001fff8 = 0x00000000
-        // 0x2001fffc = 0x0000013d
+    #[test]
+    fn pointer_authentication_virtual_address_size() {
+        assert_eq!(significant_address_bits(0x4008_1234), 31);
+        assert_eq!(significant_address_bits(0xffff_ff80_0000_1000), 39);
 
-        mocked_mem.add_word_range(
-            0x2001_ffd0,
-            &[
-                0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000, 0x0000017f, 0x00000180,
-                0x21000000, 0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
-            ],
+        // The highest address of the `entry-pc` fixture is the end of `.bss` at 0x403007.
+        let debug_info = debug_info("entry-pc");
+        assert_eq!(
+            debug_info.pointer_authentication(Some(InstructionSet::A64), None),
+            PointerAuthentication {
+                virtual_address_bits: Some(23),
+                stripped: false,
+            }
         );
 
-        let exception_handler = Box::new(ArmV6MExceptionHandler {});
+        // A size read from `TCR_EL1` takes precedence, within the architectural limits.
+        assert_eq!(
+            debug_info
+                .pointer_authentication(Some(InstructionSet::A64), Some(64))
+                .virtual_address_bits,
+            Some(52)
+        );
 
-        let frames = debug_info
-            .unwind_impl(
-                regs,
-                &mut mocked_mem,
-                exception_handler,
-                Some(probe_rs_target::InstructionSet::Thumb2),
-            )
-            .unwrap();
+        // Only A64 return addresses are signed.
+        assert_eq!(
+            debug_info.pointer_authentication(Some(InstructionSet::Thumb2), None),
+            PointerAuthentication::default()
+        );
+    }
 
-        let first_frame = &frames[0];
+    #[test]
+    fn statement_range_of_statement_location() {
+        let debug_info = debug_info("inlined-functions");
 
-        assert_eq!(first_frame.pc, RegisterValue::U32(0x00000182));
+        // The statement on line 1618 starts at 0x1c4, and the next statement starts at 0x2bc.
+        let location = debug_info.statement_location(0x1d6).unwrap();
+        assert_eq!(location.line, Some(1618));
+        assert_eq!(location.statement_range, Some(0x1c4..0x2bc));
 
-        assert_eq!(
-            first_frame.function_name,
-            "__cortex_m_rt_SVCall_trampoline".to_string()
-        );
+        // The low and high PC cover the whole sequence.
+        assert_eq!(location.low_pc, Some(0x162));
+        assert_eq!(location.high_pc, Some(0x302));
 
-        assert_eq!(first_frame.registers, expected_regs);
+        // The last statement of a sequence ends with the sequence.
+        let location = debug_info.statement_location(0x2fe).unwrap();
+        assert_eq!(location.statement_range, Some(0x2fc..0x302));
+    }
 
-        let next_frame = &frames[1];
-        assert_eq!(next_frame.function_name, "SVCall");
-        assert_eq!(next_frame.pc, RegisterValue::U32(0x00000182));
+    #[test]
+    fn return_value_location_of_functions() {
+        let debug_info = debug_info("function-parameters");
+        let registers = exception_handler_registers();
+
+        let return_value_at = |address: u64| {
+            debug_info
+                .return_value_location(address, &registers)
+                .unwrap()
+        };
 
-        // Expected stack frame(s):
-        // Frame 0: __cortex_m_rt_SVCall_trampoline @ 0x00000182
-        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:22:1
-        //
-        // <--- A frame seems to be missing here, to indicate the exception entry
-        //
-        // Frame 1: __cortex_m_rt_main @ 0x00000180   (<--- This should be 0x17e)
-        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:19:5
-        // Frame 2: __cortex_m_rt_main_trampoline @ 0x00000160
-        //        /home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src/main.rs:11:1
-        // Frame 3: memmove @ 0x0000013c
-        // Frame 4: memmove @ 0x0000013c
+        // `int scale(uint32_t factor, const point *origin)` returns its value in R0.
+        assert_eq!(
+            return_value_at(0x401000),
+            Some(VariableStorage::Registers(vec![RegisterId(0)]))
+        );
 
-        // Registers in frame 1:
-        // R0        : 0x00000001
-        // R1        : 0x2001ffcf
-        // R2        : 0x20000044
-        // R3        : 0x20000044
-        // R4        : 0x00000000
-        // R5        : 0x00000000
-        // R6        : 0x00000000
-        // R7        : 0x2001fff0
-        // R8        : 0x00000000
-        // R9        : 0x00000000
-        // R10       : 0x00000000
-        // R11       : 0x00000000
-        // R12       : 0x00000000
-        // R13       : 0x2001fff0
-        // R14       : 0x0000017f
-        // R15       : 0x0000017e
-        // MSP       : 0x2001fff0
-        // PSP       : 0x00000000
-        // XPSR      : 0x21000000
-        // EXTRA     : 0x00000000
-        // XPSR      : 0x21000000
+        // `void counter::add(int amount)` has no return value.
+        assert_eq!(return_value_at(0x401086), None);
+
+        // There is no function at this address.
+        assert_eq!(return_value_at(0x10), None);
     }
 
     #[test]
-    fn unwinding_in_exception_handler() {
+    fn unwinding_stopped_by_frame_callback() {
         let debug_info = debug_info("exceptions");
 
-        // Registers:
-        // R0        : 0x00000001
-        // R1        : 0x2001ff9f
-        // R2        : 0x20000047
-        // R3        : 0x20000047
-        // R4        : 0x00000000
-        // R5        : 0x00000000
-        // R6        : 0x00000000
-        // R7        : 0x2001ffc0
-        // R8        : 0x00000000
-        // R9        : 0x00000000
-        // R10       : 0x00000000
-        // R11       : 0x00000000
-        // R12       : 0x00000000
-        // R13       : 0x2001ffc0
-        // R14       : 0x0000042f
-        // R15       : 0x000001a4
-        // MSP       : 0x2001ffc0
-        // PSP       : 0x00000000
-        // XPSR      : 0x2100000b
-        // EXTRA     : 0x00000000
+        let unwind = |on_frame: Option<&mut dyn FnMut(&StackFrame) -> ControlFlow<()>>| {
+            debug_info
+                .unwind_impl(
+                    exception_handler_registers(),
+                    &mut exception_handler_memory(),
+                    Box::new(ArmV6MExceptionHandler {}),
+                    Some(probe_rs_target::InstructionSet::Thumb2),
+                    UnwindOptions {
+                        on_frame,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+                .frames
+        };
+        let all_frames = unwind(None);
+        assert!(all_frames.len() > 2);
+
+        // Every frame is reported, in the order it was unwound.
+        let mut reported = Vec::new();
+        let frames = unwind(Some(&mut |frame: &StackFrame| {
+            reported.push(frame.function_name.clone());
+            ControlFlow::Continue(())
+        }));
+        assert_eq!(frames.len(), all_frames.len());
+        assert_eq!(
+            reported,
+            all_frames
+                .iter()
+                .map(|frame| frame.function_name.clone())
+                .collect::<Vec<_>>()
+        );
 
-        let values: Vec<_> = [
-            0x00000001, // R0
-            0x2001ff9f, // R1
-            0x20000047, // R2
-            0x20000047, // R3
-            0x00000000, // R4
-            0x00000000, // R5
-            0x00000000, // R6
-            0x2001ffc0, // R7
-            0x00000000, // R8
-            0x00000000, // R9
-            0x00000000, // R10
-            0x00000000, // R11
-            0x00000000, // R12
-            0x2001ffc0, // R13
-            0x0000042f, // R14
-            0x000001a4, // R15
-            0x2001ffc0, // MSP
-            0x00000000, // PSP
-            0x2100000b, // XPSR
-        ]
-        .into_iter()
-        .enumerate()
-        .map(|(id, r)| DebugRegister {
-            dwarf_id: Some(id as u16),
-            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
-            value: Some(RegisterValue::U32(r)),
-        })
-        .collect();
+        // The unwind stops after the second frame.
+        let mut reported = 0;
+        let frames = unwind(Some(&mut |_: &StackFrame| {
+            reported += 1;
+            if reported == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }));
+        assert_eq!(reported, 2);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].function_name, all_frames[1].function_name);
+    }
 
-        let regs = DebugRegisters(values);
+    #[test]
+    fn inlined_call_arguments() {
+        let debug_info = debug_info("call-site-arguments");
+
+        // `scaled_report(value, 3)` is inlined into `_start`, and calls `report(value + 1, factor)`.
+        let address = 0x401013;
+        let frames_for = |find_call_site_arguments| {
+            debug_info
+                .get_stackframe_info(
+                    &mut MockMemory::new(),
+                    address,
+                    &DebugRegisters(vec![]),
+                    false,
+                    true,
+                    find_call_site_arguments,
+                )
+                .unwrap()
+        };
 
-        let mut dummy_mem = MockMemory::new();
+        let frames = frames_for(true);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].function_name, "_start");
+        assert_eq!(frames[0].call_site_arguments, None);
 
-        // Stack:
-        // 0x2001ffc0 = 0x2001ffc8
-        // 0x2001ffc4 = 0x0000018b
-        // 0x2001ffc8 = 0x2001fff0
-        // 0x2001ffcc = 0xfffffff9
-        // 0x2001ffd0 = 0x00000001
-        // 0x2001ffd4 = 0x2001ffcf
-        // 0x2001ffd8 = 0x20000044
-        // 0x2001ffdc = 0x20000044
-        // 0x2001ffe0 = 0x00000000
-        // 0x2001ffe4 = 0x0000017f
-        // 0x2001ffe8 = 0x00000180
-        // 0x2001ffec = 0x21000000
-        // 0x2001fff0 = 0x2001fff8
-        // 0x2001fff4 = 0x00000161
-        // 0x2001fff8 = 0x00000000
-        // 0x2001fffc = 0x0000013d
+        // Only the constant argument is described by a call site parameter.
+        assert_eq!(frames[1].function_name, "scaled_report");
+        assert_eq!(
+            frames[1].call_site_arguments,
+            Some(vec![("scale".to_string(), 3)])
+        );
 
-        dummy_mem.add_word_range(
-            0x2001_ffc0,
-            &[
-                0x2001ffc8, 0x0000018b, 0x2001fff0, 0xfffffff9, 0x00000001, 0x2001ffcf, 0x20000044,
-                0x20000044, 0x00000000, 0x0000017f, 0x00000180, 0x21000000, 0x2001fff8, 0x00000161,
-                0x00000000, 0x0000013d,
-            ],
+        // The arguments are only recovered on request.
+        assert!(frames_for(false)
+            .iter()
+            .all(|frame| frame.call_site_arguments.is_none()));
+    }
+
+    #[test]
+    fn zero_length_function_range() {
+        // `first_function` was patched to have an empty range at the start of `second_function`.
+        let debug_info = debug_info("zero-length-function");
+        let address = 0x400144;
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+        let functions = unit_info.get_function_dies(address, None, true).unwrap();
+        assert_eq!(functions.len(), 1);
+        assert_eq!(
+            functions[0].function_name().as_deref(),
+            Some("second_function")
         );
+        assert_eq!(functions[0].ranges(), vec![0x400144..0x400149]);
 
-        let exception_handler = Box::new(ArmV6MExceptionHandler {});
-
+        // The frame for the address is attributed to the function which actually contains it.
         let frames = debug_info
-            .unwind_impl(
-                regs,
-                &mut dummy_mem,
-                exception_handler,
-                Some(probe_rs_target::InstructionSet::Thumb2),
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                address,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
             )
             .unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].function_name, "second_function");
+
+        // A function without code cannot be used for a breakpoint.
+        assert!(debug_info
+            .breakpoint_for_function("first_function")
+            .is_err());
+        assert!(debug_info
+            .breakpoint_for_function("second_function")
+            .is_ok());
+    }
 
-        assert_eq!(frames[0].pc, RegisterValue::U32(0x000001a4));
+    #[test]
+    fn relative_source_paths() {
+        let mut debug_info = debug_info("exceptions");
 
+        // Line 24 of `main.rs`, which is stored relative to the compilation directory.
+        let location = debug_info.get_source_location(0x196).unwrap();
+        assert_eq!(location.file.as_deref(), Some("main.rs"));
         assert_eq!(
-            frames[1].function_name,
-            "__cortex_m_rt_SVCall_trampoline".to_string()
+            location.directory,
+            Some(
+                TypedPath::derive("/home/dominik/code/probe-rs/probe-rs-repro/nrf/exceptions/src")
+                    .to_path_buf()
+            )
         );
 
-        assert_eq!(frames[1].pc, RegisterValue::U32(0x0000018A)); // <-- This seems wrong, this is the instruction *after* the jump into the topmost frame
+        debug_info.set_resolve_relative_paths(false);
 
+        let location = debug_info.get_source_location(0x196).unwrap();
+        assert_eq!(location.file.as_deref(), Some("main.rs"));
         assert_eq!(
-            frames[1]
-                .registers
-                .get_frame_pointer()
-                .and_then(|r| r.value),
-            Some(RegisterValue::U32(0x2001ffc8))
+            location.directory,
+            Some(TypedPath::derive("src").to_path_buf())
         );
+    }
 
-        let printed_backtrace = frames
-            .into_iter()
-            .map(|f| f.to_string())
-            .collect::<Vec<String>>()
-            .join("");
+    #[test]
+    fn function_local_statics() {
+        let debug_info = debug_info("function-local-statics");
 
-        insta::assert_snapshot!(printed_backtrace);
+        // After the `if` block of `next_id`, which declares the static `resets`.
+        let pc: u32 = 0x401026;
+        let registers = cortex_m_registers(pc, 0, 0);
+
+        // The `.data` and `.bss` sections, with `id` and `resets`.
+        let mut memory = MockMemory::new();
+        memory.add_word_range(0x403000, &[7, 2]);
+        let mut core = Core::new(MockCore::new(0, registers.clone(), memory));
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+        let functions = unit_info.get_function_dies(pc.into(), None, false).unwrap();
+        assert_eq!(functions[0].function_name().as_deref(), Some("next_id"));
+
+        let mut cache = debug_info
+            .create_function_scope_cache(&mut core, &functions[0], &unit_info)
+            .unwrap();
+        let mut function_root = cache
+            .get_variable_by_name(&VariableName::LocalScopeRoot)
+            .unwrap();
+        debug_info
+            .cache_deferred_variables(&mut cache, &mut core, &mut function_root, &registers, None)
+            .unwrap();
+
+        for (name, address, value) in [("id", 0x403000, "7"), ("resets", 0x403004, "2")] {
+            let variable = cache
+                .get_variable_by_name(&VariableName::Named(name.to_string()))
+                .unwrap_or_else(|| panic!("Missing function-local static `{name}`"));
+            assert_eq!(variable.memory_location, VariableLocation::Address(address));
+            assert_eq!(variable.get_value(&cache), value);
+        }
     }
 
     #[test]
-    fn unwinding_in_exception_trampoline() {
-        let debug_info = debug_info("exceptions");
+    fn merged_debug_info_frame_variables() {
+        let mut debug_info = debug_info("entry-pc");
+        debug_info.merge(self::debug_info("function-local-statics"), 0x10_0000);
 
-        // Registers:
-        // R0        : 0x00000001
-        // R1        : 0x2001ffcf
-        // R2        : 0x20000044
-        // R3        : 0x20000044
-        // R4        : 0x00000000
-        // R5        : 0x00000000
-        // R6        : 0x00000000
-        // R7        : 0x2001fff0
-        // R8        : 0x00000000
-        // R9        : 0x00000000
-        // R10       : 0x00000000
-        // R11       : 0x00000000
-        // R12       : 0x00000000
-        // R13       : 0x2001ffc8
-        // R14       : 0xfffffff9
-        // R15       : 0x00000184
-        // MSP       : 0x2001ffc8
-        // PSP       : 0x00000000
-        // XPSR      : 0x2100000b
-        // EXTRA     : 0x00000000
-        // FPSCR     : 0x00000000
+        // `next_id` of the merged binary, see `function_local_statics`.
+        let pc: u32 = 0x501026;
+        let registers = cortex_m_registers(pc, 0, 0);
 
-        let values: Vec<_> = [
-            0x00000001, // R0
-            0x2001ffcf, // R1
-            0x20000044, // R2
-            0x20000044, // R3
-            0x00000000, // R4
-            0x00000000, // R5
-            0x00000000, // R6
-            0x2001fff0, // R7
-            0x00000000, // R8
-            0x00000000, // R9
-            0x00000000, // R10
-            0x00000000, // R11
-            0x00000000, // R12
-            0x2001ffc8, // R13
-            0xfffffff9, // R14
-            0x00000184, // R15
-            0x2001ffc8, // MSP
-            0x00000000, // PSP
-            0x2100000b, // XPSR
-        ]
-        .into_iter()
-        .enumerate()
-        .map(|(id, r)| DebugRegister {
-            dwarf_id: Some(id as u16),
-            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
-            value: Some(RegisterValue::U32(r)),
-        })
-        .collect();
+        // The `.data` and `.bss` sections, moved by the load offset.
+        let mut memory = MockMemory::new();
+        memory.add_word_range(0x503000, &[7, 2]);
+        let mut core = Core::new(MockCore::new(0, registers.clone(), memory));
 
-        let regs = DebugRegisters(values);
+        let mut frames = debug_info
+            .get_stackframe_info(&mut core, pc.into(), &registers, true, false, false)
+            .unwrap();
+        let frame = &mut frames[0];
+        assert_eq!(frame.function_name, "next_id");
+        assert_eq!(frame.binary_index, 1);
+        assert_eq!(frame.pc, RegisterValue::U32(pc));
+
+        let expected_location = self::debug_info("function-local-statics")
+            .get_source_location(0x401026)
+            .map(|location| relocate_source_location(location, 0x10_0000));
+        assert_eq!(frame.source_location, expected_location);
+
+        let frame_debug_info = debug_info.debug_info_for_frame(frame);
+        assert!(std::ptr::eq(frame_debug_info, &debug_info.merged[0]));
+
+        let cache = frame.local_variables.as_mut().unwrap();
+        let mut function_root = cache
+            .get_variable_by_name(&VariableName::LocalScopeRoot)
+            .unwrap();
+        frame_debug_info
+            .cache_deferred_variables(cache, &mut core, &mut function_root, &registers, None)
+            .unwrap();
 
-        let mut dummy_mem = MockMemory::new();
+        for (name, address, value) in [("id", 0x503000, "7"), ("resets", 0x503004, "2")] {
+            let variable = cache
+                .get_variable_by_name(&VariableName::Named(name.to_string()))
+                .unwrap_or_else(|| panic!("Missing function-local static `{name}`"));
+            assert_eq!(variable.memory_location, VariableLocation::Address(address));
+            assert_eq!(variable.get_value(cache), value);
+        }
+    }
 
-        // Stack:
-        // 0x2001ffc8 = 0x2001fff0
-        // 0x2001ffcc = 0xfffffff9
-        // 0x2001ffd0 = 0x00000001
-        // 0x2001ffd4 = 0x2001ffcf
-        // 0x2001ffd8 = 0x20000044
-        // 0x2001ffdc = 0x20000044
-        // 0x2001ffe0 = 0x00000000
-        // 0x2001ffe4 = 0x0000017f
-        // 0x2001ffe8 = 0x00000180
-        // 0x2001ffec = 0x21000000
-        // 0x2001fff0 = 0x2001fff8
-        // 0x2001fff4 = 0x00000161
-        // 0x2001fff8 = 0x00000000
-        // 0x2001fffc = 0x0000013d
+    #[test]
+    fn function_return_addresses() {
+        let debug_info = debug_info("return-addresses");
 
-        dummy_mem.add_word_range(
-            0x2001_ffc8,
-            &[
-                0x2001fff0, 0xfffffff9, 0x00000001, 0x2001ffcf, 0x20000044, 0x20000044, 0x00000000,
-                0x0000017f, 0x00000180, 0x21000000, 0x2001fff8, 0x00000161, 0x00000000, 0x0000013d,
-            ],
+        // Without `epilogue_begin` markers, the last row of the function is used, which is its only `ret`.
+        assert_eq!(
+            debug_info.function_return_addresses(0x401000),
+            Some(ReturnAddresses::LastRow(0x401009))
         );
 
-        let exception_handler = Box::new(ArmV6MExceptionHandler {});
+        // Both `ret` instructions of `multiple_returns` are marked with `epilogue_begin`.
+        assert_eq!(
+            debug_info.function_return_addresses(0x401010),
+            Some(ReturnAddresses::Epilogue(vec![0x40101b, 0x401025]))
+        );
+
+        assert_eq!(debug_info.function_return_addresses(0x1000_0000), None);
+    }
+
+    #[test]
+    fn sequence_range() {
+        let debug_info = debug_info("exceptions");
+
+        // Line 24 of `main.rs`, in the sequence of `__cortex_m_rt_SVCall`.
+        assert_eq!(debug_info.sequence_range(0x196), Some(0x18c..0x1a6));
+        assert_eq!(
+            debug_info
+                .get_source_location(0x196)
+                .map(|location| (location.low_pc, location.high_pc)),
+            Some((Some(0x18c), Some(0x1a6)))
+        );
+
+        // The end of a sequence is not part of it, but the start of the next one.
+        assert_eq!(debug_info.sequence_range(0x1a6), Some(0x1a6..0x1b0));
+        assert_eq!(debug_info.sequence_range(0x1000_0000), None);
+    }
+
+    #[test]
+    fn unwinding_partial_register_set() {
+        let debug_info = debug_info("exceptions");
+
+        // A snapshot with only PC, SP and LR, right after `__cortex_m_rt_SVCall` pushed R7 and LR.
+        let mut registers = exception_handler_registers();
+        for register in registers.0.iter_mut() {
+            register.value = match register.core_register.id.0 {
+                13 => Some(RegisterValue::U32(0x2001ffc0)),
+                14 => Some(RegisterValue::U32(0x0000018b)),
+                15 => Some(RegisterValue::U32(0x0000018e)),
+                _ => None,
+            };
+        }
 
+        let mut unwind_trace = Vec::new();
         let frames = debug_info
             .unwind_impl(
-                regs,
-                &mut dummy_mem,
-                exception_handler,
+                registers,
+                &mut exception_handler_memory(),
+                Box::new(ArmV6MExceptionHandler {}),
                 Some(probe_rs_target::InstructionSet::Thumb2),
+                UnwindOptions {
+                    unwind_trace: Some(&mut unwind_trace),
+                    ..Default::default()
+                },
             )
+            .unwrap()
+            .frames;
+
+        // The exception frame can't be decoded without the XPSR, so the unwind stops at the exception entry.
+        let function_names: Vec<_> = frames
+            .iter()
+            .map(|frame| frame.function_name.as_str())
+            .collect();
+        assert_eq!(
+            function_names,
+            ["__cortex_m_rt_SVCall", "__cortex_m_rt_SVCall_trampoline"]
+        );
+        assert_eq!(frames[1].pc, RegisterValue::U32(0x18a));
+
+        // R4 is preserved by the calls, but it is unknown in the snapshot, so it stays unknown.
+        let r4 = RegisterId(4);
+        assert!(frames.iter().all(|frame| frame
+            .registers
+            .get_register(r4)
+            .unwrap()
+            .value
+            .is_none()));
+        let r4_step = unwind_trace
+            .iter()
+            .find(|step| step.frame_index == 0 && step.register_name == "R4")
             .unwrap();
+        assert_eq!(r4_step.rule, "Preserve (unknown in the callee frame)");
+        assert_eq!(r4_step.value, None);
 
-        let printed_backtrace = frames
-            .into_iter()
-            .map(|f| f.to_string())
-            .collect::<Vec<String>>()
-            .join("");
+        // R7 is restored from the stack.
+        assert_eq!(
+            frames[1].registers.get_frame_pointer().unwrap().value,
+            Some(RegisterValue::U32(0x2001ffc8))
+        );
+    }
 
-        insta::assert_snapshot!(printed_backtrace);
+    #[test]
+    fn cyclic_inline_chain() {
+        // The inlined `inner` was patched to refer to the inlined `outer` as its abstract origin.
+        let debug_info = debug_info("cyclic-inline");
+        let address = 0x401010;
+
+        let mut units = debug_info.get_units();
+        let unit_info = debug_info.get_next_unit_info(&mut units).unwrap();
+        let functions = unit_info.get_function_dies(address, None, true).unwrap();
+        let names: Vec<_> = functions
+            .iter()
+            .map(|function| function.function_name())
+            .collect();
+        assert_eq!(
+            names,
+            [Some("_start".to_string()), Some("outer".to_string())]
+        );
+
+        let frames = debug_info
+            .get_stackframe_info(
+                &mut MockMemory::new(),
+                address,
+                &DebugRegisters(vec![]),
+                false,
+                true,
+                false,
+            )
+            .unwrap();
+        let frame_names: Vec<_> = frames
+            .iter()
+            .map(|frame| frame.function_name.as_str())
+            .collect();
+        assert_eq!(frame_names, ["_start", "outer"]);
     }
 
     #[test]
-    fn unwinding_inlined() {
-        let debug_info = debug_info("inlined-functions");
+    fn translate_load_address() {
+        // The code is linked at 0x401000, but loaded at 0x10001000.
+        let mut load_address = debug_info("load-address");
 
-        // Registers:
-        // R0        : 0xfffffecc
-        // R1        : 0x00000001
-        // R2        : 0x00000000
-        // R3        : 0x40008140
-        // R4        : 0x000f4240
-        // R5        : 0xfffffec0
-        // R6        : 0x00000000
-        // R7        : 0x20003ff0
-        // R8        : 0x00000000
-        // R9        : 0x00000000
-        // R10       : 0x00000000
-        // R11       : 0x00000000
-        // R12       : 0x5000050c
-        // R13       : 0x20003ff0
-        // R14       : 0x00200000
-        // R15       : 0x000002e4
-        // MSP       : 0x20003ff0
-        // PSP       : 0x00000000
-        // XPSR      : 0x61000000
-        // EXTRA     : 0x00000000
-        // FPSCR     : 0x00000000
+        assert_eq!(load_address.translate_address(0x10001007), 0x401007);
+        assert_eq!(load_address.translate_address(0x401007), 0x401007);
+        assert_eq!(load_address.translate_address(0x20000000), 0x20000000);
 
-        let values: Vec<_> = [
-            0xfffffecc, // R0
-            0x00000001, // R1
-            0x00000000, // R2
-            0x40008140, // R3
-            0x000f4240, // R4
-            0xfffffec0, // R5
-            0x00000000, // R6
-            0x20003ff0, // R7
-            0x00000000, // R8
-            0x00000000, // R9
-            0x00000000, // R10
-            0x00000000, // R11
-            0x5000050c, // R12
-            0x20003ff0, // R13
-            0x00200000, // R14
-            0x000002e4, // R15
-            0x20003ff0, // MSP
-            0x00000000, // PSP
-            0x61000000, // XPSR
-        ]
-        .into_iter()
-        .enumerate()
-        .map(|(id, r)| DebugRegister {
-            dwarf_id: Some(id as u16),
-            core_register: CORTEX_M_CORE_REGISTERS.core_register(id),
-            value: Some(RegisterValue::U32(r)),
-        })
-        .collect();
+        // The translation is only used for lookups when it is enabled.
+        assert!(load_address.get_source_location(0x10001007).is_none());
 
-        let regs = DebugRegisters(values);
+        load_address.set_translate_load_addresses(true);
+        let location = load_address.get_source_location(0x10001007).unwrap();
+        assert_eq!(location.line, Some(3));
+        assert_eq!(
+            load_address.get_source_location(0x401007).unwrap().line,
+            Some(3)
+        );
 
-        let mut dummy_mem = MockMemory::new();
+        // Binaries which are loaded at the addresses they are linked for are not affected.
+        let debug_info = debug_info("exceptions");
+        assert_eq!(debug_info.translate_address(0x196), 0x196);
+    }
 
-        // Stack:
-        // 0x20003ff0 = 0x20003ff8
-        // 0x20003ff4 = 0x00000161
-        // 0x20003ff8 = 0x00000000
-        // 0x20003ffc = 0x0000013d
+    #[test]
+    fn riscv_undefined_rule_defaults() {
+        use crate::architecture::riscv::registers::RISCV_CORE_REGSISTERS;
+
+        const GP: RegisterId = RegisterId(0x1003);
+        const TP: RegisterId = RegisterId(0x1004);
+        const T0: RegisterId = RegisterId(0x1005);
+        const S1: RegisterId = RegisterId(0x1009);
+
+        let mut registers = DebugRegisters(
+            RISCV_CORE_REGSISTERS
+                .all_registers()
+                .enumerate()
+                .map(|(index, core_register)| DebugRegister {
+                    core_register,
+                    dwarf_id: (index < 32).then_some(index as u16),
+                    value: Some(RegisterValue::U32(0x3fc8_1000 + index as u32)),
+                })
+                .collect(),
+        );
+        registers
+            .get_register_mut_by_role(&RegisterRole::ReturnAddress)
+            .unwrap()
+            .value = Some(RegisterValue::U32(0x4200_0124));
+        let callee_frame_registers = registers.clone();
+
+        // Without unwind information, all registers use the default rule from their definition.
+        let mut unwound_return_address = None;
+        let mut unwind_trace = Vec::new();
+        for debug_register in registers.0.iter_mut() {
+            assert!(unwind_register(
+                debug_register,
+                &callee_frame_registers,
+                None,
+                Some(0x3fc8_0010),
+                &mut unwound_return_address,
+                &mut MockMemory::new(),
+                Some(InstructionSet::RV32C),
+                &mut PointerAuthentication::default(),
+                0,
+                Some(&mut unwind_trace),
+            )
+            .is_continue());
+        }
 
-        dummy_mem.add_word_range(
-            0x2000_3ff0,
-            &[0x20003ff8, 0x00000161, 0x00000000, 0x0000013d],
+        let role_value = |role: RegisterRole| registers.get_register_by_role(&role).unwrap().value;
+        // `sp` is the CFA, `ra` is unknown in the calling frame, and `pc` is the return address.
+        assert_eq!(
+            role_value(RegisterRole::StackPointer),
+            Some(RegisterValue::U32(0x3fc8_0010))
+        );
+        assert_eq!(role_value(RegisterRole::ReturnAddress), None);
+        assert_eq!(
+            role_value(RegisterRole::ProgramCounter),
+            Some(RegisterValue::U32(0x4200_0124))
         );
 
-        let exception_handler = Box::new(ArmV7MExceptionHandler {});
+        // `gp`, `tp` and the saved registers are preserved, the temporaries are not.
+        let id_value = |id: RegisterId| registers.get_register(id).unwrap().value;
+        assert_eq!(id_value(GP), Some(RegisterValue::U32(0x3fc8_1003)));
+        assert_eq!(id_value(TP), Some(RegisterValue::U32(0x3fc8_1004)));
+        assert_eq!(id_value(S1), Some(RegisterValue::U32(0x3fc8_1009)));
+        assert_eq!(id_value(T0), None);
+
+        let rules: Vec<_> = unwind_trace
+            .iter()
+            .take(4)
+            .map(|step| step.rule.as_str())
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                "Clear",
+                "LR=Unknown (dwarf Undefined)",
+                "SP=CFA (dwarf Undefined)",
+                "Preserve"
+            ]
+        );
+    }
 
-        let frames = debug_info
-            .unwind_impl(
-                regs,
-                &mut dummy_mem,
-                exception_handler,
-                Some(probe_rs_target::InstructionSet::Thumb2),
-            )
-            .unwrap();
+    #[test]
+    fn big_endian_object() {
+        // An ARM object file in big-endian byte order, assembled with DWARF line information.
+        let mut debug_info = debug_info("big-endian");
 
-        let printed_backtrace = frames
-            .into_iter()
-            .map(|f| f.to_string())
-            .collect::<Vec<String>>()
-            .join("");
+        let location = debug_info.get_source_location(0xc).unwrap();
+        assert_eq!(location.line, Some(15));
 
-        insta::assert_snapshot!(printed_backtrace);
+        debug_info.set_symbol_fallback(true);
+        assert_eq!(
+            debug_info.function_name(0x4, false).unwrap().as_deref(),
+            Some("add_one+0x4")
+        );
     }
 }
@@ -1,5 +1,5 @@
 use super::{
-    debug_info::DebugInfo,
+    debug_info::{DebugInfo, ReturnAddresses},
     source_statement::SourceStatements,
     {DebugError, SourceLocation},
 };
@@ -218,6 +218,9 @@ impl SteppingMode {
                                                 high_pc: Some(
                                                     source_statement.instruction_range.end as u32,
                                                 ),
+                                                statement_range: Some(
+                                                    source_statement.instruction_range.clone(),
+                                                ),
                                             })
                                     })
                             });
@@ -327,9 +330,7 @@ impl SteppingMode {
                         "Function {:?} is marked as `noreturn`. Cannot step out of this function.",
                         function.function_name()
                     )));
-                        } else if function.low_pc <= program_counter
-                            && function.high_pc > program_counter
-                        {
+                        } else if function.contains_address(program_counter) {
                             if let Some(core) = core {
                                 if function.is_inline() {
                                     // Step_out_address for inlined functions, is the first available breakpoint address after the last statement in the inline function.
@@ -341,6 +342,46 @@ impl SteppingMode {
                                         next_instruction_address,
                                         None,
                                     );
+                                }
+
+                                // Only the `epilogue_begin` rows are known to be return instructions. The last row of a function
+                                // is just a guess, which is never reached if the function returns earlier.
+                                let return_instructions: Vec<u64> =
+                                    match debug_info.return_addresses(function) {
+                                        Some(ReturnAddresses::Epilogue(addresses)) => addresses
+                                            .into_iter()
+                                            .filter(|address| *address >= program_counter)
+                                            .collect(),
+                                        Some(ReturnAddresses::LastRow(_)) | None => Vec::new(),
+                                    };
+                                if let [epilogue_address] = return_instructions[..] {
+                                    tracing::debug!(
+                                        "Step Out target: non-inline function, running to the epilogue at: {:#010x}",
+                                        epilogue_address
+                                    );
+                                    // With a single way out of the function, we don't have to rely on the return address register,
+                                    // which may have been overwritten by calls from this function. Run to the epilogue, and step until we have returned.
+                                    let (_, epilogue_pc) =
+                                        run_to_address(program_counter, epilogue_address, core)?;
+                                    if epilogue_pc != epilogue_address {
+                                        // We halted somewhere else, e.g. at another breakpoint.
+                                        return SteppingMode::BreakPoint.get_halt_location(
+                                            None,
+                                            debug_info,
+                                            epilogue_pc,
+                                            None,
+                                        );
+                                    }
+                                    let (_, caller_address) = step_to_address(
+                                        epilogue_address..=function.high_pc.saturating_sub(1),
+                                        core,
+                                    )?;
+                                    return SteppingMode::BreakPoint.get_halt_location(
+                                        None,
+                                        debug_info,
+                                        caller_address,
+                                        None,
+                                    );
                                 } else if let Some(return_address) = return_address {
                                     tracing::debug!(
                                         "Step Out target: non-inline function, stepping over return address: {:#010x}",
@@ -1,5 +1,7 @@
 use super::{debug_info, extract_file, unit_info::UnitInfo, ColumnType, SourceLocation};
 
+use std::ops::Range;
+
 pub(crate) type FunctionDieType<'abbrev, 'unit> =
     gimli::DebuggingInformationEntry<'abbrev, 'unit, debug_info::GimliReader, usize>;
 
@@ -75,6 +77,83 @@ impl<'debugunit, 'abbrev, 'unit: 'debugunit, 'unit_info, 'debug_info>
         self.abstract_die.is_some()
     }
 
+    /// The address of the first instruction which is executed when this function is entered.
+    ///
+    /// This is the `DW_AT_entry_pc` attribute, if present, which can differ from `low_pc`
+    /// when the function is split into multiple ranges, e.g. when part of it is moved to a cold section.
+    /// Otherwise, `low_pc` is the entry point.
+    pub(crate) fn entry_pc(&self) -> u64 {
+        let Ok(Some(entry_pc)) = self.function_die.attr_value(gimli::DW_AT_entry_pc) else {
+            return self.low_pc;
+        };
+
+        match entry_pc {
+            gimli::AttributeValue::Addr(address) => address,
+            gimli::AttributeValue::DebugAddrIndex(index) => self
+                .unit_info
+                .debug_info
+                .dwarf
+                .address(&self.unit_info.unit, index)
+                .unwrap_or(self.low_pc),
+            // Since DWARF 5, a constant value is an offset from the start of the function.
+            other_value => match other_value.udata_value() {
+                Some(offset) => self.base_address().unwrap_or(self.low_pc) + offset,
+                None => {
+                    tracing::warn!("Unsupported DW_AT_entry_pc value: {:?}", other_value);
+                    self.low_pc
+                }
+            },
+        }
+    }
+
+    /// All the address ranges of this function.
+    ///
+    /// Functions can consist of multiple discontiguous ranges, e.g. when part of the function is moved to a cold section.
+    /// `low_pc` and `high_pc` only describe the range which contains the address used to find the function.
+    /// Empty ranges, which can be left behind by linker optimizations such as identical code folding, are skipped.
+    pub(crate) fn ranges(&self) -> Vec<Range<u64>> {
+        let mut ranges = Vec::new();
+
+        if let Ok(mut die_ranges) = self
+            .unit_info
+            .debug_info
+            .dwarf
+            .die_ranges(&self.unit_info.unit, &self.function_die)
+        {
+            while let Ok(Some(range)) = die_ranges.next() {
+                if range.begin < range.end {
+                    ranges.push(range.begin..range.end);
+                }
+            }
+        }
+
+        ranges
+    }
+
+    /// Returns `true` if any of the address ranges of this function contains `address`.
+    pub(crate) fn contains_address(&self, address: u64) -> bool {
+        self.ranges().iter().any(|range| range.contains(&address))
+    }
+
+    /// The base address of the function, which is `DW_AT_low_pc`, or the start of the first range in `DW_AT_ranges`.
+    fn base_address(&self) -> Option<u64> {
+        if let Ok(Some(gimli::AttributeValue::Addr(low_pc))) =
+            self.function_die.attr_value(gimli::DW_AT_low_pc)
+        {
+            return Some(low_pc);
+        }
+
+        self.unit_info
+            .debug_info
+            .dwarf
+            .die_ranges(&self.unit_info.unit, &self.function_die)
+            .ok()?
+            .next()
+            .ok()
+            .flatten()
+            .map(|range| range.begin)
+    }
+
     pub(crate) fn function_name(&self) -> Option<String> {
         if let Some(fn_name_attr) = self.get_attribute(gimli::DW_AT_name) {
             match fn_name_attr.value() {
@@ -132,6 +211,7 @@ impl<'debugunit, 'abbrev, 'unit: 'debugunit, 'unit_info, 'debug_info>
             directory: Some(directory),
             low_pc: Some(self.low_pc as u32),
             high_pc: Some(self.high_pc as u32),
+            statement_range: None,
         })
     }
 
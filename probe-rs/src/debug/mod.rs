@@ -25,7 +25,11 @@ pub mod variable;
 pub mod variable_cache;
 
 pub use self::{
-    debug_info::*, debug_step::SteppingMode, registers::*, stack_frame::StackFrame, variable::*,
+    debug_info::*,
+    debug_step::SteppingMode,
+    registers::*,
+    stack_frame::{BacktraceFormatter, BacktracePathStyle, StackFrame, StackFrameRegisters},
+    variable::*,
     variable_cache::VariableCache,
 };
 use crate::{core::Core, MemoryInterface};
@@ -35,6 +39,7 @@ use typed_path::TypedPathBuf;
 
 use std::{
     io,
+    ops::Range,
     path::PathBuf,
     str::Utf8Error,
     sync::atomic::{AtomicI64, Ordering},
@@ -126,6 +131,10 @@ pub struct SourceLocation {
     pub low_pc: Option<u32>,
     /// The address of the first location past the last instruction associated with the source code
     pub high_pc: Option<u32>,
+    /// The instructions of the source statement this location was derived from, if known.
+    ///
+    /// Unlike `low_pc` and `high_pc`, which can span a whole line sequence, this only covers a single statement.
+    pub statement_range: Option<Range<u64>>,
 }
 
 impl SourceLocation {
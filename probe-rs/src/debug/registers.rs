@@ -1,6 +1,6 @@
 use crate::{
     core::{Core, RegisterDataType, RegisterId, RegisterRole, RegisterValue},
-    CoreRegister, Error,
+    CoreRegister, CoreType, Error, InstructionSet,
 };
 
 /// Stores the relevant information from [`crate::core::CoreRegister`] for use in debug operations,
@@ -97,6 +97,37 @@ impl DebugRegisters {
         )
     }
 
+    /// Determine the instruction set which the core is executing.
+    ///
+    /// M-profile cores only execute Thumb instructions, for A-profile cores in AArch32 state the Thumb (T) bit
+    /// of the CPSR is used.
+    /// Returns `None` if the instruction set can not be determined from the registers, e.g. for RISC-V cores.
+    pub fn instruction_set(&self, core_type: CoreType) -> Option<InstructionSet> {
+        match core_type {
+            // A cleared T bit in the XPSR doesn't mean ARM state, it causes a fault on the next instruction.
+            CoreType::Armv6m | CoreType::Armv7m | CoreType::Armv7em | CoreType::Armv8m => {
+                Some(InstructionSet::Thumb2)
+            }
+            CoreType::Armv7a | CoreType::Armv8a => {
+                if self.get_address_size_bytes() == 8 {
+                    return Some(InstructionSet::A64);
+                }
+
+                let status = self
+                    .get_register_value_by_role(&RegisterRole::ProcessorStatus)
+                    .ok()?;
+
+                // CPSR bit 5 - T - Thumb state
+                if (status >> 5) & 1 == 1 {
+                    Some(InstructionSet::Thumb2)
+                } else {
+                    Some(InstructionSet::A32)
+                }
+            }
+            CoreType::Riscv => None,
+        }
+    }
+
     /// Get the canonical frame address, as specified in the [DWARF](https://dwarfstd.org) specification, section 6.4.
     /// [DWARF](https://dwarfstd.org)
     pub fn get_frame_pointer(&self) -> Option<&DebugRegister> {
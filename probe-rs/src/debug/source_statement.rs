@@ -1,4 +1,4 @@
-use super::{unit_info::UnitInfo, DebugError, DebugInfo};
+use super::{debug_info::GimliReader, unit_info::UnitInfo, DebugError, DebugInfo};
 use gimli::{ColumnType, LineSequence};
 use std::{
     fmt::{Debug, Formatter},
@@ -234,11 +234,8 @@ fn get_program_info_at_pc(
     program_counter: u64,
 ) -> Result<
     (
-        gimli::CompleteLineProgram<
-            gimli::EndianReader<gimli::LittleEndian, std::rc::Rc<[u8]>>,
-            usize,
-        >,
-        gimli::LineSequence<gimli::EndianReader<gimli::LittleEndian, std::rc::Rc<[u8]>>>,
+        gimli::CompleteLineProgram<GimliReader, usize>,
+        gimli::LineSequence<GimliReader>,
     ),
     DebugError,
 > {
@@ -1,6 +1,7 @@
 use super::*;
 use crate::core::RegisterValue;
 use std;
+use std::fmt::Write as _;
 
 /// A full stack frame with all its information contained.
 #[derive(Debug)]
@@ -22,6 +23,18 @@ pub struct StackFrame {
     pub frame_base: Option<u64>,
     /// Indicate if this stack frame belongs to an inlined function.
     pub is_inlined: bool,
+    /// Indicate if this is a synthetic stack frame, inserted by the unwinder to mark the entry into an exception handler.
+    /// The `function_name` of these frames is the description of the exception.
+    pub is_exception_entry: bool,
+    /// Identifies the binary this stack frame was resolved from, when the debug information of several binaries
+    /// is [merged](crate::debug::DebugInfo::merge). Use [`DebugInfo::debug_info_for_frame`](crate::debug::DebugInfo::debug_info_for_frame)
+    /// to get the debug information which the variables of this frame belong to.
+    pub binary_index: usize,
+    /// The names and values of the arguments which this inlined function passes to the function it calls at `pc`,
+    /// recovered from the call site parameters in the debug information.
+    /// This is `None` for frames of non-inlined functions, or if it was not requested,
+    /// see [`DebugInfo::unwind_with_call_site_arguments`](crate::debug::DebugInfo::unwind_with_call_site_arguments).
+    pub call_site_arguments: Option<Vec<(String, u64)>>,
     /// A cache of 'static' scoped variables for this stackframe
     pub static_variables: Option<VariableCache>,
     /// A cache of 'local' scoped variables for this stafckframe, with a `Variable` for each in-scope variable.
@@ -32,24 +45,226 @@ pub struct StackFrame {
 
 impl std::fmt::Display for StackFrame {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        BacktraceFormatter::new().write_frame(f, self)
+    }
+}
+
+impl StackFrame {
+    /// Get a formatter which renders the registers of this stack frame as a table, with four registers per line.
+    ///
+    /// If `filter` is not empty, only the registers which match one of its entries are included.
+    /// Registers can be selected by name (e.g. `R0`) or role (e.g. `SP`), ignoring case.
+    pub fn registers_table<'a>(&'a self, filter: &'a [String]) -> StackFrameRegisters<'a> {
+        StackFrameRegisters {
+            frame: self,
+            filter,
+        }
+    }
+}
+
+/// Renders the registers of a [`StackFrame`], created by [`StackFrame::registers_table`].
+pub struct StackFrameRegisters<'a> {
+    frame: &'a StackFrame,
+    filter: &'a [String],
+}
+
+impl StackFrameRegisters<'_> {
+    const REGISTERS_PER_LINE: usize = 4;
+
+    fn is_selected(&self, register: &registers::DebugRegister) -> bool {
+        if self.filter.is_empty() {
+            return true;
+        }
+
+        self.filter.iter().any(|selection| {
+            register
+                .core_register
+                .name()
+                .eq_ignore_ascii_case(selection)
+                || register
+                    .core_register
+                    .roles
+                    .iter()
+                    .any(|role| role.to_string().eq_ignore_ascii_case(selection))
+        })
+    }
+}
+
+impl std::fmt::Display for StackFrameRegisters<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(frame_base) = self.frame.frame_base {
+            writeln!(f, "frame base: {frame_base:#010x}")?;
+        }
+
+        let cells: Vec<(&str, String)> = self
+            .frame
+            .registers
+            .0
+            .iter()
+            .filter(|register| self.is_selected(register))
+            .map(|register| {
+                let value = register
+                    .value
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "<unknown>".to_string());
+                (register.core_register.name(), value)
+            })
+            .collect();
+
+        let name_width = cells.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        let value_width = cells
+            .iter()
+            .map(|(_, value)| value.len())
+            .max()
+            .unwrap_or(0);
+
+        for line in cells.chunks(Self::REGISTERS_PER_LINE) {
+            let line = line
+                .iter()
+                .map(|(name, value)| format!("{name:<name_width$} {value:<value_width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How the source file of a stack frame is shown by a [`BacktraceFormatter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BacktracePathStyle {
+    /// The full path, including the directory.
+    #[default]
+    Full,
+    /// Only the file name.
+    FileName,
+}
+
+/// Renders a list of [`StackFrame`]s as a backtrace.
+///
+/// With the default settings, every frame is rendered like its [`Display`](std::fmt::Display) implementation.
+///
+/// ```
+/// use probe_rs::debug::{BacktraceFormatter, BacktracePathStyle};
+///
+/// let formatter = BacktraceFormatter::new()
+///     .max_depth(10)
+///     .include_inlined(false)
+///     .path_style(BacktracePathStyle::FileName);
+/// assert_eq!(formatter.format(&[]), "");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BacktraceFormatter {
+    max_depth: Option<usize>,
+    include_inlined: bool,
+    show_addresses: bool,
+    path_style: BacktracePathStyle,
+}
+
+impl Default for BacktraceFormatter {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            include_inlined: true,
+            show_addresses: false,
+            path_style: BacktracePathStyle::Full,
+        }
+    }
+}
+
+impl BacktraceFormatter {
+    /// Create a formatter with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only render the first `max_depth` frames. Frames hidden by [`BacktraceFormatter::include_inlined`] are not counted.
+    #[must_use]
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// Include the frames of inlined functions. This is the default.
+    ///
+    /// When they are hidden, the function they were inlined into is shown with the location of the inlined call.
+    #[must_use]
+    pub fn include_inlined(self, include_inlined: bool) -> Self {
+        Self {
+            include_inlined,
+            ..self
+        }
+    }
+
+    /// Show the program counter of each frame after the function name.
+    #[must_use]
+    pub fn show_addresses(self, show_addresses: bool) -> Self {
+        Self {
+            show_addresses,
+            ..self
+        }
+    }
+
+    /// Set how the source file of each frame is shown.
+    #[must_use]
+    pub fn path_style(self, path_style: BacktracePathStyle) -> Self {
+        Self { path_style, ..self }
+    }
+
+    /// Render `frames`, with the innermost frame first.
+    pub fn format(&self, frames: &[StackFrame]) -> String {
+        let mut output = String::new();
+
+        let frames = frames
+            .iter()
+            .filter(|frame| self.include_inlined || !frame.is_inlined)
+            .take(self.max_depth.unwrap_or(usize::MAX));
+
+        for frame in frames {
+            // Writing to a `String` can't fail.
+            let _ = write!(output, "{}", DisplayFrame(self, frame));
+        }
+
+        output
+    }
+
+    fn write_frame(&self, f: &mut std::fmt::Formatter, frame: &StackFrame) -> std::fmt::Result {
         // Header info for the StackFrame
-        writeln!(f, "Frame: {}", self.function_name)?;
-        if let Some(si) = &self.source_location {
-            let separator = match &si.directory {
-                Some(path) if path.is_windows() => '\\',
-                _ => '/',
-            };
-
-            write!(
-                f,
-                "\t{}{}{}",
-                si.directory
-                    .as_ref()
-                    .map(|p| p.to_string_lossy())
-                    .unwrap_or_else(|| std::borrow::Cow::from("<unknown dir>")),
-                separator,
-                si.file.as_ref().unwrap_or(&"<unknown file>".to_owned())
-            )?;
+        write!(f, "Frame: {}", frame.function_name)?;
+        if self.show_addresses {
+            write!(f, " @ {}", frame.pc)?;
+        }
+        writeln!(f)?;
+
+        if let Some(si) = &frame.source_location {
+            let file = si
+                .file
+                .as_ref()
+                .map_or("<unknown file>", |file| file.as_str());
+
+            match self.path_style {
+                BacktracePathStyle::Full => {
+                    let separator = match &si.directory {
+                        Some(path) if path.is_windows() => '\\',
+                        _ => '/',
+                    };
+
+                    write!(
+                        f,
+                        "\t{}{}{}",
+                        si.directory
+                            .as_ref()
+                            .map(|p| p.to_string_lossy())
+                            .unwrap_or_else(|| std::borrow::Cow::from("<unknown dir>")),
+                        separator,
+                        file
+                    )?;
+                }
+                BacktracePathStyle::FileName => write!(f, "\t{file}")?,
+            }
 
             if let (Some(column), Some(line)) = (si.column, si.line) {
                 match column {
@@ -61,3 +276,12 @@ impl std::fmt::Display for StackFrame {
         writeln!(f)
     }
 }
+
+/// Adapter to render a single frame with the settings of a [`BacktraceFormatter`].
+struct DisplayFrame<'a>(&'a BacktraceFormatter, &'a StackFrame);
+
+impl std::fmt::Display for DisplayFrame<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.write_frame(f, self.1)
+    }
+}
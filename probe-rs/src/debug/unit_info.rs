@@ -6,9 +6,9 @@ use super::{
 use crate::{core::Core, core::RegisterValue, MemoryInterface};
 use gimli::{AttributeValue::Language, Location, UnitOffset};
 use num_traits::Zero;
+use std::collections::HashSet;
 
-pub(crate) type UnitIter =
-    gimli::DebugInfoUnitHeadersIter<gimli::EndianReader<gimli::LittleEndian, std::rc::Rc<[u8]>>>;
+pub(crate) type UnitIter = gimli::DebugInfoUnitHeadersIter<GimliReader>;
 
 /// The result of `UnitInfo::evaluate_expression()` can be the value of a variable, or a memory location.
 pub(crate) enum ExpressionResult {
@@ -142,8 +142,12 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
 
         let mut functions = Vec::new();
 
+        // The offsets of all DIEs which are part of the inline chain, used to detect
+        // corrupt debug info which references the same DIE more than once.
+        let mut visited = HashSet::from([offset]);
+
         if let Ok(mut cursor) = self.unit.entries_at_offset(offset) {
-            while let Ok(Some((depth, current))) = cursor.next_dfs() {
+            'dies: while let Ok(Some((depth, current))) = cursor.next_dfs() {
                 current_depth += depth;
 
                 if current_depth < abort_depth {
@@ -157,6 +161,16 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                         if ranges.begin <= address && address < ranges.end {
                             // Check if we are actually in an inlined function
 
+                            if !visited.insert(current.offset()) {
+                                tracing::warn!(
+                                    "Inlined function at {:?} has overlapping ranges, ignoring duplicate range {:#x}..{:#x}.",
+                                    current.offset(),
+                                    ranges.begin,
+                                    ranges.end
+                                );
+                                continue;
+                            }
+
                             // We don't have to search further up in the tree, if there are multiple inlined functions,
                             // they will be children of the current function.
                             abort_depth = current_depth;
@@ -166,6 +180,16 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                 current.attr(gimli::DW_AT_abstract_origin)
                             {
                                 match abstract_origin.value() {
+                                    gimli::AttributeValue::UnitRef(unit_ref)
+                                        if visited.contains(&unit_ref) =>
+                                    {
+                                        tracing::warn!(
+                                            "Inlined function at {:?} refers to {:?}, which is already part of the inline chain. Ignoring the remaining inlined functions.",
+                                            current.offset(),
+                                            unit_ref
+                                        );
+                                        break 'dies;
+                                    }
                                     gimli::AttributeValue::UnitRef(unit_ref) => {
                                         if let Ok(abstract_die) = self.unit.entry(unit_ref) {
                                             if let Some(mut die) = FunctionDie::new_inlined(
@@ -290,6 +314,7 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                         directory: Some(directory),
                                         low_pc: None,
                                         high_pc: None,
+                                        statement_range: None,
                                     });
                                 }
                                 None => {
@@ -300,6 +325,7 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                         directory: Some(directory),
                                         low_pc: None,
                                         high_pc: None,
+                                        statement_range: None,
                                     });
                                 }
                             }
@@ -316,6 +342,7 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                         directory: existing_source_location.directory,
                                         low_pc: None,
                                         high_pc: None,
+                                        statement_range: None,
                                     });
                                 }
                                 None => {
@@ -326,6 +353,7 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                         directory: None,
                                         low_pc: None,
                                         high_pc: None,
+                                        statement_range: None,
                                     });
                                 }
                             }
@@ -679,6 +707,14 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                                         // Recursively process each relevant child node.
                                         child_variable = self.process_tree(child_node, child_variable, core, stack_frame_registers, frame_base, cache)?;
                                         if child_variable.is_valid() {
+                                            // The member of the active variant is named after the variant, e.g. `Some`.
+                                            parent_variable.variant_name = cache
+                                                .get_children(Some(child_variable.variable_key))?
+                                                .into_iter()
+                                                .find_map(|variant_member| match variant_member.name {
+                                                    VariableName::Named(name) => Some(name),
+                                                    _ => None,
+                                                });
                                             // Eliminate intermediate DWARF nodes, but keep their children
                                             cache.adopt_grand_children(&parent_variable, &child_variable)?;
                                         }
@@ -767,6 +803,8 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                             parent_variable = self.process_tree(child_node, parent_variable, core, stack_frame_registers, frame_base, cache)?;
                         } else {
                             // This lexical block is NOT in scope, but other children of this parent may well be in scope, so do NOT invalidate the parent_variable.
+                            // Statics declared in the block keep their value outside of it, so they are still added to the parent.
+                            self.process_block_statics(child_node, &mut parent_variable, core, stack_frame_registers, frame_base, cache)?;
                         }
                     }
                     gimli::DW_TAG_template_type_parameter => {
@@ -802,6 +840,85 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
             .map_err(|error| error.into())
     }
 
+    /// Add the function-local statics declared in the lexical block `block_node`, and in the blocks nested in it, to `parent_variable`.
+    ///
+    /// This is used for blocks which are not in scope, and skips all the variables which don't have a fixed address.
+    fn process_block_statics(
+        &self,
+        block_node: gimli::EntriesTreeNode<GimliReader>,
+        parent_variable: &mut Variable,
+        core: &mut Core<'_>,
+        stack_frame_registers: &registers::DebugRegisters,
+        frame_base: Option<u64>,
+        cache: &mut VariableCache,
+    ) -> Result<(), DebugError> {
+        let mut child_nodes = block_node.children();
+        while let Some(mut child_node) = child_nodes.next()? {
+            match child_node.entry().tag() {
+                gimli::DW_TAG_variable if self.has_fixed_address(child_node.entry()) => {
+                    let mut child_variable = cache.cache_variable(
+                        Some(parent_variable.variable_key),
+                        Variable::new(
+                            self.unit.header.offset().as_debug_info_offset(),
+                            Some(child_node.entry().offset()),
+                        ),
+                        core,
+                    )?;
+                    child_variable = self.process_tree_node_attributes(
+                        &mut child_node,
+                        parent_variable,
+                        child_variable,
+                        core,
+                        stack_frame_registers,
+                        frame_base,
+                        cache,
+                    )?;
+                    if child_variable.is_valid() {
+                        self.process_tree(
+                            child_node,
+                            child_variable,
+                            core,
+                            stack_frame_registers,
+                            frame_base,
+                            cache,
+                        )?;
+                    }
+                }
+                gimli::DW_TAG_lexical_block => {
+                    self.process_block_statics(
+                        child_node,
+                        parent_variable,
+                        core,
+                        stack_frame_registers,
+                        frame_base,
+                        cache,
+                    )?;
+                }
+                _ => {
+                    // Other variables are only valid while the block is in scope.
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the location of `entry` is a fixed address (`DW_OP_addr` or `DW_OP_addrx`), as used for statics.
+    fn has_fixed_address(&self, entry: &gimli::DebuggingInformationEntry<GimliReader>) -> bool {
+        let Ok(Some(gimli::AttributeValue::Exprloc(expression))) =
+            entry.attr_value(gimli::DW_AT_location)
+        else {
+            return false;
+        };
+
+        let mut operations = expression.operations(self.unit.encoding());
+        matches!(
+            operations.next(),
+            Ok(Some(
+                gimli::Operation::Address { .. } | gimli::Operation::AddressIndex { .. }
+            ))
+        ) && matches!(operations.next(), Ok(None))
+    }
+
     /// Compute the discriminant value of a DW_TAG_variant variable. If it is not explicitly captured in the DWARF, then it is the default value.
     pub(crate) fn extract_variant_discriminant(
         &self,
@@ -813,12 +930,11 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                 Ok(optional_discr_value_attr) => {
                     match optional_discr_value_attr {
                         Some(discr_attr) => {
-                            match discr_attr.value() {
-                                gimli::AttributeValue::Data1(const_value) => {
-                                    VariantRole::Variant(const_value as u64)
-                                }
-                                other_attribute_value => {
-                                    variable.set_value(VariableValue::Error(format!("Unimplemented: Attribute Value for DW_AT_discr_value: {:.100}", format!("{other_attribute_value:?}"))));
+                            // The discriminant can be encoded with any of the constant forms, depending on its size.
+                            match discr_attr.udata_value() {
+                                Some(const_value) => VariantRole::Variant(const_value),
+                                None => {
+                                    variable.set_value(VariableValue::Error(format!("Unimplemented: Attribute Value for DW_AT_discr_value: {:.100}", format!("{:?}", discr_attr.value()))));
                                     VariantRole::Variant(u64::MAX)
                                 }
                             }
@@ -962,6 +1078,63 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
                             )));
                         }
                     }
+
+                    // Pointers in C do not have a name, so we reconstruct it from the referenced type.
+                    if child_variable.type_name == VariableType::Pointer(None) {
+                        child_variable.type_name =
+                            VariableType::Pointer(self.modified_type_name(node.entry().offset()));
+                    }
+                }
+                gimli::DW_TAG_typedef
+                | gimli::DW_TAG_const_type
+                | gimli::DW_TAG_volatile_type
+                | gimli::DW_TAG_restrict_type => {
+                    // Type aliases and modifiers do not hold any data of their own, so we resolve the variable using the type they refer to,
+                    // and then replace the type name with the name of the full modifier chain.
+                    match node.entry().attr(gimli::DW_AT_type) {
+                        Ok(Some(data_type_attribute)) => match data_type_attribute.value() {
+                            gimli::AttributeValue::UnitRef(unit_ref) => {
+                                let mut type_tree = self
+                                    .unit
+                                    .header
+                                    .entries_tree(&self.unit.abbreviations, Some(unit_ref))?;
+                                let referenced_type_tree_node = type_tree.root()?;
+                                child_variable = self.extract_type(
+                                    referenced_type_tree_node,
+                                    parent_variable,
+                                    child_variable,
+                                    core,
+                                    stack_frame_registers,
+                                    frame_base,
+                                    cache,
+                                )?;
+                                if let Some(modified_type_name) =
+                                    self.modified_type_name(node.entry().offset())
+                                {
+                                    child_variable.type_name =
+                                        child_variable.type_name.renamed(modified_type_name);
+                                }
+                            }
+                            other_attribute_value => {
+                                child_variable.set_value(VariableValue::Error(format!(
+                                    "Unimplemented: Attribute Value for DW_AT_type {:.100}",
+                                    format!("{other_attribute_value:?}")
+                                )));
+                            }
+                        },
+                        Ok(None) => {
+                            // A modifier without a type refers to `void`, e.g. `const void`.
+                            child_variable.type_name = VariableType::Other(
+                                self.modified_type_name(node.entry().offset())
+                                    .unwrap_or_else(|| "void".to_string()),
+                            );
+                        }
+                        Err(error) => {
+                            child_variable.set_value(VariableValue::Error(format!(
+                                "Error: Failed to decode type modifier reference: {error:?}"
+                            )));
+                        }
+                    }
                 }
                 gimli::DW_TAG_structure_type => {
                     child_variable.type_name =
@@ -1824,9 +1997,11 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
 
                     evaluation.resume_with_register(gimli::Value::Generic(raw_value.try_into()?))?
                 }
-                RequiresRelocatedAddress(address_index) => {
-                    // The address_index as an offset from 0, so just pass it into the next step.
-                    evaluation.resume_with_relocated_address(address_index)?
+                RequiresRelocatedAddress(address) => {
+                    // Static addresses of a merged binary are moved by the offset at which it is loaded.
+                    evaluation.resume_with_relocated_address(
+                        address.wrapping_add(self.debug_info.load_offset),
+                    )?
                 }
                 unimplemented_expression => {
                     return Err(DebugError::UnwindIncompleteResults {
@@ -1922,6 +2097,59 @@ impl<'debuginfo> UnitInfo<'debuginfo> {
         }
     }
 
+    /// Reconstruct the full name of the type at `unit_ref`, by following any chain of `DW_TAG_typedef`, `DW_TAG_const_type`,
+    /// `DW_TAG_volatile_type`, `DW_TAG_restrict_type` and unnamed `DW_TAG_pointer_type` entries, e.g. `const volatile uint32_t *`.
+    /// - A `DW_TAG_typedef` (or any other named type) ends the chain, and is displayed with its own name.
+    /// - A missing `DW_AT_type` on a modifier or pointer refers to `void`.
+    /// - Returns `None` if the chain can not be resolved to a name.
+    pub(crate) fn modified_type_name(&self, unit_ref: UnitOffset) -> Option<String> {
+        let entry = self
+            .unit
+            .header
+            .entry(&self.unit.abbreviations, unit_ref)
+            .ok()?;
+
+        let qualifier = match entry.tag() {
+            gimli::DW_TAG_const_type => "const",
+            gimli::DW_TAG_volatile_type => "volatile",
+            gimli::DW_TAG_restrict_type => "restrict",
+            gimli::DW_TAG_pointer_type if matches!(entry.attr(gimli::DW_AT_name), Ok(None)) => {
+                let referenced_type_name = self.referenced_type_name(&entry)?;
+                return Some(if referenced_type_name.ends_with('*') {
+                    format!("{referenced_type_name}*")
+                } else {
+                    format!("{referenced_type_name} *")
+                });
+            }
+            _ => {
+                return entry
+                    .attr(gimli::DW_AT_name)
+                    .ok()?
+                    .map(|name_attr| extract_name(self.debug_info, name_attr.value()));
+            }
+        };
+
+        let referenced_type_name = self.referenced_type_name(&entry)?;
+        // Qualifiers of a pointer follow the `*`, e.g. `const char *const`.
+        Some(if referenced_type_name.ends_with('*') {
+            format!("{referenced_type_name}{qualifier}")
+        } else {
+            format!("{qualifier} {referenced_type_name}")
+        })
+    }
+
+    /// The name of the type referenced by the `DW_AT_type` of `entry`, as reconstructed by [`UnitInfo::modified_type_name`].
+    fn referenced_type_name(
+        &self,
+        entry: &gimli::DebuggingInformationEntry<GimliReader>,
+    ) -> Option<String> {
+        match entry.attr_value(gimli::DW_AT_type).ok()? {
+            Some(gimli::AttributeValue::UnitRef(unit_ref)) => self.modified_type_name(unit_ref),
+            Some(_) => None,
+            None => Some("void".to_string()),
+        }
+    }
+
     /// A helper function to determine if the type we are referencing requires a pointer to the address of the referenced variable (e.g. variants, generics, arrays, etc.)
     fn has_address_pointer(&self, unit_ref: UnitOffset) -> Result<bool, DebugError> {
         let mut entries_tree = self
@@ -194,6 +194,20 @@ impl VariableType {
     pub fn is_array(&self) -> bool {
         matches!(self, VariableType::Array { .. })
     }
+
+    /// Replace the displayed name of this type, while keeping the category of the type.
+    /// This is used when a type is referenced through an alias or modifier, e.g. a C `typedef` or `const` qualifier.
+    /// Arrays keep their name, because it is derived from the type of their items.
+    pub(crate) fn renamed(self, name: String) -> Self {
+        match self {
+            VariableType::Base(_) => VariableType::Base(name),
+            VariableType::Struct(_) => VariableType::Struct(name),
+            VariableType::Enum(_) => VariableType::Enum(name),
+            VariableType::Pointer(_) => VariableType::Pointer(Some(name)),
+            VariableType::Array { .. } | VariableType::Namespace => self,
+            VariableType::Unknown | VariableType::Other(_) => VariableType::Other(name),
+        }
+    }
 }
 
 impl std::fmt::Display for VariableType {
@@ -270,6 +284,15 @@ impl std::fmt::Display for VariableLocation {
     }
 }
 
+/// Where the value of a variable is stored, e.g. the return value of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VariableStorage {
+    /// The value is stored in these registers, with the least significant part in the first register.
+    Registers(Vec<crate::core::RegisterId>),
+    /// The value is stored in memory, at this address.
+    Memory(u64),
+}
+
 /// The `Variable` struct is used in conjunction with `VariableCache` to cache data about variables.
 ///
 /// Any modifications to the `Variable` value will be transient (lost when it goes out of scope),
@@ -310,6 +333,8 @@ pub struct Variable {
     pub range_upper_bound: i64,
     /// The role of this variable.
     pub role: VariantRole,
+    /// For enums (variables with a [VariantRole::VariantPart]), the name of the active variant, e.g. `Some` or `Err`.
+    pub variant_name: Option<String>,
 }
 
 impl Variable {
@@ -154,6 +154,18 @@ pub enum FlashError {
     /// No core can access this RAM region.
     #[error("No core can access the ram region {0:?}.")]
     NoRamCoreAccess(RamRegion),
+    /// The RAM region used to run the flash algorithm is too small for it.
+    #[error("Flash algorithm {name} requires {required_size} bytes of RAM from {load_address:#010x}, but the RAM region {region:?} ends at {:#010x}.", .region.range.end)]
+    FlashAlgorithmDoesNotFitInRam {
+        /// The name of the flash algorithm.
+        name: String,
+        /// The address the flash algorithm would be loaded to.
+        load_address: u64,
+        /// The size of the RAM required by the flash algorithm, in bytes.
+        required_size: u64,
+        /// The RAM region which is too small.
+        region: RamRegion,
+    },
     /// The register value supplied for this flash algorithm is out of the supported range.
     #[error("The register value {0:08X?} is out of the supported range.")]
     RegisterValueNotSupported(u64),
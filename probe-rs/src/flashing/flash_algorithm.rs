@@ -50,6 +50,19 @@ pub struct FlashAlgorithm {
     pub flash_properties: FlashProperties,
 }
 
+/// The placement of a flash algorithm in RAM, as computed by [`FlashAlgorithm::ram_layout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FlashAlgorithmLayout {
+    /// The address the header and the instructions are loaded to.
+    pub load_address: u64,
+    /// The size of the header and the instructions, in bytes.
+    pub code_size: u64,
+    /// The size of the stack, in bytes, which is placed directly after the instructions.
+    pub stack_size: u64,
+    /// The base addresses of the page buffers, which start at the top of the stack.
+    pub page_buffers: Vec<u64>,
+}
+
 impl FlashAlgorithm {
     /// Try to retrieve the information about the flash sector
     /// which contains `address`.
@@ -190,6 +203,95 @@ impl FlashAlgorithm {
         }
     }
 
+    /// The size of the header which is placed in front of the instructions of a flash algorithm, in bytes.
+    pub(crate) fn algorithm_header_size(architecture: Architecture) -> u64 {
+        size_of_val(Self::get_algorithm_header(architecture)) as u64
+    }
+
+    /// The stack size used for `raw`, which is at least [`FlashAlgorithm::FLASH_ALGO_STACK_DECREMENT`] bytes.
+    pub(crate) fn stack_size(raw: &RawFlashAlgorithm) -> u32 {
+        let stack_size = raw.stack_size.unwrap_or(Self::FLASH_ALGO_STACK_SIZE);
+        if stack_size < Self::FLASH_ALGO_STACK_DECREMENT {
+            // If the stack size is less than one decrement, we
+            // won't enter the loop (below), and we'll produce a variety
+            // of addresses that all start at zero (above).
+            // Let's make sure we have a chance to compute other addresses
+            // by using a reasonable minimum stack size.
+            tracing::warn!(
+                "Stack size of {} bytes is too small; overriding to {} bytes",
+                stack_size,
+                Self::FLASH_ALGO_STACK_DECREMENT
+            );
+            Self::FLASH_ALGO_STACK_DECREMENT
+        } else {
+            stack_size
+        }
+    }
+
+    /// The placement of `raw` in `ram_region`: the header and the instructions, followed by the stack and the page buffers.
+    ///
+    /// If the code, the stack and one page buffer don't fit into the region, the stack is reduced in steps of
+    /// [`FlashAlgorithm::FLASH_ALGO_STACK_DECREMENT`] bytes. A second page buffer is only added if there is space left for it.
+    pub(crate) fn ram_layout(
+        raw: &RawFlashAlgorithm,
+        ram_region: &RamRegion,
+        architecture: Architecture,
+    ) -> Result<FlashAlgorithmLayout, FlashError> {
+        let header_size = Self::algorithm_header_size(architecture);
+
+        let load_address = match raw.load_address {
+            // The header is placed in front of the instructions.
+            Some(address) => address
+                .checked_sub(header_size)
+                .ok_or(FlashError::InvalidFlashAlgorithmLoadAddress { address })?,
+            None => ram_region.range.start,
+        };
+        if load_address < ram_region.range.start {
+            return Err(FlashError::InvalidFlashAlgorithmLoadAddress {
+                address: load_address,
+            });
+        }
+
+        let code_size = header_size + raw.instructions.len() as u64;
+        let page_size = raw.flash_properties.page_size as u64;
+        let available = ram_region.range.end.saturating_sub(load_address);
+        let fits = |size: u64| code_size + size <= available;
+
+        // Try to find a stack size that fits with at least one page of data.
+        let requested_stack_size = Self::stack_size(raw);
+        let mut stack_sizes = (0..requested_stack_size / Self::FLASH_ALGO_STACK_DECREMENT)
+            .map(|i| (requested_stack_size - Self::FLASH_ALGO_STACK_DECREMENT * i) as u64);
+
+        let Some(stack_size) = stack_sizes.find(|&stack_size| fits(stack_size + page_size)) else {
+            let smallest_stack_size = (requested_stack_size % Self::FLASH_ALGO_STACK_DECREMENT
+                + Self::FLASH_ALGO_STACK_DECREMENT) as u64;
+
+            return Err(FlashError::FlashAlgorithmDoesNotFitInRam {
+                name: raw.name.clone(),
+                load_address,
+                required_size: code_size + smallest_stack_size + page_size,
+                region: ram_region.clone(),
+            });
+        };
+        tracing::debug!("The flash algorithm will be configured with {stack_size} bytes of stack");
+
+        // Data buffer 1 starts at the top of the stack.
+        let begin_data = load_address + code_size + stack_size;
+        let mut page_buffers = vec![begin_data];
+
+        // Determine whether we can use double buffering or not by the remaining RAM region size.
+        if fits(stack_size + 2 * page_size) {
+            page_buffers.push(begin_data + page_size);
+        }
+
+        Ok(FlashAlgorithmLayout {
+            load_address,
+            code_size,
+            stack_size,
+            page_buffers,
+        })
+    }
+
     /// Constructs a complete flash algorithm, tailored to the flash and RAM sizes given.
     pub fn assemble_from_raw(
         raw: &RawFlashAlgorithm,
@@ -223,83 +325,16 @@ impl FlashAlgorithm {
             )
             .collect();
 
-        let mut offset = 0;
-        let mut addr_stack = 0;
-        let mut addr_load = 0;
-        let mut addr_data = 0;
-        let mut code_start = 0;
-
-        // Try to find a stack size that fits with at least one page of data.
-        let stack_size = {
-            let stack_size = raw.stack_size.unwrap_or(Self::FLASH_ALGO_STACK_SIZE);
-            if stack_size < Self::FLASH_ALGO_STACK_DECREMENT {
-                // If the stack size is less than one decrement, we
-                // won't enter the loop (below), and we'll produce a variety
-                // of addresses that all start at zero (above).
-                // Let's make sure we have a chance to compute other addresses
-                // by using a reasonable minimum stack size.
-                tracing::warn!(
-                    "Stack size of {} bytes is too small; overriding to {} bytes",
-                    stack_size,
-                    Self::FLASH_ALGO_STACK_DECREMENT
-                );
-                Self::FLASH_ALGO_STACK_DECREMENT
-            } else {
-                stack_size
-            }
-        };
-        tracing::debug!("The flash algorithm will be configured with {stack_size} bytes of stack");
-
-        for i in 0..stack_size / Self::FLASH_ALGO_STACK_DECREMENT {
-            // Load address
-            addr_load = raw
-                .load_address
-                .map(|a| {
-                    a.checked_sub(std::mem::size_of_val(header) as u64) // adjust the raw load address to account for the algo header
-                        .ok_or(FlashError::InvalidFlashAlgorithmLoadAddress { address: addr_load })
-                })
-                .unwrap_or(Ok(ram_region.range.start))?;
-            if addr_load < ram_region.range.start {
-                return Err(FlashError::InvalidFlashAlgorithmLoadAddress { address: addr_load });
-            }
-            offset += (std::mem::size_of_val(header)) as u64;
-            code_start = addr_load + offset;
-            offset += (instructions.len() * size_of::<u32>()) as u64;
-
-            // Stack start address (desc)
-            addr_stack = addr_load
-                + offset
-                + (stack_size
-                    .checked_sub(Self::FLASH_ALGO_STACK_DECREMENT * i)
-                    .expect("Overflow never happens; decrement multiples are always less than stack size."))
-                    as u64;
-
-            // Data buffer 1
-            addr_data = addr_stack;
-            offset += raw.flash_properties.page_size as u64;
-
-            if offset <= ram_region.range.end - addr_load {
-                break;
-            }
-        }
-
-        // Data buffer 2
-        let addr_data2 = addr_data + raw.flash_properties.page_size as u64;
-        offset += raw.flash_properties.page_size as u64;
-
-        // Determine whether we can use double buffering or not by the remaining RAM region size.
-        let page_buffers = if offset <= ram_region.range.end - addr_load {
-            vec![addr_data, addr_data2]
-        } else {
-            vec![addr_data]
-        };
+        let layout = Self::ram_layout(raw, ram_region, target.architecture())?;
+        let code_start = layout.load_address + size_of_val(header) as u64;
+        let begin_stack = layout.load_address + layout.code_size + layout.stack_size;
 
         let name = raw.name.clone();
 
         Ok(FlashAlgorithm {
             name,
             default: raw.default,
-            load_address: addr_load,
+            load_address: layout.load_address,
             instructions,
             pc_init: raw.pc_init.map(|v| code_start + v),
             pc_uninit: raw.pc_uninit.map(|v| code_start + v),
@@ -307,9 +342,9 @@ impl FlashAlgorithm {
             pc_erase_sector: code_start + raw.pc_erase_sector,
             pc_erase_all: raw.pc_erase_all.map(|v| code_start + v),
             static_base: code_start + raw.data_section_offset,
-            begin_stack: addr_stack,
-            begin_data: page_buffers[0],
-            page_buffers: page_buffers.clone(),
+            begin_stack,
+            begin_data: layout.page_buffers[0],
+            page_buffers: layout.page_buffers,
             rtt_control_block: raw.rtt_location,
             flash_properties: raw.flash_properties.clone(),
         })
@@ -401,6 +401,38 @@ impl DapAccess for FakeArmInterface<Initialized> {
     }
 }
 
+/// Only the `dtmcs` register of a RISC-V debug transport module is emulated, which is enough to create
+/// a [`RiscvCommunicationInterface`](crate::architecture::riscv::communication_interface::RiscvCommunicationInterface)
+/// for tests which don't access the debug module.
+#[cfg(test)]
+impl crate::probe::JTAGAccess for FakeProbe {
+    fn read_register(&mut self, address: u32, _len: u32) -> Result<Vec<u8>, DebugProbeError> {
+        // `dtmcs`: version 0.13, with 7 address bits.
+        assert_eq!(
+            address, 0x10,
+            "Unexpected JTAG register read from {address:#x}"
+        );
+        Ok(0x71u32.to_le_bytes().to_vec())
+    }
+
+    fn set_idle_cycles(&mut self, _idle_cycles: u8) {}
+
+    fn get_idle_cycles(&self) -> u8 {
+        0
+    }
+
+    fn set_ir_len(&mut self, _len: u32) {}
+
+    fn write_register(
+        &mut self,
+        _address: u32,
+        _data: &[u8],
+        _len: u32,
+    ) -> Result<Vec<u8>, DebugProbeError> {
+        unimplemented!()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::FakeProbe;
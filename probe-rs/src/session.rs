@@ -92,7 +92,25 @@ impl Session {
         attach_method: AttachMethod,
         permissions: Permissions,
     ) -> Result<Self, Error> {
-        let (probe, target) = get_target_from_selector(target, attach_method, probe)?;
+        let (mut probe, target) = get_target_from_selector(target, attach_method, probe)?;
+
+        // Some targets can not be debugged reliably at high clock speeds, so cap the speed if necessary.
+        if let Some(max_speed_khz) = target.recommended_debug_clock() {
+            if probe.speed_khz() > max_speed_khz {
+                match probe.set_speed(max_speed_khz) {
+                    Ok(speed_khz) => tracing::info!(
+                        "Reduced the debug clock to {} kHz, as recommended for {}",
+                        speed_khz,
+                        target.name
+                    ),
+                    Err(error) => tracing::warn!(
+                        "Unable to reduce the debug clock to the recommended {} kHz: {}",
+                        max_speed_khz,
+                        error
+                    ),
+                }
+            }
+        }
 
         let cores = target
             .cores
@@ -633,6 +651,7 @@ fn get_target_from_selector(
         TargetSelector::Specified(target) => target,
         TargetSelector::Auto => {
             let mut found_chip = None;
+            let mut found_target = None;
 
             // At this point we do not know what the target is, so we cannot use the chip specific reset sequence.
             // Thus, we try just using a normal reset for target detection if we want to do so under reset.
@@ -679,6 +698,17 @@ fn get_target_from_selector(
 
                         tracing::debug!("ID Code read over JTAG: {:x?}", idcode);
 
+                        found_target = idcode.ok().and_then(|idcode| {
+                            crate::config::get_target_by_idcode(idcode)
+                                .map_err(|error| {
+                                    tracing::debug!(
+                                        "Unable to identify the chip from its IDCODE: {}",
+                                        error
+                                    )
+                                })
+                                .ok()
+                        });
+
                         probe = interface.close();
                     }
                     Err((returned_probe, err)) => {
@@ -695,6 +725,8 @@ fn get_target_from_selector(
 
             if let Some(chip) = found_chip {
                 crate::config::get_target_by_chip_info(chip)?
+            } else if let Some(target) = found_target {
+                target
             } else {
                 return Err(Error::ChipNotFound(RegistryError::ChipAutodetectFailed));
             }
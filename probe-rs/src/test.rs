@@ -1,6 +1,13 @@
 //! Helpers for testing the crate
 
-use crate::MemoryInterface;
+use std::time::Duration;
+
+use crate::{
+    architecture::arm::core::registers::cortex_m::{CORTEX_M_CORE_REGISTERS, FP, PC, RA, SP},
+    debug::DebugRegisters,
+    Architecture, CoreInformation, CoreInterface, CoreRegister, CoreRegisters, CoreStatus,
+    CoreType, InstructionSet, MemoryInterface, RegisterId, RegisterValue,
+};
 
 #[derive(Debug)]
 pub(crate) struct MockMemory {
@@ -68,6 +75,20 @@ impl MockMemory {
         self.add_range(address, bytes);
     }
 
+    /// Overwrite existing data at `address`. Writes to memory which was not added before are not supported.
+    fn write_existing(&mut self, address: u64, data: &[u8]) {
+        let end = address + data.len() as u64;
+        let Some(index) = self.values.iter().position(|(start, stored_data)| {
+            *start <= address && end <= *start + stored_data.len() as u64
+        }) else {
+            self.missing_range(address, end)
+        };
+
+        let (start, stored_data) = &mut self.values[index];
+        let offset = (address - *start) as usize;
+        stored_data[offset..offset + data.len()].copy_from_slice(data);
+    }
+
     fn missing_range(&self, start: u64, end: u64) -> ! {
         panic!("No entry for range {:#010x} - {:#010x}", start, end);
     }
@@ -89,8 +110,11 @@ impl MemoryInterface for MockMemory {
         Ok(u32::from_le_bytes(bytes))
     }
 
-    fn read_word_8(&mut self, _address: u64) -> anyhow::Result<u8, crate::Error> {
-        todo!()
+    fn read_word_8(&mut self, address: u64) -> anyhow::Result<u8, crate::Error> {
+        let mut bytes = [0u8; 1];
+        self.read_8(address, &mut bytes)?;
+
+        Ok(bytes[0])
     }
 
     fn read_64(&mut self, _address: u64, _data: &mut [u64]) -> anyhow::Result<(), crate::Error> {
@@ -160,12 +184,14 @@ impl MemoryInterface for MockMemory {
         todo!()
     }
 
-    fn write_word_32(&mut self, _address: u64, _data: u32) -> anyhow::Result<(), crate::Error> {
-        todo!()
+    fn write_word_32(&mut self, address: u64, data: u32) -> anyhow::Result<(), crate::Error> {
+        self.write_existing(address, &data.to_le_bytes());
+        Ok(())
     }
 
-    fn write_word_8(&mut self, _address: u64, _data: u8) -> anyhow::Result<(), crate::Error> {
-        todo!()
+    fn write_word_8(&mut self, address: u64, data: u8) -> anyhow::Result<(), crate::Error> {
+        self.write_existing(address, &[data]);
+        Ok(())
     }
 
     fn write_64(&mut self, _address: u64, _data: &[u64]) -> anyhow::Result<(), crate::Error> {
@@ -185,6 +211,213 @@ impl MemoryInterface for MockMemory {
     }
 }
 
+/// A halted Cortex-M core, with fixed register values and memory contents.
+///
+/// Only the functionality required to read registers and memory is implemented.
+#[derive(Debug)]
+pub(crate) struct MockCore {
+    id: usize,
+    registers: DebugRegisters,
+    memory: MockMemory,
+}
+
+impl MockCore {
+    pub(crate) fn new(id: usize, registers: DebugRegisters, memory: MockMemory) -> Self {
+        MockCore {
+            id,
+            registers,
+            memory,
+        }
+    }
+}
+
+impl MemoryInterface for MockCore {
+    fn supports_native_64bit_access(&mut self) -> bool {
+        self.memory.supports_native_64bit_access()
+    }
+
+    fn read_word_64(&mut self, address: u64) -> anyhow::Result<u64, crate::Error> {
+        self.memory.read_word_64(address)
+    }
+
+    fn read_word_32(&mut self, address: u64) -> anyhow::Result<u32, crate::Error> {
+        self.memory.read_word_32(address)
+    }
+
+    fn read_word_8(&mut self, address: u64) -> anyhow::Result<u8, crate::Error> {
+        self.memory.read_word_8(address)
+    }
+
+    fn read_64(&mut self, address: u64, data: &mut [u64]) -> anyhow::Result<(), crate::Error> {
+        self.memory.read_64(address, data)
+    }
+
+    fn read_32(&mut self, address: u64, data: &mut [u32]) -> anyhow::Result<(), crate::Error> {
+        self.memory.read_32(address, data)
+    }
+
+    fn read_8(&mut self, address: u64, data: &mut [u8]) -> anyhow::Result<(), crate::Error> {
+        self.memory.read_8(address, data)
+    }
+
+    fn supports_8bit_transfers(&self) -> Result<bool, crate::Error> {
+        self.memory.supports_8bit_transfers()
+    }
+
+    fn write_word_64(&mut self, address: u64, data: u64) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_word_64(address, data)
+    }
+
+    fn write_word_32(&mut self, address: u64, data: u32) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_word_32(address, data)
+    }
+
+    fn write_word_8(&mut self, address: u64, data: u8) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_word_8(address, data)
+    }
+
+    fn write_64(&mut self, address: u64, data: &[u64]) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_64(address, data)
+    }
+
+    fn write_32(&mut self, address: u64, data: &[u32]) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_32(address, data)
+    }
+
+    fn write_8(&mut self, address: u64, data: &[u8]) -> anyhow::Result<(), crate::Error> {
+        self.memory.write_8(address, data)
+    }
+
+    fn flush(&mut self) -> anyhow::Result<(), crate::Error> {
+        self.memory.flush()
+    }
+}
+
+impl CoreInterface for MockCore {
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn wait_for_core_halted(&mut self, _timeout: Duration) -> Result<(), crate::Error> {
+        Ok(())
+    }
+
+    fn core_halted(&mut self) -> Result<bool, crate::Error> {
+        Ok(true)
+    }
+
+    fn status(&mut self) -> Result<CoreStatus, crate::Error> {
+        todo!()
+    }
+
+    fn halt(&mut self, _timeout: Duration) -> Result<CoreInformation, crate::Error> {
+        todo!()
+    }
+
+    fn run(&mut self) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn reset(&mut self) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn reset_and_halt(&mut self, _timeout: Duration) -> Result<CoreInformation, crate::Error> {
+        todo!()
+    }
+
+    fn step(&mut self) -> Result<CoreInformation, crate::Error> {
+        todo!()
+    }
+
+    fn read_core_reg(&mut self, address: RegisterId) -> Result<RegisterValue, crate::Error> {
+        self.registers
+            .get_register(address)
+            .and_then(|register| register.value)
+            .ok_or_else(|| anyhow::anyhow!("No value for register {:?}", address).into())
+    }
+
+    fn write_core_reg(
+        &mut self,
+        _address: RegisterId,
+        _value: RegisterValue,
+    ) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn available_breakpoint_units(&mut self) -> Result<u32, crate::Error> {
+        todo!()
+    }
+
+    fn hw_breakpoints(&mut self) -> Result<Vec<Option<u64>>, crate::Error> {
+        todo!()
+    }
+
+    fn enable_breakpoints(&mut self, _state: bool) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn set_hw_breakpoint(&mut self, _unit_index: usize, _addr: u64) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn clear_hw_breakpoint(&mut self, _unit_index: usize) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn registers(&self) -> &'static CoreRegisters {
+        &CORTEX_M_CORE_REGISTERS
+    }
+
+    fn program_counter(&self) -> &'static CoreRegister {
+        &PC
+    }
+
+    fn frame_pointer(&self) -> &'static CoreRegister {
+        &FP
+    }
+
+    fn stack_pointer(&self) -> &'static CoreRegister {
+        &SP
+    }
+
+    fn return_address(&self) -> &'static CoreRegister {
+        &RA
+    }
+
+    fn hw_breakpoints_enabled(&self) -> bool {
+        false
+    }
+
+    fn architecture(&self) -> Architecture {
+        Architecture::Arm
+    }
+
+    fn core_type(&self) -> CoreType {
+        CoreType::Armv6m
+    }
+
+    fn instruction_set(&mut self) -> Result<InstructionSet, crate::Error> {
+        Ok(InstructionSet::Thumb2)
+    }
+
+    fn fpu_support(&mut self) -> Result<bool, crate::Error> {
+        Ok(false)
+    }
+
+    fn reset_catch_set(&mut self) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn reset_catch_clear(&mut self) -> Result<(), crate::Error> {
+        todo!()
+    }
+
+    fn debug_core_stop(&mut self) -> Result<(), crate::Error> {
+        todo!()
+    }
+}
+
 #[test]
 fn mock_memory_read() {
     let mut mock_memory = MockMemory::new();
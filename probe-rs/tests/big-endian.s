@@ -0,0 +1,18 @@
+    .syntax unified
+    .arm
+    .text
+    .globl  add_one
+    .type   add_one, %function
+add_one:
+    add     r0, r0, #1
+    bx      lr
+    .size   add_one, .-add_one
+
+    .globl  _start
+    .type   _start, %function
+_start:
+    mov     r0, #41
+    bl      add_one
+1:
+    b       1b
+    .size   _start, .-_start
@@ -0,0 +1,15 @@
+volatile int sink;
+
+int valid(int value) {
+    return value + 1;
+}
+
+int broken(int value) {
+    return value * 2;
+}
+
+void _start(void) {
+    sink = broken(valid(sink));
+    for (;;) {
+    }
+}
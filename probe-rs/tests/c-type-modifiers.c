@@ -0,0 +1,22 @@
+typedef unsigned int uint32_t;
+
+struct point {
+    int x;
+    int y;
+};
+
+typedef struct point point_t;
+
+uint32_t typedef_variable = 1;
+const uint32_t const_variable = 2;
+volatile uint32_t volatile_variable = 3;
+const volatile uint32_t *const_volatile_pointer = &volatile_variable;
+const char *const const_pointer_to_const = "const";
+point_t typedef_struct_variable = {1, 2};
+const point_t *typedef_struct_pointer = &typedef_struct_variable;
+char **pointer_to_pointer = 0;
+
+void _start(void) {
+    for (;;) {
+    }
+}
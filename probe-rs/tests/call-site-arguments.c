@@ -0,0 +1,14 @@
+volatile int sink;
+
+__attribute__((noinline)) void report(int value, int scale) { sink = value * scale; }
+
+static inline __attribute__((always_inline)) void scaled_report(int value, int factor) {
+    report(value + 1, factor);
+}
+
+void _start(void) {
+    int value = sink;
+    scaled_report(value, 3);
+    for (;;) {
+    }
+}
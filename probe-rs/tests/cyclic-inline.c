@@ -0,0 +1,19 @@
+/* `inner` is inlined into `outer`, which is inlined into `_start`. */
+static volatile int sink;
+
+static inline __attribute__((always_inline)) void inner(int value) {
+    sink = value * 3;
+    sink = value + 1;
+}
+
+static inline __attribute__((always_inline)) void outer(int value) {
+    sink = value;
+    inner(value + 2);
+    sink = value - 1;
+}
+
+void _start(void) {
+    outer(5);
+    for (;;) {
+    }
+}
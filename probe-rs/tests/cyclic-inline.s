@@ -0,0 +1,413 @@
+	.file	"cyclic-inline.c"
+	.text
+.Ltext0:
+	.file 1 "cyclic-inline.c"
+	.p2align 4
+	.globl	_start
+	.type	_start, @function
+_start:
+.LFB2:
+	# cyclic-inline.c:15:19
+	.loc 1 15 19 view -0
+	.cfi_startproc
+# BLOCK 2, count:10631108 (estimated locally) seq:0
+# PRED: ENTRY [always]  count:10631108 (estimated locally) (FALLTHRU)
+	# cyclic-inline.c:16:5
+	.loc 1 16 5 view .LVU1
+.LVL0:
+	# DEBUG value => 0x5
+.LBB8:
+.LBI8:
+	# cyclic-inline.c:9:51
+	.loc 1 9 51 view .LVU2
+.LBB9:
+	# cyclic-inline.c:10:5
+	.loc 1 10 5 view .LVU3
+	# cyclic-inline.c:10:10
+	.loc 1 10 10 is_stmt 0 view .LVU4
+	movl	$5, sink(%rip)
+	# cyclic-inline.c:11:5
+	.loc 1 11 5 is_stmt 1 view .LVU5
+.LVL1:
+	# DEBUG value => 0x7
+.LBB10:
+.LBI10:
+	# cyclic-inline.c:4:51
+	.loc 1 4 51 view .LVU6
+.LBB11:
+	# cyclic-inline.c:5:5
+	.loc 1 5 5 view .LVU7
+	# cyclic-inline.c:5:10
+	.loc 1 5 10 is_stmt 0 view .LVU8
+	movl	$21, sink(%rip)
+	# cyclic-inline.c:6:5
+	.loc 1 6 5 is_stmt 1 view .LVU9
+	# cyclic-inline.c:6:10
+	.loc 1 6 10 is_stmt 0 view .LVU10
+	movl	$8, sink(%rip)
+.LVL2:
+	# DEBUG value RESET
+	# cyclic-inline.c:6:10
+	.loc 1 6 10 view .LVU11
+.LBE11:
+.LBE10:
+	# cyclic-inline.c:12:5
+	.loc 1 12 5 is_stmt 1 view .LVU12
+# SUCC: 3 [always]  count:10631108 (estimated locally) (FALLTHRU,CAN_FALLTHRU) cyclic-inline.c:13:1
+	# cyclic-inline.c:12:10
+	.loc 1 12 10 is_stmt 0 view .LVU13
+	movl	$4, sink(%rip)
+.LVL3:
+	# DEBUG value RESET
+# BLOCK 3, count:0 (estimated locally) seq:1
+# PRED: 2 [always]  count:10631108 (estimated locally) (FALLTHRU,CAN_FALLTHRU) cyclic-inline.c:13:1 3 [always]  count:0 (estimated locally) (DFS_BACK,CAN_FALLTHRU)
+.L2:
+	# cyclic-inline.c:12:10
+	.loc 1 12 10 view .LVU14
+.LBE9:
+.LBE8:
+	# cyclic-inline.c:17:5
+	.loc 1 17 5 is_stmt 1 discriminator 1 view .LVU15
+	# cyclic-inline.c:18:5
+	.loc 1 18 5 discriminator 1 view .LVU16
+	# cyclic-inline.c:17:5
+	.loc 1 17 5 discriminator 1 view .LVU17
+	# cyclic-inline.c:17:5
+	.loc 1 17 5 discriminator 1 view .LVU18
+	# cyclic-inline.c:18:5
+	.loc 1 18 5 discriminator 1 view .LVU19
+	# cyclic-inline.c:17:5
+	.loc 1 17 5 discriminator 1 view .LVU20
+# SUCC: 3 [always]  count:0 (estimated locally) (DFS_BACK,CAN_FALLTHRU)
+	jmp	.L2
+	.cfi_endproc
+.LFE2:
+	.size	_start, .-_start
+	.local	sink
+	.comm	sink,4,4
+.Letext0:
+	.section	.debug_info,"",@progbits
+.Ldebug_info0:
+	.long	0xf9	# Length of Compilation Unit Info
+	.value	0x4	# DWARF version number
+	.long	.Ldebug_abbrev0	# Offset Into Abbrev. Section
+	.byte	0x8	# Pointer Size (in bytes)
+	.uleb128 0x1	# (DIE (0xb) DW_TAG_compile_unit)
+	.long	.LASF1	# DW_AT_producer: "GNU C17 12.2.0 -mtune=generic -march=x86-64 -g -gdwarf-4 -O2 -fasynchronous-unwind-tables"
+	.byte	0xc	# DW_AT_language
+	.long	.LASF2	# DW_AT_name: "cyclic-inline.c"
+	.long	.LASF3	# DW_AT_comp_dir: "/tmp/cfx"
+	.quad	.Ltext0	# DW_AT_low_pc
+	.quad	.Letext0-.Ltext0	# DW_AT_high_pc
+	.long	.Ldebug_line0	# DW_AT_stmt_list
+	.uleb128 0x2	# (DIE (0x2d) DW_TAG_variable)
+	.long	.LASF4	# DW_AT_name: "sink"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0x2	# DW_AT_decl_line
+	.byte	0x15	# DW_AT_decl_column
+	.long	0x4a	# DW_AT_type
+	.uleb128 0x9	# DW_AT_location
+	.byte	0x3	# DW_OP_addr
+	.quad	sink
+	.uleb128 0x3	# (DIE (0x43) DW_TAG_base_type)
+	.byte	0x4	# DW_AT_byte_size
+	.byte	0x5	# DW_AT_encoding
+	.ascii "int\0"	# DW_AT_name
+	.uleb128 0x4	# (DIE (0x4a) DW_TAG_volatile_type)
+	.long	0x43	# DW_AT_type
+	.uleb128 0x5	# (DIE (0x4f) DW_TAG_subprogram)
+			# DW_AT_external
+	.long	.LASF5	# DW_AT_name: "_start"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0xf	# DW_AT_decl_line
+	.byte	0x6	# DW_AT_decl_column
+			# DW_AT_prototyped
+	.quad	.LFB2	# DW_AT_low_pc
+	.quad	.LFE2-.LFB2	# DW_AT_high_pc
+	.uleb128 0x1	# DW_AT_frame_base
+	.byte	0x9c	# DW_OP_call_frame_cfa
+			# DW_AT_GNU_all_call_sites
+	.long	0xcc	# DW_AT_sibling
+	.uleb128 0x6	# (DIE (0x6d) DW_TAG_inlined_subroutine)
+	.long	0xcc	# DW_AT_abstract_origin
+	.quad	.LBI8	# DW_AT_entry_pc
+	.byte	.LVU2	# DW_AT_GNU_entry_view
+	.quad	.LBB8	# DW_AT_low_pc
+	.quad	.LBE8-.LBB8	# DW_AT_high_pc
+	.byte	0x1	# DW_AT_call_file (cyclic-inline.c)
+	.byte	0x10	# DW_AT_call_line
+	.byte	0x5	# DW_AT_call_column
+	.uleb128 0x7	# (DIE (0x8e) DW_TAG_formal_parameter)
+	.long	0xd9	# DW_AT_abstract_origin
+	.long	.LLST0	# DW_AT_location
+	.long	.LVUS0	# DW_AT_GNU_locviews
+	.uleb128 0x6	# (DIE (0x9b) DW_TAG_inlined_subroutine)
+	.long	0x6d	# DW_AT_abstract_origin
+	.quad	.LBI10	# DW_AT_entry_pc
+	.byte	.LVU6	# DW_AT_GNU_entry_view
+	.quad	.LBB10	# DW_AT_low_pc
+	.quad	.LBE10-.LBB10	# DW_AT_high_pc
+	.byte	0x1	# DW_AT_call_file (cyclic-inline.c)
+	.byte	0xb	# DW_AT_call_line
+	.byte	0x5	# DW_AT_call_column
+	.uleb128 0x7	# (DIE (0xbc) DW_TAG_formal_parameter)
+	.long	0xef	# DW_AT_abstract_origin
+	.long	.LLST1	# DW_AT_location
+	.long	.LVUS1	# DW_AT_GNU_locviews
+	.byte	0	# end of children of DIE 0x9b
+	.byte	0	# end of children of DIE 0x6d
+	.byte	0	# end of children of DIE 0x4f
+	.uleb128 0x8	# (DIE (0xcc) DW_TAG_subprogram)
+	.long	.LASF6	# DW_AT_name: "outer"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0x9	# DW_AT_decl_line
+	.byte	0x33	# DW_AT_decl_column
+			# DW_AT_prototyped
+	.byte	0x3	# DW_AT_inline
+	.long	0xe6	# DW_AT_sibling
+	.uleb128 0x9	# (DIE (0xd9) DW_TAG_formal_parameter)
+	.long	.LASF0	# DW_AT_name: "value"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0x9	# DW_AT_decl_line
+	.byte	0x3d	# DW_AT_decl_column
+	.long	0x43	# DW_AT_type
+	.byte	0	# end of children of DIE 0xcc
+	.uleb128 0xa	# (DIE (0xe6) DW_TAG_subprogram)
+	.long	.LASF7	# DW_AT_name: "inner"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0x4	# DW_AT_decl_line
+	.byte	0x33	# DW_AT_decl_column
+			# DW_AT_prototyped
+	.byte	0x3	# DW_AT_inline
+	.uleb128 0x9	# (DIE (0xef) DW_TAG_formal_parameter)
+	.long	.LASF0	# DW_AT_name: "value"
+	.byte	0x1	# DW_AT_decl_file (cyclic-inline.c)
+	.byte	0x4	# DW_AT_decl_line
+	.byte	0x3d	# DW_AT_decl_column
+	.long	0x43	# DW_AT_type
+	.byte	0	# end of children of DIE 0xe6
+	.byte	0	# end of children of DIE 0xb
+	.section	.debug_abbrev,"",@progbits
+.Ldebug_abbrev0:
+	.uleb128 0x1	# (abbrev code)
+	.uleb128 0x11	# (TAG: DW_TAG_compile_unit)
+	.byte	0x1	# DW_children_yes
+	.uleb128 0x25	# (DW_AT_producer)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x13	# (DW_AT_language)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x1b	# (DW_AT_comp_dir)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x11	# (DW_AT_low_pc)
+	.uleb128 0x1	# (DW_FORM_addr)
+	.uleb128 0x12	# (DW_AT_high_pc)
+	.uleb128 0x7	# (DW_FORM_data8)
+	.uleb128 0x10	# (DW_AT_stmt_list)
+	.uleb128 0x17	# (DW_FORM_sec_offset)
+	.byte	0
+	.byte	0
+	.uleb128 0x2	# (abbrev code)
+	.uleb128 0x34	# (TAG: DW_TAG_variable)
+	.byte	0	# DW_children_no
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x3a	# (DW_AT_decl_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3b	# (DW_AT_decl_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x39	# (DW_AT_decl_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x49	# (DW_AT_type)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.uleb128 0x2	# (DW_AT_location)
+	.uleb128 0x18	# (DW_FORM_exprloc)
+	.byte	0
+	.byte	0
+	.uleb128 0x3	# (abbrev code)
+	.uleb128 0x24	# (TAG: DW_TAG_base_type)
+	.byte	0	# DW_children_no
+	.uleb128 0xb	# (DW_AT_byte_size)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3e	# (DW_AT_encoding)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0x8	# (DW_FORM_string)
+	.byte	0
+	.byte	0
+	.uleb128 0x4	# (abbrev code)
+	.uleb128 0x35	# (TAG: DW_TAG_volatile_type)
+	.byte	0	# DW_children_no
+	.uleb128 0x49	# (DW_AT_type)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.byte	0
+	.byte	0
+	.uleb128 0x5	# (abbrev code)
+	.uleb128 0x2e	# (TAG: DW_TAG_subprogram)
+	.byte	0x1	# DW_children_yes
+	.uleb128 0x3f	# (DW_AT_external)
+	.uleb128 0x19	# (DW_FORM_flag_present)
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x3a	# (DW_AT_decl_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3b	# (DW_AT_decl_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x39	# (DW_AT_decl_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x27	# (DW_AT_prototyped)
+	.uleb128 0x19	# (DW_FORM_flag_present)
+	.uleb128 0x11	# (DW_AT_low_pc)
+	.uleb128 0x1	# (DW_FORM_addr)
+	.uleb128 0x12	# (DW_AT_high_pc)
+	.uleb128 0x7	# (DW_FORM_data8)
+	.uleb128 0x40	# (DW_AT_frame_base)
+	.uleb128 0x18	# (DW_FORM_exprloc)
+	.uleb128 0x2117	# (DW_AT_GNU_all_call_sites)
+	.uleb128 0x19	# (DW_FORM_flag_present)
+	.uleb128 0x1	# (DW_AT_sibling)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.byte	0
+	.byte	0
+	.uleb128 0x6	# (abbrev code)
+	.uleb128 0x1d	# (TAG: DW_TAG_inlined_subroutine)
+	.byte	0x1	# DW_children_yes
+	.uleb128 0x31	# (DW_AT_abstract_origin)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.uleb128 0x52	# (DW_AT_entry_pc)
+	.uleb128 0x1	# (DW_FORM_addr)
+	.uleb128 0x2138	# (DW_AT_GNU_entry_view)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x11	# (DW_AT_low_pc)
+	.uleb128 0x1	# (DW_FORM_addr)
+	.uleb128 0x12	# (DW_AT_high_pc)
+	.uleb128 0x7	# (DW_FORM_data8)
+	.uleb128 0x58	# (DW_AT_call_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x59	# (DW_AT_call_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x57	# (DW_AT_call_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.byte	0
+	.byte	0
+	.uleb128 0x7	# (abbrev code)
+	.uleb128 0x5	# (TAG: DW_TAG_formal_parameter)
+	.byte	0	# DW_children_no
+	.uleb128 0x31	# (DW_AT_abstract_origin)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.uleb128 0x2	# (DW_AT_location)
+	.uleb128 0x17	# (DW_FORM_sec_offset)
+	.uleb128 0x2137	# (DW_AT_GNU_locviews)
+	.uleb128 0x17	# (DW_FORM_sec_offset)
+	.byte	0
+	.byte	0
+	.uleb128 0x8	# (abbrev code)
+	.uleb128 0x2e	# (TAG: DW_TAG_subprogram)
+	.byte	0x1	# DW_children_yes
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x3a	# (DW_AT_decl_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3b	# (DW_AT_decl_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x39	# (DW_AT_decl_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x27	# (DW_AT_prototyped)
+	.uleb128 0x19	# (DW_FORM_flag_present)
+	.uleb128 0x20	# (DW_AT_inline)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x1	# (DW_AT_sibling)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.byte	0
+	.byte	0
+	.uleb128 0x9	# (abbrev code)
+	.uleb128 0x5	# (TAG: DW_TAG_formal_parameter)
+	.byte	0	# DW_children_no
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x3a	# (DW_AT_decl_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3b	# (DW_AT_decl_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x39	# (DW_AT_decl_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x49	# (DW_AT_type)
+	.uleb128 0x13	# (DW_FORM_ref4)
+	.byte	0
+	.byte	0
+	.uleb128 0xa	# (abbrev code)
+	.uleb128 0x2e	# (TAG: DW_TAG_subprogram)
+	.byte	0x1	# DW_children_yes
+	.uleb128 0x3	# (DW_AT_name)
+	.uleb128 0xe	# (DW_FORM_strp)
+	.uleb128 0x3a	# (DW_AT_decl_file)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x3b	# (DW_AT_decl_line)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x39	# (DW_AT_decl_column)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.uleb128 0x27	# (DW_AT_prototyped)
+	.uleb128 0x19	# (DW_FORM_flag_present)
+	.uleb128 0x20	# (DW_AT_inline)
+	.uleb128 0xb	# (DW_FORM_data1)
+	.byte	0
+	.byte	0
+	.byte	0
+	.section	.debug_loc,"",@progbits
+.Ldebug_loc0:
+.LVUS0:
+	.uleb128 .LVU2	# View list begin (*.LVUS0)
+	.uleb128 .LVU14	# View list end (*.LVUS0)
+.LLST0:
+	.quad	.LVL0-.Ltext0	# Location list begin address (*.LLST0)
+	.quad	.LVL3-.Ltext0	# Location list end address (*.LLST0)
+	.value	0x2	# Location expression size
+	.byte	0x35	# DW_OP_lit5
+	.byte	0x9f	# DW_OP_stack_value
+	.quad	0	# Location list terminator begin (*.LLST0)
+	.quad	0	# Location list terminator end (*.LLST0)
+.LVUS1:
+	.uleb128 .LVU6	# View list begin (*.LVUS1)
+	.uleb128 .LVU11	# View list end (*.LVUS1)
+.LLST1:
+	.quad	.LVL1-.Ltext0	# Location list begin address (*.LLST1)
+	.quad	.LVL2-.Ltext0	# Location list end address (*.LLST1)
+	.value	0x2	# Location expression size
+	.byte	0x37	# DW_OP_lit7
+	.byte	0x9f	# DW_OP_stack_value
+	.quad	0	# Location list terminator begin (*.LLST1)
+	.quad	0	# Location list terminator end (*.LLST1)
+	.section	.debug_aranges,"",@progbits
+	.long	0x2c	# Length of Address Ranges Info
+	.value	0x2	# DWARF aranges version
+	.long	.Ldebug_info0	# Offset of Compilation Unit Info
+	.byte	0x8	# Size of Address
+	.byte	0	# Size of Segment Descriptor
+	.value	0	# Pad to 16 byte boundary
+	.value	0
+	.quad	.Ltext0	# Address
+	.quad	.Letext0-.Ltext0	# Length
+	.quad	0
+	.quad	0
+	.section	.debug_line,"",@progbits
+.Ldebug_line0:
+	.section	.debug_str,"MS",@progbits,1
+.LASF3:
+	.string	"/tmp/cfx"
+.LASF1:
+	.string	"GNU C17 12.2.0 -mtune=generic -march=x86-64 -g -gdwarf-4 -O2 -fasynchronous-unwind-tables"
+.LASF6:
+	.string	"outer"
+.LASF5:
+	.string	"_start"
+.LASF2:
+	.string	"cyclic-inline.c"
+.LASF0:
+	.string	"value"
+.LASF4:
+	.string	"sink"
+.LASF7:
+	.string	"inner"
+	.ident	"GCC: (Debian 12.2.0-14+deb12u1) 12.2.0"
+	.section	.note.GNU-stack,"",@progbits
@@ -0,0 +1,26 @@
+volatile int sink;
+
+__attribute__((cold, noinline)) static void report(int value) { sink = value; }
+
+static inline __attribute__((always_inline)) int checked_add(int a, int b) {
+    int result = a + b;
+    if (__builtin_expect(result < a, 0)) {
+        report(a);
+        return 0;
+    }
+    return result;
+}
+
+__attribute__((noinline)) int accumulate(int count) {
+    int total = 0;
+    for (int i = 0; i < count; i++) {
+        total = checked_add(total, sink);
+    }
+    return total;
+}
+
+void _start(void) {
+    sink = accumulate(sink);
+    for (;;) {
+    }
+}
@@ -0,0 +1,17 @@
+/* Statics declared inside a function, one of them in a nested block. */
+int next_id(int reset) {
+    static int id = 5;
+    if (reset) {
+        static int resets;
+        resets++;
+        id = 0;
+    }
+    return id++;
+}
+
+void _start(void) {
+    volatile int result = next_id(0) + next_id(1);
+    (void)result;
+    for (;;) {
+    }
+}
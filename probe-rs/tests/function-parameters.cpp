@@ -0,0 +1,29 @@
+typedef unsigned int uint32_t;
+
+struct point {
+    int x;
+    int y;
+};
+
+volatile int sink;
+
+__attribute__((noinline)) int scale(uint32_t factor, const point *origin) {
+    return origin->x * factor;
+}
+
+__attribute__((noinline)) void ignore_first(int, long flags) { sink = flags; }
+
+struct counter {
+    int total;
+
+    __attribute__((noinline)) void add(int amount) { total += amount; }
+};
+
+extern "C" void _start(void) {
+    point origin = {sink, sink};
+    counter count = {0};
+    count.add(scale(2, &origin));
+    ignore_first(1, count.total);
+    for (;;) {
+    }
+}
@@ -0,0 +1,22 @@
+volatile int sink;
+
+int scoped(int argument) {
+    int outer = argument + 1;
+    sink = outer;
+    {
+        int inner = outer * 2;
+        sink = inner;
+        {
+            int innermost = inner + outer;
+            sink = innermost;
+        }
+    }
+    sink = 0;
+    return outer;
+}
+
+void _start(void) {
+    sink = scoped(sink);
+    for (;;) {
+    }
+}
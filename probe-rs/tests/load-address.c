@@ -0,0 +1,11 @@
+/* The code is linked at 0x401000, but loaded at 0x10001000. */
+int triple(int value) {
+    return value * 3;
+}
+
+void _start(void) {
+    volatile int result = triple(2);
+    (void)result;
+    for (;;) {
+    }
+}
@@ -0,0 +1,21 @@
+; A single function with DWARF debug information, compiled into a Mach-O object with
+; `llc -mtriple=x86_64-apple-macosx10.15.0 -filetype=obj -o mach-o-function mach-o-function.ll`.
+
+define i32 @mach_o_function(i32 %value) !dbg !6 {
+entry:
+  %result = add i32 %value, 1, !dbg !9
+  ret i32 %result, !dbg !10
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!3, !4}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "probe-rs test", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, enums: !2)
+!1 = !DIFile(filename: "mach-o-function.c", directory: "/tmp")
+!2 = !{}
+!3 = !{i32 2, !"Dwarf Version", i32 4}
+!4 = !{i32 2, !"Debug Info Version", i32 3}
+!5 = !DISubroutineType(types: !2)
+!6 = distinct !DISubprogram(name: "mach_o_function", scope: !1, file: !1, line: 1, type: !5, scopeLine: 1, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !2)
+!9 = !DILocation(line: 2, column: 5, scope: !6)
+!10 = !DILocation(line: 3, column: 5, scope: !6)
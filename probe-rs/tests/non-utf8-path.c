@@ -0,0 +1,9 @@
+int value;
+
+#line 1 "caf\351.c"
+void _start(void)
+{
+    value = 1;
+    for (;;) {
+    }
+}
@@ -0,0 +1,15 @@
+/* Two identical functions, which are merged by the linker with `--icf=all`. */
+int first_function(int value) {
+    return value * 3 + 1;
+}
+
+int second_function(int value) {
+    return value * 3 + 1;
+}
+
+void _start(void) {
+    volatile int result = first_function(1) + second_function(2);
+    (void)result;
+    for (;;) {
+    }
+}
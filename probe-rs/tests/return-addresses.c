@@ -0,0 +1,21 @@
+/* A function with a single return, and one with multiple returns. */
+volatile int sink;
+
+__attribute__((noinline)) int single_return(int value) {
+    sink = value;
+    return value * 3;
+}
+
+__attribute__((noinline)) int multiple_returns(int value) {
+    if (value < 0) {
+        return -1;
+    }
+    single_return(value);
+    return value;
+}
+
+void _start(void) {
+    multiple_returns(sink);
+    for (;;) {
+    }
+}
@@ -0,0 +1,396 @@
+	.file	"return-addresses.c"
+	.text
+.Ltext0:
+	.file 1 "return-addresses.c"
+	.p2align 4
+	.globl	single_return
+	.type	single_return, @function
+single_return:
+.LVL0:
+.LFB0:
+	.loc 1 4 56 view -0
+	.cfi_startproc
+	.loc 1 5 5 view .LVU1
+	.loc 1 5 10 is_stmt 0 view .LVU2
+	movl	%edi, sink(%rip)
+	.loc 1 6 5 is_stmt 1 view .LVU3
+	.loc 1 6 18 is_stmt 0 view .LVU4
+	leal	(%rdi,%rdi,2), %eax
+	.loc 1 7 1 view .LVU5
+	ret
+	.cfi_endproc
+.LFE0:
+	.size	single_return, .-single_return
+	.p2align 4
+	.globl	multiple_returns
+	.type	multiple_returns, @function
+multiple_returns:
+.LVL1:
+.LFB1:
+	.loc 1 9 59 is_stmt 1 view -0
+	.cfi_startproc
+	.loc 1 10 5 view .LVU7
+	.loc 1 10 8 is_stmt 0 view .LVU8
+	testl	%edi, %edi
+	js	.L5
+	.loc 1 13 5 is_stmt 1 view .LVU9
+	call	single_return
+.LVL2:
+	.loc 1 14 5 view .LVU10
+	.loc 1 14 12 is_stmt 0 view .LVU11
+	movl	%edi, %eax
+	.loc 1 14 12 epilogue_begin
+	ret
+	.p2align 4,,10
+	.p2align 3
+.L5:
+	.loc 1 11 16 view .LVU12
+	movl	$-1, %eax
+	.loc 1 15 1 epilogue_begin view .LVU13
+	ret
+	.cfi_endproc
+.LFE1:
+	.size	multiple_returns, .-multiple_returns
+	.p2align 4
+	.globl	_start
+	.type	_start, @function
+_start:
+.LFB2:
+	.loc 1 17 19 is_stmt 1 view -0
+	.cfi_startproc
+	.loc 1 18 5 view .LVU15
+	movl	sink(%rip), %edi
+	call	multiple_returns
+.LVL3:
+.L7:
+	.loc 1 19 5 discriminator 1 view .LVU16
+	.loc 1 20 5 discriminator 1 view .LVU17
+	.loc 1 19 5 discriminator 1 view .LVU18
+	.loc 1 19 5 discriminator 1 view .LVU19
+	.loc 1 20 5 discriminator 1 view .LVU20
+	.loc 1 19 5 discriminator 1 view .LVU21
+	jmp	.L7
+	.cfi_endproc
+.LFE2:
+	.size	_start, .-_start
+	.globl	sink
+	.bss
+	.align 4
+	.type	sink, @object
+	.size	sink, 4
+sink:
+	.zero	4
+	.text
+.Letext0:
+	.section	.debug_info,"",@progbits
+.Ldebug_info0:
+	.long	0xea
+	.value	0x4
+	.long	.Ldebug_abbrev0
+	.byte	0x8
+	.uleb128 0x1
+	.long	.LASF3
+	.byte	0xc
+	.long	.LASF4
+	.long	.LASF5
+	.quad	.Ltext0
+	.quad	.Letext0-.Ltext0
+	.long	.Ldebug_line0
+	.uleb128 0x2
+	.long	.LASF6
+	.byte	0x1
+	.byte	0x2
+	.byte	0xe
+	.long	0x4a
+	.uleb128 0x9
+	.byte	0x3
+	.quad	sink
+	.uleb128 0x3
+	.byte	0x4
+	.byte	0x5
+	.string	"int"
+	.uleb128 0x4
+	.long	0x43
+	.uleb128 0x5
+	.long	.LASF0
+	.byte	0x1
+	.byte	0x11
+	.byte	0x6
+	.quad	.LFB2
+	.quad	.LFE2-.LFB2
+	.uleb128 0x1
+	.byte	0x9c
+	.long	0x7b
+	.uleb128 0x6
+	.quad	.LVL3
+	.long	0x7b
+	.byte	0
+	.uleb128 0x7
+	.long	.LASF7
+	.byte	0x1
+	.byte	0x9
+	.byte	0x1f
+	.long	0x43
+	.quad	.LFB1
+	.quad	.LFE1-.LFB1
+	.uleb128 0x1
+	.byte	0x9c
+	.long	0xc0
+	.uleb128 0x8
+	.long	.LASF2
+	.byte	0x1
+	.byte	0x9
+	.byte	0x34
+	.long	0x43
+	.uleb128 0x1
+	.byte	0x55
+	.uleb128 0x9
+	.quad	.LVL2
+	.long	0xc0
+	.uleb128 0xa
+	.uleb128 0x1
+	.byte	0x55
+	.uleb128 0x2
+	.byte	0x75
+	.sleb128 0
+	.byte	0
+	.byte	0
+	.uleb128 0xb
+	.long	.LASF1
+	.byte	0x1
+	.byte	0x4
+	.byte	0x1f
+	.long	0x43
+	.quad	.LFB0
+	.quad	.LFE0-.LFB0
+	.uleb128 0x1
+	.byte	0x9c
+	.uleb128 0x8
+	.long	.LASF2
+	.byte	0x1
+	.byte	0x4
+	.byte	0x31
+	.long	0x43
+	.uleb128 0x1
+	.byte	0x55
+	.byte	0
+	.byte	0
+	.section	.debug_abbrev,"",@progbits
+.Ldebug_abbrev0:
+	.uleb128 0x1
+	.uleb128 0x11
+	.byte	0x1
+	.uleb128 0x25
+	.uleb128 0xe
+	.uleb128 0x13
+	.uleb128 0xb
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x1b
+	.uleb128 0xe
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x12
+	.uleb128 0x7
+	.uleb128 0x10
+	.uleb128 0x17
+	.byte	0
+	.byte	0
+	.uleb128 0x2
+	.uleb128 0x34
+	.byte	0
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x3a
+	.uleb128 0xb
+	.uleb128 0x3b
+	.uleb128 0xb
+	.uleb128 0x39
+	.uleb128 0xb
+	.uleb128 0x49
+	.uleb128 0x13
+	.uleb128 0x3f
+	.uleb128 0x19
+	.uleb128 0x2
+	.uleb128 0x18
+	.byte	0
+	.byte	0
+	.uleb128 0x3
+	.uleb128 0x24
+	.byte	0
+	.uleb128 0xb
+	.uleb128 0xb
+	.uleb128 0x3e
+	.uleb128 0xb
+	.uleb128 0x3
+	.uleb128 0x8
+	.byte	0
+	.byte	0
+	.uleb128 0x4
+	.uleb128 0x35
+	.byte	0
+	.uleb128 0x49
+	.uleb128 0x13
+	.byte	0
+	.byte	0
+	.uleb128 0x5
+	.uleb128 0x2e
+	.byte	0x1
+	.uleb128 0x3f
+	.uleb128 0x19
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x3a
+	.uleb128 0xb
+	.uleb128 0x3b
+	.uleb128 0xb
+	.uleb128 0x39
+	.uleb128 0xb
+	.uleb128 0x27
+	.uleb128 0x19
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x12
+	.uleb128 0x7
+	.uleb128 0x40
+	.uleb128 0x18
+	.uleb128 0x2117
+	.uleb128 0x19
+	.uleb128 0x1
+	.uleb128 0x13
+	.byte	0
+	.byte	0
+	.uleb128 0x6
+	.uleb128 0x4109
+	.byte	0
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x31
+	.uleb128 0x13
+	.byte	0
+	.byte	0
+	.uleb128 0x7
+	.uleb128 0x2e
+	.byte	0x1
+	.uleb128 0x3f
+	.uleb128 0x19
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x3a
+	.uleb128 0xb
+	.uleb128 0x3b
+	.uleb128 0xb
+	.uleb128 0x39
+	.uleb128 0xb
+	.uleb128 0x27
+	.uleb128 0x19
+	.uleb128 0x49
+	.uleb128 0x13
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x12
+	.uleb128 0x7
+	.uleb128 0x40
+	.uleb128 0x18
+	.uleb128 0x2117
+	.uleb128 0x19
+	.uleb128 0x1
+	.uleb128 0x13
+	.byte	0
+	.byte	0
+	.uleb128 0x8
+	.uleb128 0x5
+	.byte	0
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x3a
+	.uleb128 0xb
+	.uleb128 0x3b
+	.uleb128 0xb
+	.uleb128 0x39
+	.uleb128 0xb
+	.uleb128 0x49
+	.uleb128 0x13
+	.uleb128 0x2
+	.uleb128 0x18
+	.byte	0
+	.byte	0
+	.uleb128 0x9
+	.uleb128 0x4109
+	.byte	0x1
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x31
+	.uleb128 0x13
+	.byte	0
+	.byte	0
+	.uleb128 0xa
+	.uleb128 0x410a
+	.byte	0
+	.uleb128 0x2
+	.uleb128 0x18
+	.uleb128 0x2111
+	.uleb128 0x18
+	.byte	0
+	.byte	0
+	.uleb128 0xb
+	.uleb128 0x2e
+	.byte	0x1
+	.uleb128 0x3f
+	.uleb128 0x19
+	.uleb128 0x3
+	.uleb128 0xe
+	.uleb128 0x3a
+	.uleb128 0xb
+	.uleb128 0x3b
+	.uleb128 0xb
+	.uleb128 0x39
+	.uleb128 0xb
+	.uleb128 0x27
+	.uleb128 0x19
+	.uleb128 0x49
+	.uleb128 0x13
+	.uleb128 0x11
+	.uleb128 0x1
+	.uleb128 0x12
+	.uleb128 0x7
+	.uleb128 0x40
+	.uleb128 0x18
+	.uleb128 0x2117
+	.uleb128 0x19
+	.byte	0
+	.byte	0
+	.byte	0
+	.section	.debug_aranges,"",@progbits
+	.long	0x2c
+	.value	0x2
+	.long	.Ldebug_info0
+	.byte	0x8
+	.byte	0
+	.value	0
+	.value	0
+	.quad	.Ltext0
+	.quad	.Letext0-.Ltext0
+	.quad	0
+	.quad	0
+	.section	.debug_line,"",@progbits
+.Ldebug_line0:
+	.section	.debug_str,"MS",@progbits,1
+.LASF7:
+	.string	"multiple_returns"
+.LASF5:
+	.string	"/tmp/cfx"
+.LASF3:
+	.string	"GNU C17 12.2.0 -mtune=generic -march=x86-64 -g -gdwarf-4 -O2 -fasynchronous-unwind-tables"
+.LASF0:
+	.string	"_start"
+.LASF1:
+	.string	"single_return"
+.LASF4:
+	.string	"return-addresses.c"
+.LASF2:
+	.string	"value"
+.LASF6:
+	.string	"sink"
+	.ident	"GCC: (Debian 12.2.0-14+deb12u1) 12.2.0"
+	.section	.note.GNU-stack,"",@progbits
@@ -0,0 +1,34 @@
+//! Fixture with Rust enums, used to test the resolution of the active enum variant.
+#![no_std]
+#![no_main]
+
+pub enum Shape {
+    Circle(u32),
+    Rectangle { width: u16, height: u16 },
+    Empty,
+}
+
+#[used]
+#[no_mangle]
+static mut SHAPE: Shape = Shape::Rectangle {
+    width: 3,
+    height: 4,
+};
+
+#[used]
+#[no_mangle]
+static mut MAYBE: Option<u32> = Some(7);
+
+#[used]
+#[no_mangle]
+static mut NOTHING: Option<u32> = None;
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
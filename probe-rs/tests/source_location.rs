@@ -96,6 +96,7 @@ fn source_location() {
                 file: Some(file.to_owned()),
                 low_pc: Some(0x80006DE),
                 high_pc: Some(0x8000E0C),
+                statement_range: None,
             }),
             di.get_source_location(*addr)
         );
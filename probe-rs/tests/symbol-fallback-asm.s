@@ -0,0 +1,10 @@
+    .text
+    .globl add_one
+    .type add_one, @function
+add_one:
+    mov %edi, %eax
+    add $1, %eax
+    ret
+    .size add_one, . - add_one
+
+    .section .note.GNU-stack,"",@progbits
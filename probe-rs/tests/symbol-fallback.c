@@ -0,0 +1,9 @@
+volatile int sink;
+
+extern int add_one(int value);
+
+void _start(void) {
+    sink = add_one(sink);
+    for (;;) {
+    }
+}
@@ -86,6 +86,7 @@ pub fn cmd_elf(
                         debug_base: None,
                         cti_base: None,
                     }),
+                    reset_domain: None,
                 }],
                 part: None,
                 name: "<chip name>".to_owned(),
@@ -98,6 +99,8 @@ pub fn cmd_elf(
                     }),
                     MemoryRegion::Ram(RamRegion {
                         is_boot_memory: true,
+                        is_reserved: false,
+                        is_stack: false,
                         range: 0x1_0000..0x2_0000,
                         cores: vec!["main".to_owned()],
                         name: None,
@@ -106,6 +109,7 @@ pub fn cmd_elf(
                 flash_algorithms: vec![algorithm_name],
                 rtt_scan_ranges: None,
                 scan_chain: None,
+                max_debug_clock_khz: None,
             }],
             flash_algorithms: vec![algorithm],
             source: BuiltIn,
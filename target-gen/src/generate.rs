@@ -162,6 +162,7 @@ where
             flash_algorithms: flash_algorithm_names,
             rtt_scan_ranges: None,
             scan_chain: None, // TODO, parse from sdf
+            max_debug_clock_khz: None,
         });
     }
 
@@ -186,6 +187,7 @@ fn create_core(processor: &Processor) -> Result<ProbeCore> {
             }),
             Architecture::Riscv => CoreAccessOptions::Riscv(RiscvCoreAccessOptions {}),
         },
+        reset_domain: None,
     })
 }
 
@@ -523,6 +525,8 @@ pub(crate) fn get_mem_map(device: &Device, cores: &[probe_rs_target::Core]) -> V
                     name: Some(region.name.clone()),
                     range: region.memory_start..region.memory_end,
                     is_boot_memory: region.is_boot_memory,
+                    is_reserved: false,
+                    is_stack: false,
                     cores,
                     }));
                 }